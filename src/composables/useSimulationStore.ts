@@ -222,11 +222,11 @@ function createStore() {
   // Per-generation Hall of Fame (top by lifespan)
   // id: stable brain-hash identifier; liveId: last known runtime creature id (for focus/lookups)
   const hallOfFameGen = ref<
-    Array<{ id: string; name: string; lifespan: number; liveId?: string; gen: number }>
+    Array<{ id: string; name: string; lifespan: number; fitness?: number; liveId?: string; gen: number }>
   >([])
   // Cumulative Hall of Fame across all generations (persists; keeps max lifespan per id)
   const hallOfFameAll = ref<
-    Array<{ id: string; name: string; lifespan: number; liveId?: string; gen: number }>
+    Array<{ id: string; name: string; lifespan: number; fitness?: number; liveId?: string; gen: number }>
   >([])
   // Generation end signal and summary
   const lastGenEnd = ref<null | { gen: number; reason: string; timestamp: number }>(null)
@@ -234,6 +234,15 @@ function createStore() {
   // Snapshot of simulation params from the generation that just ended
   const lastGenParams = ref<null | Record<string, any>>(null)
   // Brain RNG seed handled by deterministic RNG below
+  // Fitness weights forwarded to WASM (config.fitness); the default scores lifespan only
+  const fitnessWeights = reactive({
+    lifespan: 1,
+    offspring: 0,
+    energyHarvested: 0,
+    kills: 0,
+    distanceTraveled: 0,
+    damageAvoided: 0,
+  })
   // Brain memoization cache: hash -> { score, brain, genes }
   const brainCache = reactive<Record<string, { score: number; brain: any; genes: any }>>({})
   // Debug throttle counter for sparse logs
@@ -597,6 +606,15 @@ function createStore() {
     return Array.from({ length: total }, (_, i) => `In ${i}`)
   }
 
+  // Output labels from the WASM schema when it matches `total`, else null
+  function getOutputLabelsFor(total: number): string[] | null {
+    const schema = getBrainIoSchema()
+    if (schema && Array.isArray(schema.outputs) && schema.outputs.length === total) {
+      return schema.outputs.map((ch: any) => String(ch.name))
+    }
+    return null
+  }
+
   function getInputCategoriesFor(total: number, mode: 'OG' | 'Zegion', version: 'v1' | 'v2') {
     const internalIdx = new Set<number>()
     if (mode === 'OG') {
@@ -620,7 +638,24 @@ function createStore() {
     return Array.from({ length: total }, (_, i) => (internalIdx.has(i) ? 'Internal' : 'External'))
   }
 
+  // Channel layout reported by the WASM engine (null on the JS engine or older builds)
+  function getBrainIoSchema(): any | null {
+    try {
+      if (wasmWorld && typeof wasmWorld.brain_io_schema === 'function') return wasmWorld.brain_io_schema()
+    } catch {}
+    return null
+  }
+
   function getInputMeta(total: number) {
+    // Prefer the engine's own schema so labels track its input layout
+    const schema = getBrainIoSchema()
+    if (schema && Array.isArray(schema.inputs) && schema.inputs.length === total) {
+      return schema.inputs.map((ch: any, idx: number) => ({
+        idx,
+        label: String(ch.name),
+        category: ch.kind === 'internal' ? 'Internal' : 'External',
+      }))
+    }
     const mode = simulationParams.brainMode
     const version = simulationParams.inputsVersion
     const labels = getInputLabelsFor(total, mode, version)
@@ -765,6 +800,18 @@ function createStore() {
     }
   }
 
+  // Merge partial fitness weights; WASM then ranks the hall of fame and brain cache by them
+  function setFitnessWeights(weights: Partial<typeof fitnessWeights>) {
+    Object.assign(fitnessWeights, weights)
+    try {
+      if (wasmWorld && typeof wasmWorld.set_fitness_weights === 'function') {
+        wasmWorld.set_fitness_weights({ ...fitnessWeights })
+      }
+    } catch (e) {
+      console.warn('[WASM] set_fitness_weights failed', e)
+    }
+  }
+
   // --- Brain memoization helpers (mirroring OG) ---
   function simpleHash(str: string): string {
     let hash = 0
@@ -777,6 +824,19 @@ function createStore() {
     return new Uint32Array([hash >>> 0])[0].toString(36)
   }
 
+  // Brain hash for the bad-brain set and Hall of Fame: the engine's canonical xxHash64 when WASM is
+  // loaded (matches what set_bad_brain_hashes checks), else the legacy simpleHash over JSON text
+  function brainHash(brainJSON: { layerSizes: any; weights: any; biases: any }): string {
+    if (wasmWorld && typeof wasmWorld.brain_hash === 'function') {
+      try {
+        return wasmWorld.brain_hash(brainJSON)
+      } catch (e) {
+        console.warn('[WASM] brain_hash failed, using the legacy hash', e)
+      }
+    }
+    return simpleHash(JSON.stringify(brainJSON))
+  }
+
   function trimCache(
     cache: Record<string, { score: number; brain: any; genes: any }>,
     maxEntries: number,
@@ -889,10 +949,6 @@ function createStore() {
   // Build the WASM config object from current simulationParams (subset parity)
   function buildWasmConfig() {
     return {
-      // Time parity
-      realSecondsPerDay: Number(simulationParams.realSecondsPerDay),
-      worldTimeScale: Number(simulationParams.worldTimeScale),
-      startDayOfYear01: Number(simulationParams.startDayOfYear01),
       restStaminaRegenPerSec: Number(simulationParams.restStaminaRegenPerSec),
       restHealthRegenPerSec: Number(simulationParams.restHealthRegenPerSec),
       harvestPlantActionCostPerSecond: Number(simulationParams.harvestPlantActionCostPerSecond),
@@ -936,6 +992,12 @@ function createStore() {
       corpseHumidityDecayCoeff: Number(simulationParams.corpseHumidityDecayCoeff),
       corpseRainDecayCoeff: Number(simulationParams.corpseRainDecayCoeff),
       corpseWetnessDecayCoeff: Number(simulationParams.corpseWetnessDecayCoeff),
+      // Brain mutation operators (remaining mutation fields use engine defaults)
+      mutation: {
+        weightProb: Number(simulationParams.mutationRate),
+        weightSigma: Number(simulationParams.mutationAmount),
+      },
+      fitness: { ...fitnessWeights },
     }
   }
 
@@ -1322,6 +1384,9 @@ function createStore() {
         'corpseHumidityDecayCoeff',
         'corpseRainDecayCoeff',
         'corpseWetnessDecayCoeff',
+        // Brain mutation operators
+        'mutationRate',
+        'mutationAmount',
       ] as any
       watch(
         () => keys.map((k) => (simulationParams as any)[k]),
@@ -1583,9 +1648,9 @@ function createStore() {
           x: co.x,
           y: co.y,
           radius: co.radius,
-          energyRemaining: co.energy_remaining ?? 0,
-          initialDecayTime: co.initial_decay_time ?? 100,
-          decayTimer: co.decay_timer ?? 100,
+          energyRemaining: co.energyRemaining ?? 0,
+          initialDecayTime: co.initialDecayTime ?? 100,
+          decayTimer: co.decayTimer ?? 100,
         }))
       } catch (e) {
         console.warn('Failed to initialize from WASM, using JS fallback', e)
@@ -1730,6 +1795,15 @@ function createStore() {
         // Advance the WASM world and merge positional data into our creatures
         try {
           wasmWorld.step(effDt)
+          // Engine-detected degenerate brains are already in its bad set; mirror them so later syncs keep them
+          if (typeof wasmWorld.drain_events_json === 'function') {
+            for (const ev of wasmWorld.drain_events_json() as any[]) {
+              if (ev?.type === 'degenerateBrain' && typeof ev.hash === 'string') badBrainHashes[ev.hash] = true
+              if (typeof window !== 'undefined') {
+                window.dispatchEvent(new CustomEvent('wasm-world-event', { detail: ev }))
+              }
+            }
+          }
           const wasmCreatures: Array<any> = wasmWorld.creatures_json()
           // Pull minimal environmental cost telemetry from WASM (optional method)
           const wasmEnvCosts: Array<any> =
@@ -1769,9 +1843,9 @@ function createStore() {
             gestationTimer: 0,
             childGenes: null,
             genes: wc.genes || {},
-            actionsMask: wc.actions_mask ?? 0,
-            feelingsMask: wc.feelings_mask ?? 0,
-            stagnantTicks: wc.stagnant_ticks ?? 0,
+            actionsMask: wc.actionsMask ?? 0,
+            feelingsMask: wc.feelingsMask ?? 0,
+            stagnantTicks: wc.stagnantTicks ?? 0,
             // Inject last-tick env cost telemetry for overlay/parity validation (if available)
             ...(envById.has(wc.id)
               ? {
@@ -1785,6 +1859,7 @@ function createStore() {
                     envOxy: envById.get(wc.id)!.envOxy ?? 0,
                     envNoise: envById.get(wc.id)!.envNoise ?? 0,
                     envDisease: envById.get(wc.id)!.envDisease ?? 0,
+                    envSlope: envById.get(wc.id)!.envSlope ?? 0,
                     locomotion: envById.get(wc.id)!.locomotion ?? 0,
                   },
                 }
@@ -1829,7 +1904,7 @@ function createStore() {
                   ),
                 },
             radius: wc.radius ?? 5,
-            maxStamina: wc.max_stamina ?? 100,
+            maxStamina: wc.maxStamina ?? 100,
             communicationColor: { r: 255, g: 180, b: 255 },
             isResting: false,
             isSprinting: false,
@@ -1848,32 +1923,36 @@ function createStore() {
                         biases: (prev.brain as any).biases,
                       }
                     : null
-                const hofId = brainJSON ? simpleHash(JSON.stringify(brainJSON)) : String(prev.id)
+                const hofId = brainJSON ? brainHash(brainJSON) : String(prev.id)
+                // Weighted fitness from WASM (config.fitness); older builds only report lifespan
+                const fitness =
+                  typeof (prev as any).fitness === 'number' ? (prev as any).fitness : prev.lifespan
                 // Record to per-generation HoF with stable brain-hash id and last liveId for UI focus
                 const entry = {
                   id: hofId,
                   liveId: String(prev.id),
                   name: prev.name,
                   lifespan: prev.lifespan,
+                  fitness,
                   gen: generation.value,
                 }
                 hallOfFameGen.value.push(entry)
-                // Update cumulative HoF: keep max lifespan per brain id
+                // Update cumulative HoF: keep max fitness per brain id
                 const idx = hallOfFameAll.value.findIndex((e) => e.id === entry.id)
                 if (idx === -1) {
                   hallOfFameAll.value.push({ ...entry })
-                } else if ((hallOfFameAll.value[idx].lifespan || 0) < entry.lifespan) {
+                } else if (hofScore(hallOfFameAll.value[idx]) < fitness) {
                   hallOfFameAll.value[idx] = { ...entry }
                 }
                 // Telemetry: deaths counter
                 telemetry.totals.deaths++
-                // Also memoize brain in cache using lifespan as score
+                // Also memoize brain in cache using fitness as score
                 if (brainJSON) {
                   const h = hofId
                   const existing = brainCache[h]
-                  if (!existing || existing.score < prev.lifespan) {
+                  if (!existing || existing.score < fitness) {
                     brainCache[h] = {
-                      score: prev.lifespan,
+                      score: fitness,
                       brain: brainJSON,
                       genes: (prev as any).genes || {},
                     }
@@ -1918,6 +1997,7 @@ function createStore() {
                 Number(w.envOxy ?? 0),
                 Number(w.envNoise ?? 0),
                 Number(w.envDisease ?? 0),
+                Number(w.envSlope ?? 0),
               ]
               const sum = parts.reduce((a, b) => a + (Number.isFinite(b) ? b : 0), 0)
               const total = Number(w.envTotal ?? 0)
@@ -2051,9 +2131,9 @@ function createStore() {
             x: co.x,
             y: co.y,
             radius: co.radius,
-            energyRemaining: co.energy_remaining ?? 0,
-            initialDecayTime: co.initial_decay_time ?? 100,
-            decayTimer: co.decay_timer ?? 100,
+            energyRemaining: co.energyRemaining ?? 0,
+            initialDecayTime: co.initialDecayTime ?? 100,
+            decayTimer: co.decayTimer ?? 100,
             ...(ccByIndex.has(i)
               ? {
                   _lastDecay: {
@@ -3294,7 +3374,7 @@ function createStore() {
                   }
                 : null
             if (brainJSON) {
-              const h = simpleHash(JSON.stringify(brainJSON))
+              const h = brainHash(brainJSON)
               const existing = brainCache[h]
               if (!existing || existing.score < c.lifespan) {
                 brainCache[h] = { score: c.lifespan, brain: brainJSON, genes: c.genes || {} }
@@ -3312,7 +3392,7 @@ function createStore() {
                   biases: (c.brain as any).biases,
                 }
               : null
-          const hofIdNow = hofBrainJSON ? simpleHash(JSON.stringify(hofBrainJSON)) : String(c.id)
+          const hofIdNow = hofBrainJSON ? brainHash(hofBrainJSON) : String(c.id)
           const entryNow = {
             id: hofIdNow,
             liveId: String(c.id),
@@ -3324,7 +3404,7 @@ function createStore() {
           const idxAll = hallOfFameAll.value.findIndex((e) => e.id === entryNow.id)
           if (idxAll === -1) {
             hallOfFameAll.value.push({ ...entryNow })
-          } else if ((hallOfFameAll.value[idxAll].lifespan || 0) < entryNow.lifespan) {
+          } else if (hofScore(hallOfFameAll.value[idxAll]) < hofScore(entryNow)) {
             hallOfFameAll.value[idxAll] = { ...entryNow }
           }
           corpses.value.push({
@@ -3675,6 +3755,8 @@ function createStore() {
   // Selection helpers
   function setSelectedCreature(id: string | null) {
     selectedCreatureId.value = id
+    // The engine only keeps brain activations for the watched creature
+    wasmWorld?.watch_creature?.(id)
   }
 
   function getSelectedCreature(): Creature | null {
@@ -4110,21 +4192,26 @@ function createStore() {
     }
   }
 
+  // Ranking score for HoF entries: WASM fitness when recorded, else lifespan
+  function hofScore(e: { lifespan: number; fitness?: number }): number {
+    return e.fitness ?? (e.lifespan || 0)
+  }
+
   // Hall of Fame accessors (per-generation)
   function getHallOfFameTop(
     limit = 10,
-  ): Array<{ id: string; name: string; lifespan: number; liveId?: string; gen: number }> {
+  ): Array<{ id: string; name: string; lifespan: number; fitness?: number; liveId?: string; gen: number }> {
     const list = hallOfFameGen.value.slice()
-    list.sort((a, b) => (b.lifespan || 0) - (a.lifespan || 0))
+    list.sort((a, b) => hofScore(b) - hofScore(a))
     return list.slice(0, Math.max(0, limit))
   }
 
   // Cumulative Hall of Fame accessor (all-time)
   function getHallOfFameTopAll(
     limit = 10,
-  ): Array<{ id: string; name: string; lifespan: number; liveId?: string; gen: number }> {
+  ): Array<{ id: string; name: string; lifespan: number; fitness?: number; liveId?: string; gen: number }> {
     const list = hallOfFameAll.value.slice()
-    list.sort((a, b) => (b.lifespan || 0) - (a.lifespan || 0))
+    list.sort((a, b) => hofScore(b) - hofScore(a))
     return list.slice(0, Math.max(0, limit))
   }
 
@@ -4261,6 +4348,10 @@ function createStore() {
     exportBadBrainLibrary,
     syncBadBrainsToWasm,
 
+    // Fitness API
+    fitnessWeights: readonly(fitnessWeights),
+    setFitnessWeights,
+
     // Creature events API
     getCreatureEvents,
     clearCreatureEvents,
@@ -4272,5 +4363,7 @@ function createStore() {
     getInputMeta,
     getInputLabelsFor,
     getInputCategoriesFor,
+    getOutputLabelsFor,
+    getBrainIoSchema,
   }
 }
@@ -1,29 +1,142 @@
 // Temporary module declaration to avoid TS errors before WASM build output exists.
+// The wasm-pack build emits generated interfaces for the core structs (Creature, Config, WorldEvent,
+// WorldStats, ...; ecosim-core feature "ts") and types the exports with them; keep these in step.
 declare module '@/wasm/ecosim/pkg/ecosim' {
   export interface WasmConfig {
-    // --- Time parameters (world clock parity) ---
-    realSecondsPerDay: number
-    worldTimeScale: number
-    startDayOfYear01: number
     restStaminaRegenPerSec: number
     restHealthRegenPerSec: number
     harvestPlantActionCostPerSecond: number
+    // Plant biomass: per-eater bite rate, per-plant cap, and regrowth (shared bites split what is left)
+    harvestRatePerSec?: number
+    plantMaxBiomass?: number
+    plantRegrowthPerSec?: number
+    // Yearly temperature swing; regrowth and germination ramp from 0 at frostC to full at
+    // growthOptimumC, boosted in spring
+    seasons?: {
+      enabled?: boolean
+      yearTicks?: number
+      tempAmplitudeC?: number
+      frostC?: number
+      growthOptimumC?: number
+      springGrowthMult?: number
+      germinationPerSec?: number // seeding chance of each fully grown plant
+      seedSpread?: number
+      maxPlants?: number
+    }
+    // Scheduled blooms: the biome fills with `flowers` plants of `biomass` each that don't regrow
+    // and wilt after durationTicks. Seasonal blooms start offsetTicks into the season every year
+    // (needs seasons); otherwise every periodTicks from offsetTicks
+    blooms?: {
+      enabled?: boolean
+      schedule?: {
+        biome?: WasmBiome
+        season?: 'spring' | 'summer' | 'autumn' | 'winter' | null
+        periodTicks?: number
+        offsetTicks?: number
+        durationTicks?: number
+        flowers?: number
+        biomass?: number
+      }[]
+    }
+    // North and south halves swing in opposite seasonal directions (temperature and plant growth),
+    // creatures remember their best foraging region (regionSize cells), and brains get 6 inputs:
+    // bestRegionDirX/DirY/Dist/Rating, yearSin, yearCos. Quadrant movement: migration_history_json
+    migration?: {
+      enabled?: boolean
+      tempSwingC?: number
+      growthSwing?: number // 0..1
+      regionSize?: number
+      forageWindowSec?: number
+      regionHalfLifeSec?: number
+      historyLen?: number // seasons kept
+    }
     attackCostPerHitStamina: number
     sprintOverflowCostPerSec: number
     postureCostPerSec: number
     attackCostPerHitEnergy: number
+    // A boosting carnivore bites the nearest herbivore whose edge is within attackReach of its own
+    attackReach?: number
+    attackDamagePerSec?: number // 0 (default) keeps bites harmless
+    killCreditWindowTicks?: number // a predation death this soon after a bite is the biter's kill
     thirstThreshold: number
     thirstRecoveryPerSec: number
     drinkCostPerSecond: number
+    // Thirst loss per second (base + per 10 C above comfortHighC + per unit speed) and the
+    // health damage per second once thirst reaches 0
+    thirstDecayPerSec?: number
+    thirstHeatCoeff?: number
+    thirstExertionCoeff?: number
+    dehydrationDamagePerSec?: number
+    // Drinking happens within drinkReach of a water band or where rain >= puddleRainThreshold
+    drinkReach?: number
+    puddleRainThreshold?: number
     moveCostCoeffPerSpeedPerSec: number
     ambientHealthDecayPerSec: number
+    // Energy per second per brain weight/bias (0 = brains are free); booked as ledger category 'brain'
+    brainCostPerParamPerSec?: number
+    // Health regained per second while energy exceeds regenEnergyThreshold, costing regenEnergyPerHealth each
+    regenEnergyThreshold?: number
+    regenHealthPerSec?: number
+    regenEnergyPerHealth?: number
+    // Reproduction costs may push energy down to energyDebtFloor (<= 0). Creatures starve at 0 energy
+    // unless surviveEnergyDebt is set: then they lose energyDebtHealthPenaltyPerSec health while in
+    // debt and starve at the floor
+    energyDebtFloor?: number
+    energyDebtHealthPenaltyPerSec?: number
+    surviveEnergyDebt?: boolean
     agingHealthDecayCoeff: number
+    // Aging peaks at maxLife ticks scaled by each creature's heritable longevity gene (mean 1)
+    maxLife?: number
+    longevityInitialSigma?: number
+    longevityMutationProb?: number
+    longevityMutationSigma?: number
     gestationBaseCostPerSec: number
     gestationCostPerOffspringPerSec: number
     gestationPeriod: number
     birthEventCostEnergy: number
+    // Newborns are placed out of water unless the parent's swimSkill is at least aquaticSwimSkill
+    aquaticSwimSkill?: number
+    birthPlacementAttempts?: number
+    // Conception requires this much energy and health, an age in ticks, and the postpartum
+    // cooldown (gestationPeriod units) to have elapsed since the last birth
+    gestationMinEnergy?: number
+    gestationMinHealth?: number
+    gestationMinAge?: number
+    postpartumCooldown?: number
+    // As gestation progresses the mother loses up to speedLoss of her speed and gestation costs rise
+    // to (1 + costRamp)x; below miscarriageEnergy she loses the litter (a 'miscarriage' event)
+    pregnancy?: { enabled?: boolean; speedLoss?: number; costRamp?: number; miscarriageEnergy?: number }
+    // Per birth: base + perStdChange * realized mutation magnitude (gene changes plus the L2 norm
+    // of inherited brain weight changes)
     mutationCostEnergyBase: number
     mutationCostPerStdChange: number
+    // Litter size is a heritable gene (1..maxLitterSize, founders start at 1); each newborn gets
+    // newbornEnergy / n^litterSizeTradeoff energy and a proportionally smaller body
+    maxLitterSize?: number
+    litterSizeMutationProb?: number
+    litterSizeMutationSigma?: number
+    newbornEnergy?: number
+    newbornRadius?: number
+    litterSizeTradeoff?: number
+    // Juveniles (younger than juvenileTicks) put intakeFraction of their food intake into growing toward
+    // adultRadius * the adultSize gene, at most maxRatePerSec radius per second (ledger category 'growth');
+    // famine-born cohorts stay stunted
+    growth?: {
+      enabled?: boolean
+      adultRadius?: number
+      intakeFraction?: number
+      energyPerRadius?: number
+      maxRatePerSec?: number
+      juvenileTicks?: number
+      sizeMutationProb?: number
+      sizeMutationSigma?: number
+    }
+    // Per-tick caps on speed and acceleration (0 = unlimited), scaled by the heritable agility gene (mean 1)
+    maxSpeed?: number
+    maxAccel?: number
+    agilityInitialSigma?: number
+    agilityMutationProb?: number
+    agilityMutationSigma?: number
     // Telemetry thresholds
     hungerEnergyThreshold: number
     fatigueStaminaThreshold: number
@@ -40,32 +153,933 @@ declare module '@/wasm/ecosim/pkg/ecosim' {
     humidityThreshold: number
     oxygenThinAirPenaltyPerSec: number
     thinAirElevationCutoff01: number
+    altitudeAcclimatizationPerSec?: number
+    altitudeAcclimatizationRelief?: number // share of the thin-air penalty removed at full acclimatization
     noiseStressPenaltyPerSec: number
-    diseaseEnergyDrainPerSec: number
+    diseaseEnergyDrainPerSec: number // everyone pays it, or only the infected while disease.enabled
+    // Contagion: chances per second scale by (1 + humidityFactor * humidity) * (1 + crowdingFactor * contacts)
+    disease?: {
+      enabled?: boolean
+      contactRadius?: number
+      transmissionPerSec?: number // per infected contact
+      outbreakPerSec?: number
+      humidityFactor?: number
+      crowdingFactor?: number
+      recoveryPerSec?: number
+      immunityTicks?: number
+      // Eating a corpse past carrionOnset of its decay risks infection, up to carrionPerSec when fully
+      // rotten, scaled by 1 + carrionTempFactor * warmth ((temp - 20 C) / 15, clamped 0..2)
+      carrionPerSec?: number
+      carrionOnset?: number
+      carrionTempFactor?: number
+    }
+    slopeUphillCostPerSec?: number
+    slopeSpeedPenalty?: number
+    slopeDownhillBoost?: number
+    swimSkillCostReduction?: number
+    swimSpeedMinMult?: number
+    swimStaminaDrainPerSec?: number
+    drowningDamagePerSec?: number
+    swimSkillMutationProb?: number // chance the gene mutates at all per birth
+    swimSkillMutationSigma?: number
+    // Water bands flow downstream (+x), fastest mid-channel, drifting toward the banks; swimmers
+    // resist in proportion to swimSkill and loose corpses strand on the banks
+    waterCurrents?: {
+      enabled?: boolean
+      speed?: number // world units per tick at mid-channel
+      bankDrift?: number
+      swimmerResistance?: number
+    }
+    flyerInitialFraction?: number
+    flightGeneFlipProb?: number
+    flightEnergyCostPerSec?: number
+    flightClimbRatePerSec?: number
+    // Carnivores eating next to a corpse draw this much energy from it per second
+    meatIntakePerSec?: number
+    // Each creature's plantShare of recent intake (forgetting over `memory` food units) is always
+    // tracked; when enabled, food yields novice + (specialist - novice) * share^curve energy per unit
+    // (the difference is ledger category 'digestion')
+    dietSpecialization?: {
+      enabled?: boolean
+      memory?: number
+      specialistEfficiency?: number
+      noviceEfficiency?: number
+      curve?: number
+    }
+    // CARRY output: carnivores latch onto a corpse and drag it (slowed by its size)
+    corpseCarry?: {
+      enabled?: boolean
+      reach?: number
+      dragPerRadius?: number
+    }
+    // Decomposer colonies eat corpses (in reach) and plant litter (litterfallPerSec of plant biomass,
+    // plus corpses that rot away) and return `conversion` of it as soil fertility; plant growth is
+    // multiplied by 1 + fertilityBoost * f / (f + fertilityHalf). Colonies bud at budBiomass and die at 0
+    decomposers?: {
+      enabled?: boolean
+      cellSize?: number
+      litterfallPerSec?: number
+      passivePerSec?: number
+      leachPerSec?: number
+      fertilityBoost?: number
+      fertilityHalf?: number
+      reach?: number
+      feedPerSec?: number
+      conversion?: number
+      growthEfficiency?: number
+      upkeepPerSec?: number
+      budBiomass?: number
+      budDistance?: number
+      sporePerSec?: number
+      maxColonies?: number
+    }
+    // Loose food items (placed food, spawn_food drops, fallen fruit) lose decayPerSec of their energy
+    // per second and are eaten like plants; with fruitDrop, fully grown plants drop fruitEnergy of biomass
+    foodItems?: {
+      decayPerSec?: number
+      maxItems?: number
+      fruitDrop?: boolean
+      fruitPerSec?: number
+      fruitEnergy?: number
+      fruitSpread?: number
+    }
+    // Old-age decline past onset (fraction of max life) along age^exponent: each *Loss is the share
+    // lost at max life; perceptionRange bounds what a creature in its prime senses (0 = unlimited)
+    senescence?: {
+      enabled?: boolean
+      onset?: number
+      exponent?: number
+      speedLoss?: number
+      staminaRegenLoss?: number
+      perceptionLoss?: number
+      perceptionRange?: number
+    }
+    // Hibernation: adds a TORPOR output; torpid creatures lie still at a fraction of their metabolic
+    // costs (less for big bodies and in winter), and wake up sluggish
+    torpor?: {
+      enabled?: boolean
+      metabolicMult?: number
+      referenceRadius?: number
+      winterMult?: number
+      minDurationSec?: number
+      wakeUpSec?: number
+      wakeSpeedMult?: number
+    }
+    burrowDigCostEnergy?: number
+    burrowRadius?: number
+    burrowTempShelter?: number
+    maxBurrows?: number
+    // step() clamps real dt and sub-steps: ticks of at most maxSubstepDtSec, up to maxSubsteps per call
+    // (time beyond that budget is dropped)
+    maxFrameDtSec?: number
+    maxSubstepDtSec?: number
+    maxSubsteps?: number
+    // Serve *_json/*_msgpack entity exports from a copy of the last completed step
+    doubleBufferedExports?: boolean
+    // Rewind snapshot ring; off by default (capacity 0). A rewind scrubber sets snapshotCapacity (e.g. 20)
+    // to enable it, and rewind_horizon starts at the tick it was switched on
+    snapshotEveryTicks?: number
+    snapshotCapacity?: number
+    stateHashHistory?: number
+    // Applied commands kept for rewind replay and command_log_json (oldest dropped first)
+    commandLogLimit?: number
+    // Debug: per-tick energy conservation report (energy_audit_json)
+    energyAudit?: boolean
+    legacyBrainHash?: boolean
+    // Export creature/plant/corpse/elite/burrow keys in the old snake_case (compatibility only)
+    legacySnakeCaseEntities?: boolean
+    degenerateWindowTicks?: number
+    degenerateAction?: WasmDegenerateAction
+    degenerateMutationSigma?: number
+    // Imported brains get NaN weights zeroed and the rest clamped to +/- this
+    brainWeightLimit?: number
+    pruneEveryTicks?: number
+    pruneMode?: 'reinit' | 'remove'
+    sparsifyWeightBelow?: number
+    // Brain inheritance and structural mutation (per offspring)
+    inheritBrains?: boolean
+    addNeuronProb?: number
+    removeNeuronProb?: number
+    addLayerProb?: number
+    maxHiddenNeurons?: number
+    maxHiddenLayers?: number
+    structuralNoiseSigma?: number
+    mutation?: Partial<WasmMutationConfig>
+    crossover?: { mode?: 'none' | 'uniform' | 'layer' | 'blend'; mixRatio?: number }
+    // Newborns of parents related by at least `threshold` (0.5 parent/child and siblings,
+    // lineageRelatedness for other same-founder pairs) get weaker vitals and more disease drain
+    inbreeding?: {
+      enabled?: boolean
+      lineageRelatedness?: number
+      threshold?: number
+      vitalsPenalty?: number
+      diseaseSusceptibility?: number
+    }
+    // Two-sex mode (off = asexual). Only females gestate; a female ready to conceive takes the male of
+    // her diet within mateReach with the best health01 * exp(choosiness * ornament). Males pay
+    // displayCostPerSec per unit of the heritable ornament gene (ledger category 'display')
+    sexes?: {
+      enabled?: boolean
+      maleFraction?: number
+      mateReach?: number
+      choosiness?: number
+      displayCostPerSec?: number
+      refractoryPeriod?: number // male wait between matings, in gestationPeriod units
+      ornamentMutationProb?: number
+      ornamentMutationSigma?: number
+    }
+    elitism?: { topK?: number; periodTicks?: number; offspringPerElite?: number; protect?: boolean }
+    novelty?: {
+      enabled?: boolean
+      k?: number
+      periodTicks?: number
+      archiveThreshold?: number
+      archiveCap?: number
+      fitnessWeight?: number
+    }
+    fitness?: Partial<WasmFitnessWeights>
+    // Every intervalTicks, nudges plantRegrowthPerSec up and ambientHealthDecayPerSec down (by step
+    // of each range) while herbivores < herbivoreMin, and the reverse above herbivoreMax
+    autoBalance?: {
+      enabled?: boolean
+      herbivoreMin?: number
+      herbivoreMax?: number
+      intervalTicks?: number
+      step?: number
+      regrowthMin?: number
+      regrowthMax?: number
+      decayMin?: number
+      decayMax?: number
+    }
+    // Highlights digest warns when a diet's population drops below this
+    extinctionWarningThreshold?: number
+    // Carrying-capacity estimate: plant regrowth / measured herbivore upkeep, scaled by water access
+    carryingCapacity?: {
+      enabled?: boolean // measure upkeep and record capacity_history_json
+      intervalTicks?: number
+      historyLen?: number
+      waterReach?: number
+      trophicEfficiency?: number // carnivores per herbivore of capacity
+      defaultUpkeepPerSec?: number // until herbivore upkeep has been measured
+    }
+    // Decaying creature-density heatmap per diet for density_f32
+    density?: {
+      enabled?: boolean
+      resolution?: number
+      halfLifeSec?: number
+    }
+    // Recent positions per creature for trails_json (length samples, one every strideTicks)
+    trails?: {
+      enabled?: boolean
+      length?: number
+      strideTicks?: number
+    }
+    // Within-lifetime weight learning; learned deltas reset at birth unless heritable
+    plasticity?: {
+      enabled?: boolean
+      rule?: 'hebbian' | 'oja'
+      learningRate?: number
+      decay?: number
+      heritable?: boolean
+      maxWeight?: number
+    }
+    // Adds dir/dist inputs for the nearest plant, corpse, and water, plus the thirst level
+    resourceSensing?: boolean
+    // SHARE output (OG brains): give energy to an adjacent low-energy creature, preferring kin
+    energySharing?: {
+      enabled?: boolean
+      amount?: number
+      efficiency?: number
+      range?: number
+      recipientMaxEnergy?: number
+      minDonorEnergy?: number
+      kinBias?: number
+      cooldownSec?: number
+    }
+    // Plants and corpses emit a diffusing smell; creatures sense its local gradient
+    scent?: {
+      enabled?: boolean
+      cellSize?: number
+      diffusion?: number
+      evaporation?: number
+      plantEmission?: number
+      corpseEmission?: number
+    }
+    // Remembers the last seen food and last fight location per creature and feeds them as inputs
+    spatialMemory?: {
+      enabled?: boolean
+      sightRadius?: number
+      halfLifeSec?: number
+    }
+    // Adds dir/dist/size inputs for the 3 nearest herbivores, carnivores, plants and corpses
+    multiTargetSensing?: boolean
+    // Per-input Gaussian noise (sigma) and dropout probability; `channels` keys are
+    // brain_io_schema input names and override the defaults
+    sensorNoise?: {
+      enabled?: boolean
+      sigma?: number
+      dropout?: number
+      channels?: Record<string, { sigma?: number; dropout?: number }>
+    }
     // --- Corpse decay tunables ---
     corpseBaseDecayPerSec: number
     corpseTempDecayCoeff: number
     corpseHumidityDecayCoeff: number
     corpseRainDecayCoeff: number
     corpseWetnessDecayCoeff: number
+    // Corpse energy = corpseEnergyPerRadius * radius + remaining energy; decay time scales with radius
+    corpseEnergyPerRadius?: number
+    corpseDecayTimePerRadius?: number
+    // --- Terrain/climate noise (optional; Rust defaults apply when omitted) ---
+    noiseOctaves?: number
+    noiseLacunarity?: number
+    noisePersistence?: number
+    noiseScale?: number
+  }
+  export interface WasmMutationConfig {
+    weightProb: number
+    weightSigma: number
+    resetProb: number
+    biasScale: number
+    // Sigma multiplier per generation, floored at minSigma
+    annealRate: number
+    minSigma: number
+  }
+  // Selection fitness = sum of weight * metric; damageAvoided scores negative damage taken
+  export interface WasmFitnessWeights {
+    lifespan: number
+    offspring: number
+    energyHarvested: number
+    kills: number
+    distanceTraveled: number
+    damageAvoided: number
+  }
+  export type WasmCommand =
+    | { type: 'spawnCreature'; x: number; y: number }
+    | { type: 'spawnPlant'; x: number; y: number; radius?: number | null }
+    | { type: 'setBrainMode'; mode: string }
+    | { type: 'feedCreature'; id: string; amount: number }
+    | { type: 'placeFood'; x: number; y: number; energy: number }
+    | { type: 'setConfig'; overrides: Partial<WasmConfig> }
+  export interface WasmScheduledCommand {
+    tick: number
+    seq: number
+    command: WasmCommand
+  }
+  export type WasmDegenerateAction = 'flag' | 'reroll' | 'mutate'
+  export type WasmDeathCause = 'oldAge' | 'starvation' | 'dehydration' | 'drowning' | 'predation'
+  export type WasmWorldEvent =
+    | {
+        type: 'degenerateBrain'
+        tick: number
+        id: string
+        hash: string
+        reason: 'nonFinite' | 'allZero' | 'saturated' | 'flat'
+        action: WasmDegenerateAction
+      }
+    | { type: 'energyShared'; tick: number; from: string; to: string; amount: number; received: number; kin: boolean }
+    | { type: 'death'; tick: number; id: string; cause: WasmDeathCause; generation: number; lifespan: number }
+    // A pregnant creature's energy fell below pregnancy.miscarriageEnergy; progress is 0..1 through gestation
+    | { type: 'miscarriage'; tick: number; id: string; offspring: number; progress: number }
+    | { type: 'handFed'; tick: number; id: string; amount: number }
+    | { type: 'foodPlaced'; tick: number; x: number; y: number; energy: number }
+    | { type: 'balanceAdjusted'; tick: number; herbivores: number; plantRegrowthPerSec: number; ambientHealthDecayPerSec: number }
+    // Only emitted by builds with the invariant-checks feature
+    | { type: 'invariantViolation'; tick: number; violation: WasmInvariantViolation }
+  export interface WasmInvariantViolation {
+    kind: 'creature' | 'plant' | 'corpse'
+    id: string // creature id, or plant/corpse index
+    field: string
+    reason: 'nonFinite' | 'outOfBounds' | 'negativeRadius'
+    value: number | null // NaN/Inf serialize as null in JSON
+  }
+  // Stores at tick end, inflows, outflows, and the unexplained residual (see config.energyAudit)
+  export interface WasmEnergyAudit {
+    tick: number
+    creatures: number
+    plants: number
+    corpses: number
+    plantGrowth: number
+    carcassBody: number
+    births: number
+    debtWrittenOff: number
+    eliteRescue?: number // energy topped up to keep protected elites alive
+    spending: number
+    corpseDecay: number
+    decomposition?: number // litter and corpse matter taken up by decomposers
+    food?: number // energy held by food items
+    foodDecay?: number
+    foodIntake: number
+    residual: number
+    cumulativeResidual: number
+  }
+  export type WasmHighlight =
+    | { type: 'firstKill'; tick: number; id: string; generation: number }
+    | { type: 'recordLifespan'; tick: number; id: string; generation: number; lifespan: number }
+    | { type: 'newSpecies'; tick: number; id: string; generation: number; layerSizes: number[] }
+    | { type: 'lineageExtinct'; tick: number; lineage: string }
+    | { type: 'extinctionWarning'; tick: number; diet: 'Herbivore' | 'Carnivore'; count: number }
+  export interface WasmWorldStats {
+    tick: number
+    creatures: number
+    herbivores: number
+    carnivores: number
+    plants: number
+    corpses: number
+    burrows: number
+    meanEnergy: number
+    meanHealth: number
+    meanAgeTicks: number
+    birthsTotal: number
+    deathsTotal: number
+    meanNovelty: number
+    noveltyArchiveSize: number
+    meanInbreeding: number
+    // Attacks started (ATTACK rising edges with prey in reach), kills (predation deaths within
+    // killCreditWindowTicks of a bite, plus record_kill credits), and kills per attempt
+    attackAttemptsTotal: number
+    killsTotal: number
+    predationSuccessRate: number
+    infected?: number
+    // Realized diet: carnivores' mean plant share of intake, and mean specialization (0 = even mix, 1 = one food)
+    carnivorePlantShare?: number
+    meanDietSpecialization?: number
+    decomposers?: number
+    foodItems?: number
+    foodEnergy?: number
+  }
+  export type WasmBiome = 'Water' | 'Mountain' | 'Tundra' | 'Desert' | 'Forest' | 'Grassland'
+  export interface WasmBiomeStats {
+    biome: WasmBiome
+    areaShare: number // fraction of the map in this biome right now
+    creatures: number
+    herbivores: number
+    carnivores: number
+    plants: number
+    plantBiomass: number
+    meanEnergy: number
+    meanHealth: number
+    meanAgeTicks: number
+  }
+  // load = population / capacity (null when the capacity is 0); above 1 the population is overshooting
+  export interface WasmRegionCapacity {
+    biome: WasmBiome
+    areaShare: number
+    plantSupplyPerSec: number
+    waterAccess: number // fraction of the biome within waterReach of drinkable water
+    herbivoreCapacity: number
+    carnivoreCapacity: number
+    herbivores: number
+    carnivores: number
+    herbivoreLoad: number | null
+    carnivoreLoad: number | null
+  }
+  export interface WasmCarryingCapacity {
+    tick: number
+    upkeepPerSec: number // mean herbivore energy use per second
+    herbivoreCapacity: number
+    carnivoreCapacity: number
+    herbivores: number
+    carnivores: number
+    herbivoreLoad: number | null
+    carnivoreLoad: number | null
+    regions: WasmRegionCapacity[]
+  }
+  export interface WasmCapacitySample {
+    tick: number
+    herbivoreCapacity: number
+    carnivoreCapacity: number
+    herbivores: number
+    carnivores: number
+  }
+  // Crossings between map quadrants during one season; arrays are ordered NW, NE, SW, SE (north = top)
+  export interface WasmQuadrantMovement {
+    season: 'spring' | 'summer' | 'autumn' | 'winter' | null
+    startTick: number
+    endTick: number
+    crossings: number[][] // [from][to]
+    net: number[] // arrivals minus departures
+    population: number[]
+  }
+  // Mean, sample standard deviation, and 95% Student-t confidence interval across runs
+  export interface WasmSampleSummary {
+    n: number
+    mean: number
+    stdDev: number
+    ci95Low: number
+    ci95High: number
+    min: number
+    max: number
+  }
+  export interface WasmEnsembleRun {
+    seed: number
+    final: WasmWorldStats
+    extinctAtTick: number | null
+    herbivoresExtinct: boolean
+    carnivoresExtinct: boolean
+  }
+  export interface WasmEnsembleReport {
+    ticks: number
+    runs: WasmEnsembleRun[]
+    finalCreatures: WasmSampleSummary
+    finalHerbivores: WasmSampleSummary
+    finalCarnivores: WasmSampleSummary
+    finalPlants: WasmSampleSummary
+    meanEnergy: WasmSampleSummary
+    births: WasmSampleSummary
+    deaths: WasmSampleSummary
+    extinctionRate: number
+    herbivoreExtinctionRate: number
+    carnivoreExtinctionRate: number
+  }
+  // One outcome under both configs; diff is B minus A per seed
+  export interface WasmPairedMetric {
+    metric: 'creatures' | 'herbivores' | 'carnivores' | 'plants' | 'meanEnergy' | 'births' | 'deaths' | 'extinct'
+    meanA: number
+    meanB: number
+    diff: WasmSampleSummary
+    effectSize: number // paired Cohen's d
+    significant: boolean // 95% interval of diff excludes zero
+  }
+  export interface WasmConfigComparison {
+    ticks: number
+    seeds: number[]
+    metrics: WasmPairedMetric[]
+    a: WasmEnsembleReport
+    b: WasmEnsembleReport
+  }
+  export interface WasmPerfReport {
+    steps: number
+    sensingMs: number
+    brainMs: number
+    movementMs: number
+    environmentMs: number
+    reproductionMs: number
+    corpseDecayMs: number
+    serializationMs: number
+    totalStepMs: number
+  }
+  export interface WasmBenchmarkReport {
+    ticks: number
+    population: number
+    finalPopulation: number
+    elapsedMs: number
+    ticksPerSec: number
+    perf: WasmPerfReport
+  }
+  export interface WasmIoChannel {
+    name: string
+    // null: unbounded (custom channels)
+    min: number | null
+    max: number | null
+    kind: 'internal' | 'external' | 'constant' | 'custom'
+  }
+  // Ordered brain input/output channels; outputs pass through tanh and actions fire above 0.5
+  export interface WasmBrainIoSchema {
+    mode: 'OG' | 'Zegion'
+    inputs: WasmIoChannel[]
+    outputs: WasmIoChannel[]
+  }
+  // One recorded step for reinforcement learning (see collect_transitions)
+  // Weather at one future tick; grids are cols x rows, row-major, sampled at cell centres
+  export interface WasmForecastFrame {
+    tick: number
+    season: 'spring' | 'summer' | 'autumn' | 'winter' | null
+    meanRain01: number
+    meanTemperatureC: number
+    meanWind: number
+    rain01: number[]
+    temperatureC: number[]
+    wind: number[]
+  }
+  export interface WasmForecast {
+    cols: number
+    rows: number
+    frames: WasmForecastFrame[]
+    // Blooms under way now or starting within the forecast window, soonest first
+    blooms: { biome: WasmBiome; startTick: number; endTick: number; flowers: number; biomass: number }[]
+  }
+  export interface WasmTransition {
+    tick: number
+    inputs: number[]
+    outputs: number[]
+    reward: number
+    nextInputs: number[]
+    // The creature died this tick; nextInputs repeats inputs
+    done: boolean
+  }
+  export interface WasmBrainBatch {
+    layerSizes: number[]
+    ids: string[]
+    // Per layer: [count][nOut][nIn] weights and [count][nOut] biases (ReLU hidden, tanh output)
+    weights: Float32Array[]
+    biases: Float32Array[]
+    // [count][nIn]
+    inputs: Float32Array
+  }
+  export interface WasmDeathCounts {
+    total: number
+    oldAge: number
+    starvation: number
+    dehydration: number
+    drowning: number
+    predation: number
+  }
+  export interface WasmPhaseTimings {
+    sensingMs: number
+    brainMs: number
+    movementMs: number
+    environmentMs: number
+    reproductionMs: number
+    corpseDecayMs: number
+    totalMs: number
+  }
+  export interface WasmStepSummary {
+    tick: number
+    ticks: number
+    births: number
+    deaths: WasmDeathCounts
+    attacks: number
+    plantsEaten: number
+    biomassEaten: number
+    creatures: number
+    herbivores: number
+    carnivores: number
+    plants: number
+    corpses: number
+    timings: WasmPhaseTimings
+  }
+  export interface WasmWatchRecord {
+    tick: number
+    inputs: number[]
+    outputs: number[]
+    activations: number[][]
+    ledger: number[] // indexed by energy_ledger_categories()
+    x: number
+    y: number
+    energy: number
+    health: number
+    stamina: number
+    thirst: number
+    actionsMask: number
+    feelingsMask: number
+    events: WasmWorldEvent[]
+  }
+  export interface WasmWatchedCreature {
+    id: string
+    // False from the tick the creature died; no further records are added
+    alive: boolean
+    records: WasmWatchRecord[]
+    // Recent discrete actions, oldest first; consecutive ticks of one action merge into one entry
+    recentActions: WasmActionLogEntry[]
+  }
+  export type WasmCreatureAction =
+    | { type: 'atePlant'; plant: number } // index into plants_json at the time
+    | { type: 'ateCorpse' }
+    | { type: 'attacked'; target: string }
+    | { type: 'drank' }
+    | { type: 'gaveBirth' }
+    | { type: 'miscarried' }
+    | { type: 'sharedEnergy'; to: string }
+    | { type: 'mated'; partner: string }
+    | { type: 'handFed' }
+    | { type: 'ateFood' }
+  export interface WasmActionLogEntry {
+    tick: number
+    lastTick: number
+    action: WasmCreatureAction
+    // Summed over the entry's ticks: food eaten, thirst recovered, energy given, or offspring born
+    amount: number
+  }
+  export type WasmStopCondition =
+    | { type: 'populationBelow'; count: number; diet?: 'Herbivore' | 'Carnivore' | null }
+    | { type: 'tickReached'; tick: number }
+    | { type: 'firstExtinction' }
+    | { type: 'speciesCountChanges' }
+  export interface WasmStepUntilOutcome {
+    // Type of the condition that stopped the run, or 'maxTicks'
+    reason: WasmStopCondition['type'] | 'maxTicks'
+    condition: WasmStopCondition | null
+    ticks: number
+    stats: WasmWorldStats
+  }
+  // Creature.actionsMask / feelingsMask bit positions (mask = 1 << value)
+  export enum ActionFlag {
+    Resting = 0,
+    Eating = 1,
+    Sprinting = 2,
+    Attacking = 3,
+    Drinking = 4,
+    Flying = 5,
+    Burrowed = 6,
+    Digging = 7,
+    Sharing = 8,
+    Carrying = 9,
+    Torpid = 10,
+  }
+  export enum FeelingFlag {
+    Thirsty = 0,
+    Hungry = 1,
+    Fatigued = 2,
+    Restless = 3,
+    Drowning = 4,
+  }
+  export interface WasmFlagInfo {
+    bit: number
+    mask: number
+    name: string
+  }
+  export interface WasmFlagRegistry {
+    actions: WasmFlagInfo[]
+    feelings: WasmFlagInfo[]
   }
   // Minimal shape; actual bindings are generated by wasm-bindgen.
+  // Invalid arguments and serialization failures throw an Error whose message names the method
   export class World {
     constructor(width: number, height: number, seed: number)
     step(dt: number): void
+    // step() plus what its ticks did and the current counts (ticks = 0 while paused)
+    step_with_summary?(dt: number): WasmStepSummary
+    // Synthetic dense-world run (fixed seed) with per-phase timings and ticks/sec
+    static benchmark?(ticks: number, population: number): WasmBenchmarkReport
+    // Same config headless across seeds; throws for an invalid config
+    static run_ensemble?(config: Partial<WasmConfig>, width: number, height: number, seeds: Uint32Array, ticks: number): WasmEnsembleReport
+    // A/B test on matched seeds; throws if either config is invalid
+    static compare_configs?(configA: Partial<WasmConfig>, configB: Partial<WasmConfig>, width: number, height: number, seeds: Uint32Array, ticks: number): WasmConfigComparison
+    // Names and masks for actionsMask/feelingsMask bits
+    static flag_registry_json?(): WasmFlagRegistry
+    // Built-in curriculum scenarios for load_scenario
+    static scenarios_json?(): { name: string; description: string }[]
+    // Engine-side speed control (step dt is multiplied by the time scale; no-op while paused)
+    set_time_scale?(scale: number): void
+    time_scale?(): number
+    set_paused?(paused: boolean): void
+    is_paused?(): boolean
     creatures_json(): any
+    // Behaviour descriptors (each 0..1) and novelty scores (config.novelty)
+    behavior_descriptors_json?(): {
+      id: string
+      coverage: number
+      plantShare: number
+      movementEntropy: number
+      novelty: number
+    }[]
+    // Elite archive, fittest first (config.elitism)
+    elites_json?(): any[]
     plants_json(): any
     corpses_json(): any
+    burrows_json?(): { id: number; x: number; y: number; radius: number; dugTick: number }[]
+    decomposers_json?(): { x: number; y: number; biomass: number; bornTick: number }[]
+    food_items_json?(): { x: number; y: number; energy: number; decayPerSec: number; bornTick: number }[]
     env_costs_json?(): any
     corpse_costs_json?(): any
-    set_config(config: WasmConfig): void
+    // Merges into the current config (omitted fields keep their values); throws (config unchanged)
+    // for unknown keys or invalid values
+    set_config(config: Partial<WasmConfig>): void
+    // Throws (world unchanged) for an unknown mode
     set_brain_mode(mode: 'OG' | 'Zegion'): void
+    // Reseeds the RNG and regenerates seeded terrain/climate (an uploaded elevation grid is kept)
     set_seed(seed: number): void
     // Additional helpers available in newer builds
+    // Reseeds only the simulation RNG (brains, spawns, births); terrain and climate are untouched
     set_brain_seed?(seed: number): void
     set_bad_brain_hashes?(hashes: string[]): void
+    // Replace a creature's brain with a trained one; returns how many values were sanitized and
+    // throws if the id or topology doesn't fit
+    import_brain?(id: string, brain: { layerSizes: number[]; weights: number[][]; biases: number[][] }): number
+    // RL hook: reward signal for transitions (throws for an unknown mode); the first
+    // collect_transitions(id) call starts recording
+    set_reward_source?(mode: 'energy' | 'health' | 'fitness' | 'survival'): void
+    collect_transitions?(id: string): WasmTransition[]
+    // Merge partial weights into config.fitness; creatures_json entries then carry the weighted `fitness`
+    set_fitness_weights?(weights: Partial<WasmFitnessWeights>): void
+    // Credit a host-resolved kill to a living creature (stats killsTotal, fitness kills); the engine
+    // credits attackers of creatures that die on its own
+    record_kill?(killerId: string): boolean
+    // Recombined child of two same-topology brains using config.crossover (null if off or mismatched)
+    crossover_brains?(
+      a: { layerSizes: number[]; weights: number[][]; biases: number[][] },
+      b: { layerSizes: number[]; weights: number[][]; biases: number[][] },
+    ): { layerSizes: number[]; weights: number[][]; biases: number[][] } | null
+    // Mutated copy of a brain using config.mutation (annealed for `generation`)
+    mutate_brain?(brain: { layerSizes: number[]; weights: number[][]; biases: number[][] }, generation: number): {
+      layerSizes: number[]
+      weights: number[][]
+      biases: number[][]
+    } | null
+    // Dead-neuron pruning / weight sparsification pass (also runs every config.pruneEveryTicks)
+    prune_brains_json?(): { brainsTouched: number; neuronsPruned: number; weightsZeroed: number }
+    // Engine events since the last drain
+    drain_events_json?(): WasmWorldEvent[]
+    // Latest 64 notable moments, oldest first (not drained)
+    highlights_json?(): WasmHighlight[]
+    // Energy conservation report for the latest tick (null unless config.energyAudit)
+    energy_audit_json?(): WasmEnergyAudit | null
+    // Per-cell population and infected counts (row-major) for infection-rate overlays
+    infection_grid_json?(resolution: number): { cols: number; rows: number; population: number[]; infected: number[] }
+    // Hash in the format set_bad_brain_hashes expects (throws for an invalid brain)
+    brain_hash?(brain: { layerSizes: number[]; weights: number[][]; biases: number[][] }): string
     reset_world?(): void
+    // Custom input channels appended after the built-in senses, for one creature id or '*' (all).
+    // The array length sets the channel count; changing it resizes every brain's input layer.
+    // Throws for an unknown creature id.
+    set_custom_inputs?(target: string, values: Float32Array): void
+    // Named channel layout for a mode (current mode when omitted), including custom inputs
+    brain_io_schema?(mode?: 'OG' | 'Zegion'): WasmBrainIoSchema
+    input_len?(): number
+    // Reset into a built-in scenario (its overrides on the default config, not the current one); throws for an unknown name
+    load_scenario?(name: 'foodGradient' | 'predatorGauntlet' | 'seasonalScarcity' | 'mazeWorld' | string): void
     spawn_creature?(x: number, y: number): void
     spawn_plant?(x: number, y: number, radius?: number): void
+    // Immediate food drop, not in the command log (false at config.foodItems.maxItems)
+    spawn_food?(x: number, y: number, energy: number, decayPerSec?: number): boolean
+    // Hand feeding: queued for the next tick and recorded in the command log; return the sequence id
+    // (feed_creature throws for an unknown id, place_food if the command cannot be queued)
+    feed_creature?(id: string, amount: number): number
+    place_food?(x: number, y: number, energy: number): number
+    // Row-major resolution x resolution grid of one environment field
+    env_grid_json?(
+      field: 'temperature' | 'humidity' | 'rain' | 'wetness' | 'wind' | 'elevation' | 'noise' | 'scent' | 'speed' | 'currentX' | 'currentY' | 'plantGrowth' | 'litter' | 'fertility',
+      resolution: number,
+    ): Float32Array
+    // Row-major cols x rows movement speed multipliers stretched over the world, replacing the
+    // built-in terrain speed noise; throws if values.length !== cols * rows
+    set_speed_grid?(cols: number, rows: number, values: Float32Array): void
+    clear_speed_grid?(): void
+    // Row-major cols x rows heightmap (0..1) replacing the generated elevation for climate, slopes,
+    // biomes and thin air; throws if values.length !== cols * rows
+    set_elevation_grid?(cols: number, rows: number, values: Float32Array): void
+    clear_elevation_grid?(): void
+    season?(): 'spring' | 'summer' | 'autumn' | 'winter' | null
+    // Up to 8 future weather frames spread over the next ticksAhead ticks (8x8 row-major grids)
+    forecast_json?(ticksAhead: number): WasmForecast
+    // Environment values at a point for the current tick
+    sample_env?(x: number, y: number): {
+      temperatureC: number
+      humidity01: number
+      rain01: number
+      wetness01: number
+      wind: number
+      elevation01: number
+      noise01: number
+      biome: WasmBiome
+      inWater: boolean
+      currentX: number
+      currentY: number
+    }
+    stats_json?(): WasmWorldStats
+    // One entry per biome (all six, fixed order)
+    biome_stats_json?(): WasmBiomeStats[]
+    // Herbivores/carnivores each biome could feed, and the population's load against it
+    carrying_capacity_json?(): WasmCarryingCapacity
+    // Capacity vs population over time, oldest first; needs config.carryingCapacity.enabled
+    capacity_history_json?(): WasmCapacitySample[]
+    // Quadrant movement per season, oldest first, the season under way last; needs config.migration.enabled
+    migration_history_json?(): WasmQuadrantMovement[]
+    // Habitat-usage heatmap (config.density): density_resolution()^2 row-major cells; throws for an unknown layer
+    density_f32?(layer: 'herbivore' | 'carnivore' | 'all'): Float32Array
+    density_resolution?(): number
+    // Recent positions (oldest first) for ids, or every creature for []; needs config.trails.enabled
+    trails_json?(ids: string[]): { id: string; tick: number; points: [number, number][] }[]
+    // Follow one creature with per-tick telemetry (null stops); only it keeps brain.activations
+    watch_creature?(id: string | null): void
+    // Recent per-tick records for the watched creature (oldest first), or null when none is watched
+    watched_creature_json?(): WasmWatchedCreature | null
+    // Fast-forward without per-frame exports; returns periodic summaries
+    run_headless?(ticks: number, reportEvery: number): WasmWorldStats[]
+    // Fixed ticks until any condition holds or maxTicks run out; throws for an unknown condition
+    step_until?(conditions: WasmStopCondition | WasmStopCondition[], maxTicks: number): WasmStepUntilOutcome
+    // Time travel via the internal snapshot ring (enable with config.snapshotCapacity > 0)
+    rewind?(ticks: number): boolean
+    rewind_horizon?(): number
+    // Counterfactual ghost run with config overrides, stepped in lockstep; host commands, spawns,
+    // custom inputs, and brain outputs reach it too
+    start_ghost?(overrides: Partial<WasmConfig>): void
+    stop_ghost?(): void
+    ghost_divergence_json?(): {
+      tick: number
+      creaturesDelta: number
+      herbivoresDelta: number
+      carnivoresDelta: number
+      plantsDelta: number
+      corpsesDelta: number
+      birthsDelta: number
+      deathsDelta: number
+      meanEnergyDelta: number
+      meanHealthDelta: number
+      main: WasmWorldStats
+      ghost: WasmWorldStats
+    } | null
+    // MessagePack exports (decode with a msgpack lib; same shapes as the *_json methods); throw if
+    // encoding fails
+    creatures_msgpack?(): Uint8Array
+    plants_msgpack?(): Uint8Array
+    corpses_msgpack?(): Uint8Array
+    snapshot_msgpack?(): Uint8Array
+    load_snapshot_msgpack?(bytes: Uint8Array): void
+    // Lockstep: scheduled commands, replayable logs, and state hashes (queue_command throws for a past tick)
+    queue_command?(tick: number, command: WasmCommand): number
+    command_log_json?(): WasmScheduledCommand[]
+    load_command_log?(log: WasmScheduledCommand[]): number
+    state_hash?(): string
+    state_hashes_json?(): { tick: number; hash: string }[]
+    // Batched brain evaluation outside wasm (e.g. WebGPU): per-topology matrices plus this tick's inputs,
+    // then outputs fed back row-major in `ids` order for the next tick only
+    brain_batches?(): WasmBrainBatch[]
+    set_brain_outputs?(ids: string[], outputs: Float32Array): number
+    set_log_level?(level: 'off' | 'error' | 'warn' | 'info' | 'debug' | 'trace'): void
+    // Metadata getters
+    tick?(): number
+    frame_tick?(): number
+    creature_count?(): number
+    plant_count?(): number
+    corpse_count?(): number
+    width?(): number
+    height?(): number
+    brain_mode?(): 'OG' | 'Zegion'
+    // Per-tick energy ledger (stride = energy_ledger_categories().length)
+    energy_ledger_f32?(): Float32Array
+    // Zero-copy SoA views: new Float32Array(World.memory().buffer, positions_ptr(), hot_capacity() * 3)
+    // (x, y, radius) and vitals_ptr() with stride 4 (energy, health, stamina, thirst); first
+    // hot_count() slots live. Rebuild when hot_version() changes or the buffer is detached.
+    static memory?(): WebAssembly.Memory
+    positions_ptr?(): number
+    vitals_ptr?(): number
+    hot_capacity?(): number
+    hot_count?(): number
+    hot_version?(): number
+    energy_ledger_categories?(): string[]
+    // Accumulated step-phase timings (ms)
+    perf_json?(): WasmPerfReport
+    reset_perf?(): void
+    // Approximate memory usage in bytes per collection
+    memory_report_json?(): {
+      // Inline creature structs; their ids, learned weights, and action logs are in creatureHeap
+      creatures: number
+      creatureHeap: number
+      brains: number
+      plants: number
+      corpses: number
+      burrows: number
+      // Loose food items and decomposers
+      food: number
+      grids: number
+      history: number
+      // Pending/applied commands and per-tick state hashes
+      commands: number
+      events: number
+      elites: number
+      // Highlights, novelty archive, RL transitions
+      archives: number
+      // Carrying-capacity and migration logs
+      logs: number
+      badBrainHashes: number
+      // Read buffer kept when doubleBufferedExports is set
+      frontBuffer: number
+      trails: number
+      // The whole ghost world, when one is running
+      ghost: number
+      total: number
+    }
   }
 }
@@ -0,0 +1,159 @@
+// Headless runner: steps an ecosim-core world at a fixed dt, streams stats, and writes snapshots.
+//
+//   ecosim-cli --config params.toml --seed 7 --ticks 216000 --report-every 600 --csv run.csv \
+//              --snapshot-every 36000 --snapshot-dir snaps/
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Parser;
+use ecosim_core::{BrainMode, Config, World, WorldStats};
+
+#[derive(Parser)]
+#[command(name = "ecosim-cli", about = "Run an EvoSim ecosystem headless")]
+struct Args {
+    /// Simulation config (camelCase keys, .toml or .json); missing keys use defaults
+    #[arg(long)]
+    config: Option<PathBuf>,
+    #[arg(long, default_value_t = 1)]
+    seed: u32,
+    #[arg(long, default_value_t = 3600)]
+    ticks: u64,
+    #[arg(long, default_value_t = 800.0)]
+    width: f32,
+    #[arg(long, default_value_t = 600.0)]
+    height: f32,
+    /// Seconds per tick
+    #[arg(long, default_value_t = 1.0 / 60.0)]
+    dt: f32,
+    /// "OG" or "Zegion"
+    #[arg(long, default_value = "OG")]
+    brain_mode: String,
+    /// Start from a built-in scenario: foodGradient, predatorGauntlet, seasonalScarcity, mazeWorld
+    #[arg(long)]
+    scenario: Option<String>,
+    /// Emit a stats row every N ticks (the final tick is always reported)
+    #[arg(long, default_value_t = 600)]
+    report_every: u64,
+    /// Write stats rows as CSV to this file instead of JSON lines on stdout
+    #[arg(long)]
+    csv: Option<PathBuf>,
+    /// Write a MessagePack snapshot every N ticks (0 = only at the end when --snapshot-dir is set)
+    #[arg(long, default_value_t = 0)]
+    snapshot_every: u64,
+    #[arg(long)]
+    snapshot_dir: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("ecosim-cli: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), String> {
+    if BrainMode::parse(&args.brain_mode).is_none() {
+        return Err(format!("unknown brain mode '{}' (expected OG or Zegion)", args.brain_mode));
+    }
+    let mut world = World::new(args.width, args.height, args.seed);
+    // Also the base a --scenario's overrides apply to
+    let config = match &args.config {
+        Some(path) => {
+            let config = load_config(path)?;
+            world.set_config(config.clone());
+            config
+        }
+        None => Config::default(),
+    };
+    if !args.dt.is_finite() || args.dt <= 0.0 || args.dt > config.max_substep_dt_sec {
+        return Err(format!("--dt {} out of range (expected 0 < dt <= {})", args.dt, config.max_substep_dt_sec));
+    }
+    world.set_brain_mode(&args.brain_mode)?;
+    if let Some(name) = &args.scenario { world.load_scenario_on(name, config)?; }
+    if let Some(dir) = &args.snapshot_dir {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+
+    let mut sink: Box<dyn StatsSink> = match &args.csv {
+        Some(path) => {
+            let file = File::create(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            Box::new(CsvSink::new(BufWriter::new(file)).map_err(|e| e.to_string())?)
+        }
+        None => Box::new(JsonLinesSink(io::stdout().lock())),
+    };
+
+    let every = args.report_every.max(1);
+    for i in 1..=args.ticks {
+        world.advance(args.dt);
+        if i % every == 0 || i == args.ticks {
+            sink.write(&world.stats()).map_err(|e| e.to_string())?;
+        }
+        if let Some(dir) = &args.snapshot_dir {
+            if (args.snapshot_every > 0 && i % args.snapshot_every == 0) || i == args.ticks {
+                write_snapshot(&world, dir)?;
+            }
+        }
+    }
+    sink.flush().map_err(|e| e.to_string())
+}
+
+fn load_config(path: &Path) -> Result<Config, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let parsed = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str::<Config>(&text).map_err(|e| e.to_string()),
+        _ => toml::from_str::<Config>(&text).map_err(|e| e.to_string()),
+    };
+    parsed.map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn write_snapshot(world: &World, dir: &Path) -> Result<(), String> {
+    let path = dir.join(format!("snapshot-{:08}.msgpack", world.tick()));
+    fs::write(&path, world.snapshot_msgpack()?).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+trait StatsSink {
+    fn write(&mut self, s: &WorldStats) -> io::Result<()>;
+    fn flush(&mut self) -> io::Result<()>;
+}
+
+struct JsonLinesSink<W: Write>(W);
+
+impl<W: Write> StatsSink for JsonLinesSink<W> {
+    fn write(&mut self, s: &WorldStats) -> io::Result<()> {
+        serde_json::to_writer(&mut self.0, s)?;
+        writeln!(self.0)
+    }
+
+    fn flush(&mut self) -> io::Result<()> { self.0.flush() }
+}
+
+struct CsvSink<W: Write>(W);
+
+impl<W: Write> CsvSink<W> {
+    fn new(mut w: W) -> io::Result<Self> {
+        writeln!(w, "tick,creatures,herbivores,carnivores,plants,corpses,burrows,meanEnergy,meanHealth,meanAgeTicks,birthsTotal,deathsTotal,meanNovelty,noveltyArchiveSize,meanInbreeding,attackAttemptsTotal,killsTotal,predationSuccessRate,infected")?;
+        Ok(Self(w))
+    }
+}
+
+impl<W: Write> StatsSink for CsvSink<W> {
+    fn write(&mut self, s: &WorldStats) -> io::Result<()> {
+        writeln!(
+            self.0,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            s.tick, s.creatures, s.herbivores, s.carnivores, s.plants, s.corpses, s.burrows,
+            s.mean_energy, s.mean_health, s.mean_age_ticks, s.births_total, s.deaths_total,
+            s.mean_novelty, s.novelty_archive_size, s.mean_inbreeding,
+            s.attack_attempts_total, s.kills_total, s.predation_success_rate, s.infected
+        )
+    }
+
+    fn flush(&mut self) -> io::Result<()> { self.0.flush() }
+}
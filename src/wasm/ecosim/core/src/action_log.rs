@@ -0,0 +1,66 @@
+// Recent discrete actions per creature (ate a plant, attacked, drank, gave birth, ...) so an
+// inspector can show what a creature has been doing rather than just this tick's action mask.
+// Consecutive ticks of the same action merge into one entry. Display only: kill credit comes from
+// Creature::last_bite, since this ring can drop an attack well inside the credit window.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+// Entries kept per creature; the oldest is dropped first
+const ACTION_LOG_LEN: usize = 16;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CreatureAction {
+    // Index into plants_json at the time of the bite
+    AtePlant { plant: u32 },
+    AteCorpse,
+    Attacked { target: String },
+    Drank,
+    GaveBirth,
+    Miscarried,
+    SharedEnergy { to: String },
+    Mated { partner: String },
+    HandFed,
+    AteFood,
+}
+
+// `amount` sums over the entry's ticks: food eaten, thirst recovered, energy given or hand-fed, or
+// offspring born or lost (0 for attacks, 1 per mating)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionLogEntry {
+    pub tick: u64,
+    pub last_tick: u64,
+    pub action: CreatureAction,
+    pub amount: f32,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ActionLog(VecDeque<ActionLogEntry>);
+
+impl ActionLog {
+    pub(crate) fn push(&mut self, tick: u64, action: CreatureAction, amount: f32) {
+        if let Some(last) = self.0.back_mut() {
+            if last.action == action && last.last_tick + 1 >= tick {
+                last.last_tick = tick;
+                last.amount += amount;
+                return;
+            }
+        }
+        if self.0.len() >= ACTION_LOG_LEN { self.0.pop_front(); }
+        self.0.push_back(ActionLogEntry { tick, last_tick: tick, action, amount });
+    }
+
+    // Oldest first
+    pub fn entries(&self) -> Vec<ActionLogEntry> { self.0.iter().cloned().collect() }
+
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let target = |e: &ActionLogEntry| match &e.action { CreatureAction::Attacked { target } => target.capacity(), _ => 0 };
+        self.0.capacity() * std::mem::size_of::<ActionLogEntry>() + self.0.iter().map(target).sum::<usize>()
+    }
+}
@@ -0,0 +1,53 @@
+// Senescence: past an onset age, creatures lose top speed, stamina regeneration, and perception
+// range along a configurable curve, on top of the ambient health decay. Age structure then shapes
+// population dynamics, and predators catch the old more easily.
+
+use serde::{Deserialize, Serialize};
+
+// Senescence settings (serialized as the `senescence` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SenescenceConfig {
+    pub enabled: bool,
+    pub onset: f32,              // decline starts at this fraction of the creature's max life
+    pub exponent: f32,           // curve shape past the onset: 1 = linear, >1 = slow then steep
+    pub speed_loss: f32,         // fraction of top speed lost at max life
+    pub stamina_regen_loss: f32, // fraction of stamina regeneration lost at max life
+    pub perception_loss: f32,    // fraction of perception_range lost at max life
+    pub perception_range: f32,   // how far a creature in its prime senses others and food (0 = unlimited)
+}
+
+impl Default for SenescenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, onset: 0.5, exponent: 2.0, speed_loss: 0.5, stamina_regen_loss: 0.6,
+            perception_loss: 0.5, perception_range: 600.0,
+        }
+    }
+}
+
+// Capability multipliers for one creature this tick (all 1 while senescence is off or before onset)
+#[derive(Clone, Copy)]
+pub(crate) struct Vigor {
+    pub(crate) speed: f32,
+    pub(crate) stamina_regen: f32,
+    pub(crate) perception_range: f32, // f32::INFINITY when unlimited
+}
+
+impl SenescenceConfig {
+    // Decline (0..1) at `age_norm` (age / max life)
+    fn decline(&self, age_norm: f32) -> f32 {
+        if !self.enabled { return 0.0; }
+        let onset = self.onset.clamp(0.0, 0.99);
+        let past = ((age_norm - onset) / (1.0 - onset)).clamp(0.0, 1.0);
+        past.powf(self.exponent.max(0.01))
+    }
+
+    pub(crate) fn vigor(&self, age_norm: f32) -> Vigor {
+        let d = self.decline(age_norm);
+        let keep = |loss: f32| 1.0 - loss.clamp(0.0, 1.0) * d;
+        let perception_range = if self.enabled && self.perception_range > 0.0 { self.perception_range * keep(self.perception_loss) } else { f32::INFINITY };
+        Vigor { speed: keep(self.speed_loss), stamina_regen: keep(self.stamina_regen_loss), perception_range }
+    }
+}
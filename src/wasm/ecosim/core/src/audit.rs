@@ -0,0 +1,112 @@
+// Energy audit (Config::energy_audit): a per-tick conservation report. Energy enters as plant
+// regrowth, carcass body mass, newborn endowments, written-off energy debt, and the floor given to
+// protected elites; it leaves through
+// creature spending (metabolism, actions, environment), corpse decay, decomposers, and food item
+// decay. Eating is a transfer between stores. `residual` is what the books can't explain; nonzero values point at energy
+// changed outside the ledger.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::{Creature, LedgerCat};
+use crate::World;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EnergyAudit {
+    pub tick: u64,
+    // Stored at the end of the tick (plant biomass counts 1:1 with energy)
+    pub creatures: f32,
+    pub plants: f32,
+    pub corpses: f32,
+    #[serde(default)]
+    pub food: f32,
+    // Inflows
+    pub plant_growth: f32,
+    pub carcass_body: f32,
+    pub births: f32,
+    pub debt_written_off: f32,
+    // Energy topped up to keep protected elites alive (Config::elitism protect)
+    #[serde(default)]
+    pub elite_rescue: f32,
+    // Outflows
+    pub spending: f32,
+    pub corpse_decay: f32,
+    // Plant litter and corpse matter taken up by decomposers (Config::decomposers)
+    #[serde(default)]
+    pub decomposition: f32,
+    // Energy food items lost to decay (Config::food_items)
+    #[serde(default)]
+    pub food_decay: f32,
+    // Transfer from plants and corpses into creatures (already inside the stores)
+    pub food_intake: f32,
+    pub residual: f32,
+    pub cumulative_residual: f32,
+}
+
+// Flows accumulated during the current tick
+#[derive(Default)]
+pub(crate) struct EnergyFlows {
+    pub(crate) stored_before: f32,
+    pub(crate) plant_growth: f32,
+    pub(crate) carcass_body: f32,
+    pub(crate) births: f32,
+    pub(crate) debt_written_off: f32,
+    pub(crate) elite_rescue: f32,
+    pub(crate) corpse_decay: f32,
+    pub(crate) decomposition: f32,
+    pub(crate) food_decay: f32,
+    // Ledger totals of creatures that died this tick: (all categories, intake)
+    pub(crate) dead_ledger: (f32, f32),
+}
+
+impl EnergyFlows {
+    // Book a creature removed by death: its ledger, the corpse's body energy, and any unpaid debt
+    pub(crate) fn record_death(&mut self, c: &Creature, body: f32) {
+        self.dead_ledger.0 += c.ledger.0.iter().sum::<f32>();
+        self.dead_ledger.1 += c.ledger.0[LedgerCat::Intake as usize];
+        self.carcass_body += body;
+        self.debt_written_off += (-c.energy).max(0.0);
+    }
+}
+
+impl World {
+    fn stored_energy(&self) -> (f32, f32, f32, f32) {
+        (
+            self.creatures.iter().map(|c| c.energy).sum(),
+            self.plants.iter().map(|p| p.biomass).sum(),
+            self.corpses.iter().map(|c| c.energy_remaining).sum(),
+            self.food.iter().map(|f| f.energy).sum(),
+        )
+    }
+
+    // Start of tick: clear the flow accumulators and, when auditing, record the opening balance
+    pub(crate) fn begin_energy_audit(&mut self) {
+        self.flows = EnergyFlows::default();
+        if !self.config.energy_audit { self.energy_audit = None; return; }
+        let (c, p, k, f) = self.stored_energy();
+        self.flows.stored_before = c + p + k + f;
+    }
+
+    // End of tick: close the books into self.energy_audit
+    pub(crate) fn finish_energy_audit(&mut self) {
+        if !self.config.energy_audit { return; }
+        let (creatures, plants, corpses, food) = self.stored_energy();
+        let f = &self.flows;
+        let ledger = self.creatures.iter().map(|c| c.ledger.0.iter().sum::<f32>()).sum::<f32>() + f.dead_ledger.0;
+        let food_intake = self.creatures.iter().map(|c| c.ledger.0[LedgerCat::Intake as usize]).sum::<f32>() + f.dead_ledger.1;
+        let spending = food_intake - ledger;
+        let inflow = f.plant_growth + f.carcass_body + f.births + f.debt_written_off + f.elite_rescue;
+        let outflow = spending + f.corpse_decay + f.decomposition + f.food_decay;
+        let residual = (creatures + plants + corpses + food) - f.stored_before - inflow + outflow;
+        let cumulative_residual = self.energy_audit.as_ref().map_or(0.0, |a| a.cumulative_residual) + residual;
+        self.energy_audit = Some(EnergyAudit {
+            tick: self.tick, creatures, plants, corpses, food,
+            plant_growth: f.plant_growth, carcass_body: f.carcass_body, births: f.births, debt_written_off: f.debt_written_off, elite_rescue: f.elite_rescue,
+            spending, corpse_decay: f.corpse_decay, decomposition: f.decomposition, food_decay: f.food_decay, food_intake, residual, cumulative_residual,
+        });
+    }
+
+    // Conservation report for the latest tick; None unless config.energyAudit
+    pub fn energy_audit(&self) -> Option<&EnergyAudit> { self.energy_audit.as_ref() }
+}
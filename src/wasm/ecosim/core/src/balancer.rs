@@ -0,0 +1,57 @@
+// Ecosystem auto-balancer: a slow controller that keeps the herbivore population inside a target
+// band by nudging plant regrowth (food supply) and ambient health decay (mortality) within bounds
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Diet;
+use crate::events::{push_event, WorldEvent};
+use crate::World;
+
+// Balancer settings (serialized as the `autoBalance` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AutoBalanceConfig {
+    pub enabled: bool,
+    pub herbivore_min: u32,
+    pub herbivore_max: u32,
+    pub interval_ticks: u32,
+    pub step: f32, // fraction of each knob's range moved per adjustment
+    pub regrowth_min: f32,
+    pub regrowth_max: f32,
+    pub decay_min: f32,
+    pub decay_max: f32,
+}
+
+impl Default for AutoBalanceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, herbivore_min: 30, herbivore_max: 300, interval_ticks: 600, step: 0.1,
+            regrowth_min: 0.01, regrowth_max: 0.5, decay_min: 0.0, decay_max: 0.1,
+        }
+    }
+}
+
+// Move `v` by `dir` steps of `step` times the range's width, staying inside it
+fn nudge(v: f32, dir: f32, step: f32, lo: f32, hi: f32) -> f32 {
+    let (lo, hi) = (lo.min(hi), lo.max(hi));
+    (v + dir * step * (hi - lo)).clamp(lo, hi)
+}
+
+impl World {
+    // One controller update: too few herbivores means more food and slower decay, too many the
+    // opposite. Each change is logged as a balanceAdjusted event.
+    pub(crate) fn auto_balance(&mut self) {
+        let b = &self.config.auto_balance;
+        let herbivores = self.creatures.iter().filter(|c| c.diet == Diet::Herbivore).count() as u32;
+        let dir = if herbivores < b.herbivore_min { 1.0 } else if herbivores > b.herbivore_max { -1.0 } else { return; };
+        let regrowth = nudge(self.config.plant_regrowth_per_sec, dir, b.step, b.regrowth_min, b.regrowth_max);
+        let decay = nudge(self.config.ambient_health_decay_per_sec, -dir, b.step, b.decay_min, b.decay_max);
+        if regrowth == self.config.plant_regrowth_per_sec && decay == self.config.ambient_health_decay_per_sec { return; }
+        self.config.plant_regrowth_per_sec = regrowth;
+        self.config.ambient_health_decay_per_sec = decay;
+        push_event(&mut self.events, WorldEvent::BalanceAdjusted {
+            tick: self.tick, herbivores, plant_regrowth_per_sec: regrowth, ambient_health_decay_per_sec: decay,
+        });
+    }
+}
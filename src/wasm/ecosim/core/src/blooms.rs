@@ -0,0 +1,155 @@
+// Bloom events: on a fixed schedule (a season of every year, or every period_ticks) one biome
+// bursts into flowering plants that carry far more biomass than ordinary plants and wilt when the
+// bloom ends. Because the schedule is known in advance, blooms show up in the forecast, and
+// populations that learn the calendar can migrate to meet them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Plant;
+use crate::environment::Biome;
+use crate::seasons::{Season, SeasonConfig};
+use crate::World;
+
+// Attempts at finding a spot in the biome per flower before giving up on it
+const PLACEMENT_ATTEMPTS: u32 = 20;
+
+// One recurring bloom. With `season` set it starts offset_ticks into that season every year
+// (seasons must be enabled); otherwise every period_ticks from offset_ticks.
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BloomSpec {
+    pub biome: Biome,
+    pub season: Option<Season>,
+    pub period_ticks: u64,
+    pub offset_ticks: u64,
+    pub duration_ticks: u64,
+    pub flowers: u32,
+    pub biomass: f32, // per flower; flowers don't regrow once grazed
+}
+
+impl Default for BloomSpec {
+    fn default() -> Self {
+        Self { biome: Biome::Grassland, season: Some(Season::Spring), period_ticks: 0, offset_ticks: 0, duration_ticks: 60 * 60, flowers: 30, biomass: 40.0 }
+    }
+}
+
+// Bloom settings (serialized as the `blooms` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BloomConfig {
+    pub enabled: bool,
+    pub schedule: Vec<BloomSpec>,
+}
+
+impl Default for BloomConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            schedule: vec![
+                BloomSpec::default(),
+                BloomSpec { biome: Biome::Desert, season: None, period_ticks: 60 * 60 * 5, offset_ticks: 60 * 60 * 2, duration_ticks: 60 * 30, flowers: 15, biomass: 30.0 },
+            ],
+        }
+    }
+}
+
+// A bloom that is under way or scheduled to start within the forecast window
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpcomingBloom {
+    pub biome: Biome,
+    pub start_tick: u64,
+    pub end_tick: u64,
+    pub flowers: u32,
+    pub biomass: f32,
+}
+
+impl BloomSpec {
+    // Period and phase of this bloom's start ticks; None when it never fires
+    fn cycle(&self, seasons: &SeasonConfig) -> Option<(u64, u64)> {
+        match self.season {
+            Some(season) => {
+                if !seasons.enabled { return None; }
+                let year = seasons.year_ticks.max(1) as u64;
+                Some((year, (season as u64 * year / 4 + self.offset_ticks) % year))
+            }
+            None => (self.period_ticks > 0).then(|| (self.period_ticks, self.offset_ticks % self.period_ticks)),
+        }
+    }
+
+    // First start tick at or after `t`
+    fn next_start(&self, seasons: &SeasonConfig, t: u64) -> Option<u64> {
+        let (period, phase) = self.cycle(seasons)?;
+        if t <= phase { return Some(phase); }
+        Some(phase + (t - phase).div_ceil(period) * period)
+    }
+
+    // Latest start tick at or before `t`
+    fn last_start(&self, seasons: &SeasonConfig, t: u64) -> Option<u64> {
+        let (period, phase) = self.cycle(seasons)?;
+        (t >= phase).then(|| phase + (t - phase) / period * period)
+    }
+
+    fn upcoming(&self, start_tick: u64) -> UpcomingBloom {
+        UpcomingBloom { biome: self.biome, start_tick, end_tick: start_tick + self.duration_ticks, flowers: self.flowers, biomass: self.biomass }
+    }
+}
+
+impl World {
+    // Wilt flowers whose bloom is over (their leftovers become litter when decomposers are on),
+    // then plant the flowers of blooms starting this tick
+    pub(crate) fn update_blooms(&mut self) {
+        let (tick, litter) = (self.tick, self.config.decomposers.enabled);
+        let (soil, flows) = (&mut self.soil, &mut self.flows);
+        self.plants.retain(|p| {
+            if p.bloom_until.is_none_or(|t| t > tick) { return true; }
+            if litter {
+                soil.add_litter(p.x, p.y, p.biomass);
+                flows.decomposition += p.biomass;
+            } else {
+                flows.plant_growth -= p.biomass;
+            }
+            false
+        });
+        if !self.config.blooms.enabled { return; }
+        // Tick 0 is never stepped, so blooms scheduled for it open on tick 1
+        let since = if tick == 1 { 0 } else { tick };
+        let starting: Vec<BloomSpec> = self.config.blooms.schedule.iter()
+            .filter(|b| b.duration_ticks > 0 && b.next_start(&self.config.seasons, since).is_some_and(|s| s <= tick))
+            .cloned()
+            .collect();
+        for b in starting {
+            let until = tick + b.duration_ticks;
+            for _ in 0..b.flowers {
+                for _ in 0..PLACEMENT_ATTEMPTS {
+                    let (x, y) = (self.rng.uniform(0.0, self.width), self.rng.uniform(0.0, self.height));
+                    if self.biome_at(x, y) != b.biome { continue; }
+                    self.plants.push(Plant { x, y, radius: 4.0, biomass: b.biomass.max(0.0), bloom_until: Some(until) });
+                    self.flows.plant_growth += b.biomass.max(0.0);
+                    break;
+                }
+            }
+        }
+    }
+
+    // Blooms under way at `tick` or starting within (tick, tick + ticks_ahead], soonest first
+    pub(crate) fn upcoming_blooms(&self, ticks_ahead: u64) -> Vec<UpcomingBloom> {
+        let (seasons, tick) = (&self.config.seasons, self.tick);
+        if !self.config.blooms.enabled { return Vec::new(); }
+        let mut out = Vec::new();
+        for b in &self.config.blooms.schedule {
+            if b.duration_ticks == 0 { continue; }
+            if let Some(s) = b.last_start(seasons, tick).filter(|&s| s + b.duration_ticks > tick) { out.push(b.upcoming(s)); }
+            let mut next = b.next_start(seasons, tick + 1);
+            while let Some(s) = next.filter(|&s| s <= tick + ticks_ahead) {
+                out.push(b.upcoming(s));
+                next = b.next_start(seasons, s + 1);
+            }
+        }
+        out.sort_by_key(|u| u.start_tick);
+        out
+    }
+}
@@ -0,0 +1,624 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::creature::{Creature, Diet};
+use crate::rng::RngLCG;
+use crate::sensing::{memory_inputs, migration_inputs, multi_target_inputs, resource_inputs, SenseEnv};
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Brain {
+    pub layer_sizes: Vec<u32>,
+    pub weights: Option<Vec<Vec<f32>>>,
+    pub biases: Option<Vec<Vec<f32>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activations: Option<Vec<Vec<f32>>>,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub enum BrainMode { OG, Zegion }
+
+// Why a brain can't be imported or evaluated
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BrainError {
+    // Fewer than an input and an output layer
+    TooFewLayers { layers: u32 },
+    MissingParameters,
+    LayerCount { expected: u32, weights: u32, biases: u32 },
+    // Row-major n_out x n_in weights / n_out biases of layer `layer` (1-based)
+    WeightLength { layer: u32, expected: u32, actual: u32 },
+    BiasLength { layer: u32, expected: u32, actual: u32 },
+    // First layer doesn't match the input vector
+    InputSize { expected: u32, actual: u32 },
+    UnknownCreature { id: String },
+}
+
+impl fmt::Display for BrainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrainError::TooFewLayers { layers } => write!(f, "{} layer(s), need at least 2", layers),
+            BrainError::MissingParameters => write!(f, "weights or biases missing"),
+            BrainError::LayerCount { expected, weights, biases } => write!(f, "{} weight and {} bias layers, expected {}", weights, biases, expected),
+            BrainError::WeightLength { layer, expected, actual } => write!(f, "layer {} has {} weights, expected {}", layer, actual, expected),
+            BrainError::BiasLength { layer, expected, actual } => write!(f, "layer {} has {} biases, expected {}", layer, actual, expected),
+            BrainError::InputSize { expected, actual } => write!(f, "first layer takes {} inputs, expected {}", actual, expected),
+            BrainError::UnknownCreature { id } => write!(f, "no creature '{}'", id),
+        }
+    }
+}
+
+impl std::error::Error for BrainError {}
+
+impl BrainMode {
+    // Input vector length produced by build_inputs for this mode
+    pub fn input_len(self) -> u32 {
+        match self { BrainMode::OG => 14, BrainMode::Zegion => 24 }
+    }
+
+    // Built-in action outputs (optional actions are appended after these)
+    pub fn output_len(self) -> u32 {
+        match self { BrainMode::OG => 8, BrainMode::Zegion => 6 }
+    }
+
+    // Case-insensitive "OG" / "Zegion"
+    pub fn parse(mode: &str) -> Option<BrainMode> {
+        if mode.eq_ignore_ascii_case("OG") { Some(BrainMode::OG) }
+        else if mode.eq_ignore_ascii_case("Zegion") { Some(BrainMode::Zegion) }
+        else { None }
+    }
+}
+
+impl Brain {
+    // xxHash64 over a fixed binary encoding: layer count and sizes as u32 LE, then each layer's
+    // weights and biases as f32 LE bits (-0.0 folded into 0.0, every NaN into one bit pattern).
+    // Missing weights/biases encode as empty layers.
+    pub fn canonical_hash(&self) -> u64 {
+        fn put_f32s(buf: &mut Vec<u8>, vals: &[f32]) {
+            buf.extend_from_slice(&(vals.len() as u32).to_le_bytes());
+            for &v in vals {
+                let bits = if v.is_nan() { f32::NAN.to_bits() } else if v == 0.0 { 0 } else { v.to_bits() };
+                buf.extend_from_slice(&bits.to_le_bytes());
+            }
+        }
+        let mut buf: Vec<u8> = Vec::new();
+        buf.extend_from_slice(&(self.layer_sizes.len() as u32).to_le_bytes());
+        for &n in &self.layer_sizes { buf.extend_from_slice(&n.to_le_bytes()); }
+        let empty: Vec<Vec<f32>> = Vec::new();
+        let weights = self.weights.as_ref().unwrap_or(&empty);
+        let biases = self.biases.as_ref().unwrap_or(&empty);
+        for li in 0..self.layer_sizes.len().saturating_sub(1) {
+            put_f32s(&mut buf, weights.get(li).map(|w| &w[..]).unwrap_or(&[]));
+            put_f32s(&mut buf, biases.get(li).map(|b| &b[..]).unwrap_or(&[]));
+        }
+        xxhash_rust::xxh64::xxh64(&buf, 0)
+    }
+
+    // Any NaN or infinite weight or bias
+    pub fn has_non_finite(&self) -> bool {
+        self.weights.iter().chain(self.biases.iter()).flatten().flatten().any(|v| !v.is_finite())
+    }
+
+    // Weights and biases present and sized to layer_sizes (row-major n_out x n_in per layer)
+    pub fn validate(&self) -> Result<(), BrainError> {
+        let ls = &self.layer_sizes;
+        if ls.len() < 2 { return Err(BrainError::TooFewLayers { layers: ls.len() as u32 }); }
+        let (Some(weights), Some(biases)) = (&self.weights, &self.biases) else { return Err(BrainError::MissingParameters); };
+        if weights.len() != ls.len() - 1 || biases.len() != ls.len() - 1 {
+            return Err(BrainError::LayerCount { expected: ls.len() as u32 - 1, weights: weights.len() as u32, biases: biases.len() as u32 });
+        }
+        for li in 1..ls.len() {
+            let (w, b) = (weights[li - 1].len() as u32, biases[li - 1].len() as u32);
+            let expected = ls[li - 1].saturating_mul(ls[li]);
+            if w != expected { return Err(BrainError::WeightLength { layer: li as u32, expected, actual: w }); }
+            if b != ls[li] { return Err(BrainError::BiasLength { layer: li as u32, expected: ls[li], actual: b }); }
+        }
+        Ok(())
+    }
+
+    pub fn is_well_formed(&self) -> bool { self.validate().is_ok() }
+
+    // Zero NaN parameters and clamp the rest (including infinities) to +/- limit; returns how many changed
+    pub fn sanitize(&mut self, limit: f32) -> u32 {
+        let limit = limit.abs();
+        let mut fixed = 0;
+        for v in self.weights.iter_mut().chain(self.biases.iter_mut()).flatten().flatten() {
+            let clean = if v.is_nan() { 0.0 } else { v.clamp(-limit, limit) };
+            if clean.to_bits() != v.to_bits() { *v = clean; fixed += 1; }
+        }
+        fixed
+    }
+}
+
+// Same-topology brains packed for batched evaluation (see World::brain_batches). Per layer,
+// weights are [count][n_out][n_in] and biases [count][n_out]; inputs are [count][n_in].
+// Hidden layers use ReLU and the output layer tanh, as in brain_forward.
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrainBatch {
+    pub layer_sizes: Vec<u32>,
+    pub ids: Vec<String>,
+    pub weights: Vec<Vec<f32>>,
+    pub biases: Vec<Vec<f32>>,
+    pub inputs: Vec<f32>,
+}
+
+impl BrainBatch {
+    pub(crate) fn new(layer_sizes: Vec<u32>) -> BrainBatch {
+        let layers = layer_sizes.len().saturating_sub(1);
+        BrainBatch { layer_sizes, ids: Vec::new(), weights: vec![Vec::new(); layers], biases: vec![Vec::new(); layers], inputs: Vec::new() }
+    }
+
+    // Caller guarantees the brain is well formed and matches layer_sizes
+    pub(crate) fn push(&mut self, id: &str, brain: &Brain, inputs: &[f32]) {
+        let (Some(weights), Some(biases)) = (&brain.weights, &brain.biases) else { return; };
+        self.ids.push(id.to_string());
+        for (dst, src) in self.weights.iter_mut().zip(weights) { dst.extend_from_slice(src); }
+        for (dst, src) in self.biases.iter_mut().zip(biases) { dst.extend_from_slice(src); }
+        self.inputs.extend_from_slice(inputs);
+    }
+}
+
+// Why a brain was flagged as degenerate
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum DegenerateReason { NonFinite, AllZero, Saturated, Flat }
+
+// What happens to a creature once its brain is flagged (Config::degenerate_action)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum DegenerateAction { #[default] Flag, Reroll, Mutate }
+
+// Per-creature output statistics over the current detection window
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutputWatch {
+    ticks: u32,
+    min: Vec<f32>,
+    max: Vec<f32>,
+    all_zero: bool,
+    all_saturated: bool,
+}
+
+impl OutputWatch {
+    pub(crate) fn heap_bytes(&self) -> usize { (self.min.capacity() + self.max.capacity()) * std::mem::size_of::<f32>() }
+
+    // Feed one tick of outputs. Non-finite weights/outputs are reported at once; the other
+    // checks are judged (and the window restarted) every `window` ticks (0 disables them).
+    pub(crate) fn observe(&mut self, brain: &Brain, out: &[f32], window: u32) -> Option<DegenerateReason> {
+        if out.iter().any(|v| !v.is_finite()) || (self.ticks == 0 && brain.has_non_finite()) {
+            return Some(DegenerateReason::NonFinite);
+        }
+        if window == 0 { return None; }
+        if self.ticks == 0 {
+            *self = OutputWatch { ticks: 0, min: out.to_vec(), max: out.to_vec(), all_zero: true, all_saturated: true };
+        }
+        for ((lo, hi), &v) in self.min.iter_mut().zip(self.max.iter_mut()).zip(out) {
+            *lo = lo.min(v);
+            *hi = hi.max(v);
+        }
+        self.all_zero &= out.iter().all(|v| v.abs() < 1e-3);
+        self.all_saturated &= out.iter().all(|v| v.abs() > 0.999);
+        self.ticks += 1;
+        if self.ticks < window { return None; }
+        let flat = self.min.iter().zip(&self.max).all(|(lo, hi)| hi - lo < 1e-4);
+        let reason = if self.all_zero { Some(DegenerateReason::AllZero) }
+            else if self.all_saturated { Some(DegenerateReason::Saturated) }
+            else if flat { Some(DegenerateReason::Flat) }
+            else { None };
+        *self = OutputWatch::default();
+        reason
+    }
+}
+
+// Flag a degenerate brain: add its hash to the bad set and apply `action`. Returns the flagged hash.
+pub(crate) fn handle_degenerate(c: &mut Creature, action: DegenerateAction, sigma: f32, rng: &mut RngLCG, bad: &mut HashSet<String>, legacy_hash: bool) -> String {
+    let hash = brain_hash(&c.brain, legacy_hash);
+    bad.insert(hash.clone());
+    c.output_watch = OutputWatch::default();
+    match action {
+        DegenerateAction::Flag => c.degenerate_brain = true,
+        DegenerateAction::Reroll => {
+            c.brain = init_brain_avoiding_bad(c.brain.layer_sizes.clone(), rng, bad, legacy_hash);
+            c.learned_weights.clear();
+        }
+        DegenerateAction::Mutate => {
+            for layer in c.brain.weights.iter_mut().chain(c.brain.biases.iter_mut()).flatten() {
+                for v in layer.iter_mut() {
+                    *v = if v.is_finite() { *v + rng.normal() * sigma } else { rng.normal() * sigma };
+                }
+            }
+        }
+    }
+    hash
+}
+
+// Which hidden neurons produced a positive activation since the last pruning pass
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NeuronActivity {
+    ticks: u32,
+    fired: Vec<Vec<bool>>,
+}
+
+impl NeuronActivity {
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.fired.capacity() * std::mem::size_of::<Vec<bool>>() + self.fired.iter().map(|f| f.capacity()).sum::<usize>()
+    }
+
+    // Record one forward pass (`acts` as returned by brain_forward); a topology change restarts the window
+    pub(crate) fn observe(&mut self, acts: &[Vec<f32>]) {
+        let hidden = acts.get(1..acts.len().saturating_sub(1)).unwrap_or(&[]);
+        if self.fired.len() != hidden.len() || self.fired.iter().zip(hidden).any(|(f, a)| f.len() != a.len()) {
+            self.fired = hidden.iter().map(|a| vec![false; a.len()]).collect();
+            self.ticks = 0;
+        }
+        for (f, a) in self.fired.iter_mut().zip(hidden) {
+            for (fi, &v) in f.iter_mut().zip(a) { *fi |= v > 0.0; }
+        }
+        self.ticks += 1;
+    }
+}
+
+// How the pruning pass treats hidden neurons that never fired (Config::prune_mode)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum PruneMode { #[default] Reinit, Remove }
+
+// Re-initialize or remove dead hidden neurons, then zero weights smaller than `sparsify_below`
+// (0 disables). Restarts the activity window. Returns (neurons touched, weights zeroed).
+pub(crate) fn prune_brain(brain: &mut Brain, activity: &mut NeuronActivity, mode: PruneMode, sparsify_below: f32, rng: &mut RngLCG) -> (u32, u32) {
+    let mut touched = 0;
+    let matches = brain.is_well_formed()
+        && activity.fired.len() + 2 == brain.layer_sizes.len()
+        && activity.fired.iter().zip(&brain.layer_sizes[1..]).all(|(f, &n)| f.len() == n as usize);
+    if activity.ticks > 0 && matches {
+        // Back to front so removals never shift indices still to be visited
+        for hl in (0..activity.fired.len()).rev() {
+            for j in (0..activity.fired[hl].len()).rev() {
+                if activity.fired[hl][j] { continue; }
+                match mode {
+                    PruneMode::Reinit => { reinit_neuron(brain, hl + 1, j, rng); touched += 1; }
+                    PruneMode::Remove if brain.layer_sizes[hl + 1] > 1 => { remove_neuron(brain, hl + 1, j); touched += 1; }
+                    PruneMode::Remove => {}
+                }
+            }
+        }
+    }
+    *activity = NeuronActivity::default();
+    let mut zeroed = 0;
+    if sparsify_below > 0.0 {
+        for w in brain.weights.iter_mut().flatten().flatten() {
+            if *w != 0.0 && w.abs() < sparsify_below { *w = 0.0; zeroed += 1; }
+        }
+    }
+    (touched, zeroed)
+}
+
+// Fresh He-scaled incoming weights and a zero bias for neuron `j` of layer `li`
+fn reinit_neuron(brain: &mut Brain, li: usize, j: usize, rng: &mut RngLCG) {
+    let n_in = brain.layer_sizes[li - 1] as usize;
+    let scale = (2.0f32 / (n_in as f32).max(1.0)).sqrt();
+    if let (Some(weights), Some(biases)) = (brain.weights.as_mut(), brain.biases.as_mut()) {
+        for w in &mut weights[li - 1][j * n_in..(j + 1) * n_in] { *w = rng.uniform(-1.0, 1.0) * scale; }
+        biases[li - 1][j] = 0.0;
+    }
+}
+
+// Drop hidden neuron `j` of layer `li`: its incoming row, its bias, and its column in the next layer
+pub(crate) fn remove_neuron(brain: &mut Brain, li: usize, j: usize) {
+    let n_in = brain.layer_sizes[li - 1] as usize;
+    let n_here = brain.layer_sizes[li] as usize;
+    let n_next = brain.layer_sizes[li + 1] as usize;
+    if let (Some(weights), Some(biases)) = (brain.weights.as_mut(), brain.biases.as_mut()) {
+        weights[li - 1].drain(j * n_in..(j + 1) * n_in);
+        biases[li - 1].remove(j);
+        for row in (0..n_next).rev() { weights[li].remove(row * n_here + j); }
+    }
+    brain.layer_sizes[li] -= 1;
+}
+
+// Brain helpers
+fn init_brain(layer_sizes: Vec<u32>, rng: &mut RngLCG) -> Brain {
+    let mut weights: Vec<Vec<f32>> = Vec::new();
+    let mut biases: Vec<Vec<f32>> = Vec::new();
+    // For L layers where layer_sizes = [n0, n1, ..., n_{L-1}]
+    for li in 1..layer_sizes.len() {
+        let n_in = layer_sizes[li-1] as usize;
+        let n_out = layer_sizes[li] as usize;
+        // He-like init
+        let scale = (2.0f32 / (n_in as f32).max(1.0)).sqrt();
+        let mut w: Vec<f32> = Vec::with_capacity(n_in * n_out);
+        for oi in 0..n_out { let _ = oi; for _ in 0..n_in { w.push((rng.uniform(-1.0, 1.0)) * scale); } }
+        let b: Vec<f32> = vec![0.0; n_out];
+        weights.push(w);
+        biases.push(b);
+    }
+    Brain { layer_sizes, weights: Some(weights), biases: Some(biases), activations: None }
+}
+
+// Weights plus biases actually held by a brain (the nervous-system cost basis)
+pub(crate) fn brain_param_count(brain: &Brain) -> usize {
+    let count = |v: &Option<Vec<Vec<f32>>>| v.as_ref().map_or(0, |outer| outer.iter().map(Vec::len).sum());
+    count(&brain.weights) + count(&brain.biases)
+}
+
+// Heap bytes owned by a brain's layer sizes, weights, biases, and cached activations
+pub(crate) fn brain_heap_bytes(brain: &Brain) -> usize {
+    fn nested(v: &Option<Vec<Vec<f32>>>) -> usize {
+        v.as_ref().map(|outer| {
+            outer.capacity() * std::mem::size_of::<Vec<f32>>()
+                + outer.iter().map(|inner| inner.capacity() * std::mem::size_of::<f32>()).sum::<usize>()
+        }).unwrap_or(0)
+    }
+    brain.layer_sizes.capacity() * std::mem::size_of::<u32>()
+        + nested(&brain.weights)
+        + nested(&brain.biases)
+        + nested(&brain.activations)
+}
+
+// Initialize a brain, retrying a limited number of times if the hash is in the bad set
+pub(crate) fn init_brain_avoiding_bad(layer_sizes: Vec<u32>, rng: &mut RngLCG, bad: &HashSet<String>, legacy_hash: bool) -> Brain {
+    const MAX_TRIES: usize = 16;
+    let mut last = init_brain(layer_sizes.clone(), rng);
+    if bad.is_empty() { return last; }
+    for _ in 0..MAX_TRIES {
+        let h = brain_hash(&last, legacy_hash);
+        if !bad.contains(&h) { return last; }
+        last = init_brain(layer_sizes.clone(), rng);
+    }
+    last
+}
+
+// JS-simpleHash parity: 32-bit rolling hash over JSON text, then to radix36 string
+fn simple_hash_str(s: &str) -> String {
+    let mut hash: i32 = 0;
+    for &b in s.as_bytes() {
+        hash = (hash << 5).wrapping_sub(hash).wrapping_add(b as i32);
+    }
+    let mut n: u32 = hash as u32; // two's complement cast like JS |0 then >>>0
+    // convert to radix36
+    if n == 0 { return "0".to_string(); }
+    let mut digits = Vec::new();
+    while n > 0 {
+        let rem = (n % 36) as u8;
+        n /= 36;
+        let ch = if rem < 10 { (b'0' + rem) as char } else { (b'a' + (rem - 10)) as char };
+        digits.push(ch);
+    }
+    digits.iter().rev().collect()
+}
+
+// Hex brain hash used for the bad-brain set (canonical xxHash64 unless the legacy scheme is requested)
+pub(crate) fn brain_hash(brain: &Brain, legacy: bool) -> String {
+    if legacy { legacy_brain_hash(brain) } else { format!("{:016x}", brain.canonical_hash()) }
+}
+
+fn legacy_brain_hash(brain: &Brain) -> String {
+    // Serialize the same canonical fields as JS does
+    #[derive(Serialize)]
+    struct Canon<'a> { layer_sizes: &'a Vec<u32>, weights: &'a Vec<Vec<f32>>, biases: &'a Vec<Vec<f32>> }
+    // Missing weights/biases hash as empty layers, as in canonical_hash
+    let empty = vec![Vec::new(); brain.layer_sizes.len().saturating_sub(1)];
+    let weights = brain.weights.as_ref().unwrap_or(&empty);
+    let biases = brain.biases.as_ref().unwrap_or(&empty);
+    let canon = Canon { layer_sizes: &brain.layer_sizes, weights, biases };
+    let json = serde_json::to_string(&canon).unwrap_or_else(|_| String::new());
+    simple_hash_str(&json)
+}
+
+pub(crate) fn build_inputs(env: &SenseEnv, c: &Creature, a: &[Creature], b: &[Creature]) -> Vec<f32> {
+    let mut v = mode_inputs(env, c, a, b);
+    if env.layout.resources { resource_inputs(env, c, &mut v); }
+    if env.layout.memory { memory_inputs(env, c, &mut v); }
+    if env.layout.scent { let (gx, gy) = env.scent.gradient(c.x, c.y); v.extend([gx, gy]); }
+    if env.layout.multi_target { multi_target_inputs(env, c, a, b, &mut v); }
+    if env.layout.migration { migration_inputs(env, c, &mut v); }
+    v
+}
+
+// The brain mode's built-in senses
+fn mode_inputs(env: &SenseEnv, c: &Creature, a: &[Creature], b: &[Creature]) -> Vec<f32> {
+    let (width, height, tick, mode) = (env.width, env.height, env.tick, env.layout.mode);
+    // Common features
+    let nx = c.x / width;
+    let ny = c.y / height;
+    let spx = c.vx.tanh();
+    let spy = c.vy.tanh();
+    let e = (c.energy / 100.0).clamp(0.0, 1.0);
+    let h = (c.health / 100.0).clamp(0.0, 1.0);
+    let t = (tick as f32) * 0.01;
+    let ts = f32::sin(t);
+    let tc = f32::cos(t);
+    // Nearest herbivore vector (nothing beyond sight range)
+    let sight = env.sight(c);
+    let in_sight = |&(x, y): &(f32, f32)| (x - c.x).hypot(y - c.y) <= sight;
+    let target = nearest_herbivore(c.x, c.y, a, b).filter(in_sight);
+    let (dxn, dyy, dd) = if let Some((tx, ty)) = target {
+        let dx = tx - c.x; let dy = ty - c.y; let d = (dx*dx + dy*dy).sqrt().max(0.0001);
+        (dx / d, dy / d, (d / width.max(height)).clamp(0.0, 1.0))
+    } else { (0.0, 0.0, 1.0) };
+    // Channel order and ranges are described by sensing::io_schema; keep the two in sync
+    let mut v = vec![nx, ny, spx, spy, e, h, ts, tc, dxn, dyy, dd];
+    match mode {
+        BrainMode::OG => {
+            // Add bias + pad to 14
+            v.push(1.0);
+            let need = 14usize;
+            if v.len() < need { v.extend(std::iter::repeat_n(0.0, need - v.len())); }
+            v.truncate(need);
+            v
+        }
+        BrainMode::Zegion => {
+            // Nearest carnivore
+            let pred = nearest_carnivore(c.x, c.y, a, b).filter(in_sight);
+            let (dxn2, dyn2, dd2) = if let Some((px, py)) = pred {
+                let dx = px - c.x; let dy = py - c.y; let d = (dx*dx + dy*dy).sqrt().max(0.0001);
+                (dx / d, dy / d, (d / width.max(height)).clamp(0.0, 1.0))
+            } else { (0.0, 0.0, 1.0) };
+            let rough = env.terrain_speed(c.x, c.y);
+            let rough_n = (rough - 0.6) / 0.4; // 0..1 -> normalize
+            let speed_mag = (c.vx * c.vx + c.vy * c.vy).sqrt().tanh();
+            let dot_herb = spx * dxn + spy * dyy;
+            let dot_carn = spx * dxn2 + spy * dyn2;
+            let ts2 = f32::sin(t * 0.37 + c.x * 0.0007 + c.y * 0.0009);
+            let tc2 = f32::cos(t * 0.41 - c.x * 0.0006 + c.y * 0.0011);
+            let diet_carn = if c.diet == Diet::Carnivore { 1.0 } else { 0.0 };
+            let inv_e = (1.0 - e).clamp(0.0, 1.0);
+            v.extend([dxn2, dyn2, dd2, rough, rough_n, speed_mag, dot_herb, dot_carn, ts2, tc2, diet_carn, inv_e, 1.0]);
+            // Now ensure length is 24
+            let need = 24usize;
+            if v.len() < need { v.extend(std::iter::repeat_n(0.0, need - v.len())); }
+            v.truncate(need);
+            v
+        }
+    }
+}
+
+pub(crate) fn brain_forward(brain: &mut Brain, inputs: &[f32], mode: BrainMode) -> Result<(Vec<f32>, Vec<Vec<f32>>), BrainError> {
+    brain.validate()?;
+    let ls = &brain.layer_sizes;
+    if inputs.len() != ls[0] as usize { return Err(BrainError::InputSize { expected: inputs.len() as u32, actual: ls[0] }); }
+    let (Some(weights), Some(biases)) = (&brain.weights, &brain.biases) else { return Err(BrainError::MissingParameters); };
+    let mut acts: Vec<Vec<f32>> = Vec::new();
+    let mut cur = inputs.to_vec();
+    acts.push(cur.clone());
+    for li in 1..ls.len() {
+        let n_in = ls[li-1] as usize;
+        let n_out = ls[li] as usize;
+        let w = &weights[li-1];
+        let b = &biases[li-1];
+        let mut next = vec![0.0f32; n_out];
+        for o in 0..n_out {
+            let mut sum = b[o];
+            let base = o * n_in;
+            for ii in 0..n_in { sum += w[base + ii] * cur[ii]; }
+            next[o] = match (mode, li == ls.len()-1) {
+                // Hidden layers: ReLU
+                (_, false) => if sum > 0.0 { sum } else { 0.0 },
+                // Output layer: tanh
+                (_, true) => sum.tanh(),
+            };
+        }
+        acts.push(next.clone());
+        cur = next;
+    }
+    Ok((cur.clone(), acts))
+}
+
+pub(crate) fn nearest_herbivore(x: f32, y: f32, a: &[Creature], b: &[Creature]) -> Option<(f32,f32)> {
+    let mut best_d2 = f32::INFINITY;
+    let mut best = None;
+    for c in a.iter().chain(b.iter()) {
+        // Burrowed creatures are hidden from predators
+        if c.diet == Diet::Herbivore && c.burrow_id.is_none() {
+            let dx = c.x - x; let dy = c.y - y;
+            let d2 = dx*dx + dy*dy;
+            if d2 < best_d2 { best_d2 = d2; best = Some((c.x, c.y)); }
+        }
+    }
+    best
+}
+
+// Index (into a then b) of the nearest exposed, living herbivore whose edge is within `reach` of
+// the attacker's
+pub(crate) fn prey_in_reach(c: &Creature, a: &[Creature], b: &[Creature], reach: f32) -> Option<usize> {
+    let mut best: Option<(usize, f32)> = None;
+    for (i, o) in a.iter().chain(b.iter()).enumerate() {
+        if o.diet != Diet::Herbivore || o.burrow_id.is_some() || o.health <= 0.0 { continue; }
+        let (dx, dy) = (o.x - c.x, o.y - c.y);
+        let d2 = dx * dx + dy * dy;
+        let r = c.radius + o.radius + reach.max(0.0);
+        if d2 <= r * r && best.is_none_or(|(_, bd)| d2 < bd) { best = Some((i, d2)); }
+    }
+    best.map(|(i, _)| i)
+}
+
+fn nearest_carnivore(x: f32, y: f32, a: &[Creature], b: &[Creature]) -> Option<(f32,f32)> {
+    let mut best: Option<(f32,f32,f32)> = None;
+    for c in a.iter().chain(b.iter()) {
+        if c.diet != Diet::Carnivore || c.burrow_id.is_some() { continue; }
+        let dx = c.x - x; let dy = c.y - y; let d = (dx*dx + dy*dy).sqrt();
+        match best { Some((_,_,bd)) if d >= bd => {}, _ => { best = Some((c.x, c.y, d)); } }
+    }
+    best.map(|(x,y,_)| (x,y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    // 2 inputs -> 3 hidden -> 1 output
+    fn small_brain() -> Brain {
+        Brain {
+            layer_sizes: vec![2, 3, 1],
+            weights: Some(vec![vec![0.1, -0.2, 0.3, -0.4, 0.5, -0.6], vec![0.7, -0.8, 0.9]]),
+            biases: Some(vec![vec![0.0, 0.1, -0.1], vec![0.2]]),
+            activations: None,
+        }
+    }
+
+    #[test]
+    fn validate_rejects_mismatched_shapes() {
+        assert_eq!(small_brain().validate(), Ok(()));
+        let mut b = small_brain();
+        b.weights.as_mut().unwrap()[1].pop();
+        assert_eq!(b.validate(), Err(BrainError::WeightLength { layer: 2, expected: 3, actual: 2 }));
+        let mut b = small_brain();
+        b.biases.as_mut().unwrap()[0].push(0.0);
+        assert_eq!(b.validate(), Err(BrainError::BiasLength { layer: 1, expected: 3, actual: 4 }));
+        let mut b = small_brain();
+        b.layer_sizes = vec![2, 3, 1, 1];
+        assert_eq!(b.validate(), Err(BrainError::LayerCount { expected: 3, weights: 2, biases: 2 }));
+        let b = Brain { weights: None, ..small_brain() };
+        assert_eq!(b.validate(), Err(BrainError::MissingParameters));
+        let b = Brain { layer_sizes: vec![2], ..small_brain() };
+        assert_eq!(b.validate(), Err(BrainError::TooFewLayers { layers: 1 }));
+    }
+
+    #[test]
+    fn sanitize_zeroes_nan_and_clamps_to_the_weight_limit() {
+        let limit = Config::default().brain_weight_limit;
+        let mut b = small_brain();
+        let w = b.weights.as_mut().unwrap();
+        w[0][0] = f32::NAN;
+        w[0][1] = limit * 3.0;
+        w[1][2] = f32::NEG_INFINITY;
+        b.biases.as_mut().unwrap()[1][0] = -limit - 1.0;
+        assert_eq!(b.sanitize(limit), 4);
+        let w = b.weights.as_ref().unwrap();
+        assert_eq!((w[0][0], w[0][1], w[0][2], w[1][2]), (0.0, limit, 0.3, -limit));
+        assert_eq!(b.biases.as_ref().unwrap()[1][0], -limit);
+        assert!(!b.has_non_finite());
+        // Already clean: nothing changes
+        assert_eq!(b.sanitize(limit), 0);
+    }
+
+    #[test]
+    fn canonical_hash_survives_serialization() {
+        let b = Brain { activations: Some(vec![vec![1.0, 2.0]]), ..small_brain() };
+        let hash = b.canonical_hash();
+        let json: Brain = serde_json::from_str(&serde_json::to_string(&b).unwrap()).unwrap();
+        assert_eq!(json.canonical_hash(), hash);
+        let msgpack: Brain = rmp_serde::from_slice(&rmp_serde::to_vec_named(&b).unwrap()).unwrap();
+        assert_eq!(msgpack.canonical_hash(), hash);
+        // Activations don't count; parameters do
+        assert_eq!(small_brain().canonical_hash(), hash);
+        let mut changed = small_brain();
+        changed.biases.as_mut().unwrap()[1][0] = 0.25;
+        assert_ne!(changed.canonical_hash(), hash);
+    }
+}
@@ -0,0 +1,174 @@
+// Carrying-capacity estimate per biome: how many herbivores the biome's plant regrowth (scaled by
+// seasons and local temperature) could feed at the measured per-herbivore upkeep, discounted by
+// how much of the biome can reach water; carnivores get a trophic share of that. Sampled over
+// time so hosts can tell a population at its ceiling from one crashing below it.
+// Not part of snapshots; the history restarts empty after a restore.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Diet;
+use crate::environment::{can_drink_at, Biome, BIOMES};
+use crate::World;
+
+// Carrying-capacity settings (serialized as the `carryingCapacity` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CarryingCapacityConfig {
+    pub enabled: bool,              // measure upkeep and record capacity_history (the estimate is always available)
+    pub interval_ticks: u32,        // ticks between history samples
+    pub history_len: u32,           // samples kept
+    pub water_reach: f32,           // distance a creature will travel to drink
+    pub trophic_efficiency: f32,    // carnivores supported per herbivore of capacity
+    pub default_upkeep_per_sec: f32, // herbivore energy use assumed until one has been measured
+}
+
+impl Default for CarryingCapacityConfig {
+    fn default() -> Self {
+        Self { enabled: false, interval_ticks: 60, history_len: 240, water_reach: 150.0, trophic_efficiency: 0.1, default_upkeep_per_sec: 0.05 }
+    }
+}
+
+// `load` is population / capacity (None when the capacity is 0): near 1 means the population has
+// reached what the region can feed, above 1 means it is living on borrowed energy
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RegionCapacity {
+    pub biome: Biome,
+    pub area_share: f32,
+    pub plant_supply_per_sec: f32, // biomass regrown per second at current growth conditions
+    pub water_access: f32,         // fraction of the biome within water_reach of drinkable water
+    pub herbivore_capacity: f32,
+    pub carnivore_capacity: f32,
+    pub herbivores: u32,
+    pub carnivores: u32,
+    pub herbivore_load: Option<f32>,
+    pub carnivore_load: Option<f32>,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CarryingCapacity {
+    pub tick: u64,
+    pub upkeep_per_sec: f32, // mean energy a herbivore spends per second
+    pub herbivore_capacity: f32,
+    pub carnivore_capacity: f32,
+    pub herbivores: u32,
+    pub carnivores: u32,
+    pub herbivore_load: Option<f32>,
+    pub carnivore_load: Option<f32>,
+    pub regions: Vec<RegionCapacity>,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacitySample {
+    pub tick: u64,
+    pub herbivore_capacity: f32,
+    pub carnivore_capacity: f32,
+    pub herbivores: u32,
+    pub carnivores: u32,
+}
+
+#[derive(Default)]
+pub(crate) struct CapacityLog {
+    // Smoothed per-herbivore spend, None until herbivores have been measured
+    upkeep: Option<f32>,
+    samples: VecDeque<CapacitySample>,
+}
+
+impl CapacityLog {
+    pub(crate) fn heap_bytes(&self) -> usize { self.samples.capacity() * std::mem::size_of::<CapacitySample>() }
+}
+
+fn load(population: u32, capacity: f32) -> Option<f32> {
+    (capacity > 0.0).then(|| population as f32 / capacity)
+}
+
+impl World {
+    pub fn carrying_capacity(&self) -> CarryingCapacity {
+        let cfg = &self.config.carrying_capacity;
+        let upkeep = self.capacity.upkeep.unwrap_or(cfg.default_upkeep_per_sec).max(1e-6);
+        let trophic = cfg.trophic_efficiency.max(0.0);
+        let mut regions: Vec<RegionCapacity> = BIOMES.iter().map(|&biome| RegionCapacity {
+            biome, area_share: 0.0, plant_supply_per_sec: 0.0, water_access: 0.0, herbivore_capacity: 0.0, carnivore_capacity: 0.0,
+            herbivores: 0, carnivores: 0, herbivore_load: None, carnivore_load: None,
+        }).collect();
+        let slot = |b: Biome| BIOMES.iter().position(|&o| o == b).unwrap_or(0);
+        const N: usize = 32;
+        let (cw, ch) = (self.width / N as f32, self.height / N as f32);
+        let mut cells = [0u32; BIOMES.len()];
+        for gy in 0..N {
+            for gx in 0..N {
+                let (x, y) = ((gx as f32 + 0.5) * cw, (gy as f32 + 0.5) * ch);
+                let k = slot(self.biome_at(x, y));
+                cells[k] += 1;
+                if can_drink_at(x, y, self.height, self.tick, cfg.water_reach.max(0.0), self.config.puddle_rain_threshold) {
+                    regions[k].water_access += 1.0;
+                }
+            }
+        }
+        for (r, &n) in regions.iter_mut().zip(&cells) {
+            r.area_share = n as f32 / (N * N) as f32;
+            r.water_access /= n.max(1) as f32;
+        }
+        let regrow = self.config.plant_regrowth_per_sec.max(0.0);
+        for p in self.plants.iter().filter(|p| p.bloom_until.is_none()) {
+            regions[slot(self.biome_at(p.x, p.y))].plant_supply_per_sec += regrow * self.plant_growth_at(p.x, p.y);
+        }
+        for c in &self.creatures {
+            let r = &mut regions[slot(self.biome_at(c.x, c.y))];
+            if c.diet == Diet::Herbivore { r.herbivores += 1; } else { r.carnivores += 1; }
+        }
+        for r in &mut regions {
+            r.herbivore_capacity = r.plant_supply_per_sec / upkeep * r.water_access;
+            r.carnivore_capacity = r.herbivore_capacity * trophic;
+            r.herbivore_load = load(r.herbivores, r.herbivore_capacity);
+            r.carnivore_load = load(r.carnivores, r.carnivore_capacity);
+        }
+        let herbivore_capacity = regions.iter().map(|r| r.herbivore_capacity).sum();
+        let carnivore_capacity = regions.iter().map(|r| r.carnivore_capacity).sum();
+        let herbivores = regions.iter().map(|r| r.herbivores).sum();
+        let carnivores = regions.iter().map(|r| r.carnivores).sum();
+        CarryingCapacity {
+            tick: self.tick, upkeep_per_sec: upkeep, herbivore_capacity, carnivore_capacity, herbivores, carnivores,
+            herbivore_load: load(herbivores, herbivore_capacity), carnivore_load: load(carnivores, carnivore_capacity), regions,
+        }
+    }
+
+    // Oldest first; empty unless config.carrying_capacity is enabled
+    pub fn capacity_history(&self) -> Vec<CapacitySample> { self.capacity.samples.iter().cloned().collect() }
+
+    // Measure herbivore upkeep from this tick's ledgers and record a sample when the interval comes round
+    pub(crate) fn sample_capacity(&mut self, t_sec: f32) {
+        let cfg = &self.config.carrying_capacity;
+        if !cfg.enabled {
+            if self.capacity.upkeep.is_some() || !self.capacity.samples.is_empty() { self.capacity = CapacityLog::default(); }
+            return;
+        }
+        if !self.tick.is_multiple_of(cfg.interval_ticks.max(1) as u64) || t_sec <= 0.0 { return; }
+        let spend: Vec<f32> = self.creatures.iter()
+            .filter(|c| c.diet == Diet::Herbivore)
+            .map(|c| -c.ledger.0.iter().filter(|&&e| e < 0.0).sum::<f32>() / t_sec)
+            .collect();
+        if !spend.is_empty() {
+            let mean = spend.iter().sum::<f32>() / spend.len() as f32;
+            self.capacity.upkeep = Some(self.capacity.upkeep.map_or(mean, |u| u + 0.2 * (mean - u)));
+        }
+        let len = self.config.carrying_capacity.history_len as usize;
+        let est = self.carrying_capacity();
+        let samples = &mut self.capacity.samples;
+        while !samples.is_empty() && samples.len() >= len { samples.pop_front(); }
+        if len > 0 {
+            samples.push_back(CapacitySample {
+                tick: est.tick, herbivore_capacity: est.herbivore_capacity, carnivore_capacity: est.carnivore_capacity,
+                herbivores: est.herbivores, carnivores: est.carnivores,
+            });
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Corpse handling after the creature pass: carnivores feeding on adjacent corpses, and the CARRY
+// action that latches onto a corpse and drags it along (caching, kleptoparasitism)
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::action_log::CreatureAction;
+use crate::creature::{Corpse, Creature};
+use crate::flags::ActionFlag;
+use crate::World;
+
+// Carrying settings (serialized as the `corpseCarry` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CorpseCarryConfig {
+    pub enabled: bool,         // adds the CARRY output to every brain
+    pub reach: f32,            // max gap between carnivore and corpse for latching on
+    pub drag_per_radius: f32,  // carrier speed is divided by 1 + drag_per_radius * corpse radius
+}
+
+impl Default for CorpseCarryConfig {
+    fn default() -> Self { Self { enabled: false, reach: 4.0, drag_per_radius: 0.08 } }
+}
+
+impl CorpseCarryConfig {
+    // Speed multiplier while dragging a corpse of radius `load` (1 when not carrying)
+    pub(crate) fn drag_mult(&self, load: f32) -> f32 { 1.0 / (1.0 + self.drag_per_radius.max(0.0) * load) }
+}
+
+// Nearest corpse within `reach` of the creature's body edge matching `pick`
+fn nearest_corpse(corpses: &[Corpse], c: &Creature, reach: f32, pick: impl Fn(&Corpse) -> bool) -> Option<usize> {
+    corpses.iter().enumerate()
+        .filter(|(_, co)| pick(co))
+        .map(|(i, co)| (i, (co.x - c.x).hypot(co.y - c.y) - c.radius - co.radius))
+        .filter(|&(_, gap)| gap <= reach)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+impl World {
+    // Carnivores that signalled EAT (indices into self.creatures) draw meat from the nearest
+    // adjacent corpse, their own carried one first
+    pub(crate) fn feed_on_corpses(&mut self, eaters: &[usize], t_sec: f32) {
+        let reach = self.config.corpse_carry.reach.max(0.0);
+        let rate = self.config.meat_intake_per_sec.max(0.0) * t_sec;
+        for &i in eaters {
+            let c = &mut self.creatures[i];
+            let own = self.corpses.iter().position(|co| co.carried_by.as_deref() == Some(c.id.as_str()));
+            let Some(k) = own.or_else(|| nearest_corpse(&self.corpses, c, reach, |co| co.energy_remaining > 0.0)) else { continue; };
+            let co = &mut self.corpses[k];
+            let bite = rate.min(co.energy_remaining);
+            if bite <= 0.0 { continue; }
+            co.energy_remaining -= bite;
+            self.config.diet_specialization.digest(c, false, bite);
+            c.behavior.meat_intake += bite;
+            c.metrics.energy_harvested += bite;
+            c.actions_mask |= ActionFlag::Eating.mask();
+            c.recent_actions.push(self.tick, CreatureAction::AteCorpse, bite);
+            // Rotten meat can carry disease
+            if !c.infected && c.immunity_timer <= 0.0 {
+                let risk = self.config.disease.carrion_risk(co, self.climate.temperature_c(co.x, co.y, self.tick));
+                if risk > 0.0 && self.rng.next_f32_01() < risk * t_sec { c.infected = true; }
+            }
+        }
+    }
+
+    // Resolve CARRY for living creatures: carriers that let go (or died) drop their corpse, the
+    // rest drag it to just behind them, and `carriers` not yet holding one latch onto the nearest
+    // free corpse in reach. Refreshes every creature's carry_load.
+    pub(crate) fn update_carrying(&mut self, carriers: &[String]) {
+        // Living creatures still signalling CARRY
+        let index: HashMap<String, usize> = self.creatures.iter().enumerate()
+            .filter(|(_, c)| carriers.contains(&c.id))
+            .map(|(i, c)| (c.id.clone(), i))
+            .collect();
+        for c in &mut self.creatures { c.carry_load = 0.0; }
+        for co in &mut self.corpses {
+            let Some(id) = co.carried_by.as_deref() else { continue; };
+            match index.get(id) {
+                Some(&i) => {
+                    let c = &mut self.creatures[i];
+                    let speed = c.vx.hypot(c.vy).max(0.0001);
+                    let gap = c.radius + co.radius;
+                    co.x = c.x - c.vx / speed * gap;
+                    co.y = c.y - c.vy / speed * gap;
+                    c.carry_load = co.radius;
+                }
+                None => co.carried_by = None,
+            }
+        }
+        let reach = self.config.corpse_carry.reach.max(0.0);
+        for id in carriers {
+            let Some(&i) = index.get(id.as_str()) else { continue; };
+            if self.creatures[i].carry_load > 0.0 { continue; }
+            let Some(k) = nearest_corpse(&self.corpses, &self.creatures[i], reach, |co| co.carried_by.is_none()) else { continue; };
+            self.corpses[k].carried_by = Some(id.clone());
+            self.creatures[i].carry_load = self.corpses[k].radius;
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Field-name casing policy: every exported type serializes camelCase (matching Config and Brain).
+// Entity arrays can still be exported with the snake_case keys they used before
+// (Config::legacy_snake_case_entities) for consumers that have not migrated yet.
+
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+
+// Entity array exported under the configured casing
+pub struct EntityExport<'a, T: ?Sized> {
+    value: &'a T,
+    legacy: bool,
+}
+
+impl<T: Serialize + ?Sized> Serialize for EntityExport<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !self.legacy { return self.value.serialize(serializer); }
+        let mut v = serde_json::to_value(self.value).map_err(S::Error::custom)?;
+        rekey_entities(&mut v, camel_to_snake);
+        v.serialize(serializer)
+    }
+}
+
+impl crate::World {
+    // Wrap creatures/plants/corpses/elites/burrows for export, honouring legacy_snake_case_entities
+    pub fn export_entities<'a, T: Serialize + ?Sized>(&self, value: &'a T) -> EntityExport<'a, T> {
+        EntityExport { value, legacy: self.config.legacy_snake_case_entities }
+    }
+}
+
+// Rename the top-level keys of each object in an entity array (nested Brain/Genes/metrics keep theirs)
+pub(crate) fn rekey_entities(value: &mut serde_json::Value, rename: fn(&str) -> String) {
+    let Some(items) = value.as_array_mut() else { return };
+    for item in items {
+        if let Some(obj) = item.as_object_mut() {
+            *obj = std::mem::take(obj).into_iter().map(|(k, v)| (rename(&k), v)).collect();
+        }
+    }
+}
+
+pub(crate) fn camel_to_snake(key: &str) -> String {
+    let mut out = String::with_capacity(key.len() + 4);
+    for ch in key.chars() {
+        if ch.is_ascii_uppercase() {
+            out.push('_');
+            out.push(ch.to_ascii_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+pub(crate) fn snake_to_camel(key: &str) -> String {
+    let mut out = String::with_capacity(key.len());
+    let mut upper = false;
+    for ch in key.chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            out.push(ch.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
@@ -0,0 +1,607 @@
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Deserialize};
+
+use crate::aging::SenescenceConfig;
+use crate::balancer::AutoBalanceConfig;
+use crate::blooms::BloomConfig;
+use crate::brain::{DegenerateAction, PruneMode};
+use crate::carrying::CorpseCarryConfig;
+use crate::currents::WaterCurrentConfig;
+use crate::decomposers::DecomposerConfig;
+use crate::capacity::CarryingCapacityConfig;
+use crate::density::DensityConfig;
+use crate::diet::DietSpecializationConfig;
+use crate::food::FoodItemConfig;
+use crate::disease::DiseaseConfig;
+use crate::growth::GrowthConfig;
+use crate::harvest::DEFAULT_PLANT_BIOMASS;
+use crate::inbreeding::InbreedingConfig;
+use crate::memory::SpatialMemoryConfig;
+use crate::migration::MigrationConfig;
+use crate::scent::ScentConfig;
+use crate::seasons::SeasonConfig;
+use crate::sex::SexConfig;
+use crate::sharing::EnergySharingConfig;
+use crate::torpor::TorporConfig;
+use crate::trails::TrailConfig;
+use crate::novelty::NoveltyConfig;
+use crate::plasticity::PlasticityConfig;
+use crate::pregnancy::PregnancyConfig;
+
+// Simulation cost configuration (subset mirrored from JS simulationParams)
+// Missing fields fall back to defaults so older JS payloads keep deserializing.
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Config {
+    pub rest_stamina_regen_per_sec: f32,
+    pub rest_health_regen_per_sec: f32,
+    pub harvest_plant_action_cost_per_second: f32,
+    // Plant biomass: energy a creature can bite off per second, per-plant cap, and regrowth
+    pub harvest_rate_per_sec: f32,
+    pub plant_max_biomass: f32,
+    pub plant_regrowth_per_sec: f32,
+    // Yearly temperature cycle; plant regrowth and germination follow temperature and season
+    pub seasons: SeasonConfig,
+    // Scheduled blooms: a biome briefly fills with high-biomass flowering plants
+    pub blooms: BloomConfig,
+    // Opposite seasonal swings in the north and south halves, a remembered best region, and quadrant movement tallies
+    pub migration: MigrationConfig,
+    pub attack_cost_per_hit_stamina: f32,
+    pub sprint_overflow_cost_per_sec: f32,
+    pub posture_cost_per_sec: f32,
+    pub attack_cost_per_hit_energy: f32,
+    // Predation: a boosting carnivore bites the nearest herbivore whose edge is within attack_reach of
+    // its own, taking attack_damage_per_sec health (DeathCause::Predation when it kills). Bites are
+    // harmless by default, so attacks are only tallied until a world opts into damage.
+    pub attack_reach: f32,
+    pub attack_damage_per_sec: f32,
+    // A predation death within this many ticks of the latest bite counts as the biter's kill
+    pub kill_credit_window_ticks: u32,
+    pub thirst_threshold: f32,
+    pub thirst_recovery_per_sec: f32,
+    pub drink_cost_per_second: f32,
+    // Thirst loss per second: base, per 10 C above comfort_high_c, and per unit speed; health
+    // damage per second while thirst is 0
+    pub thirst_decay_per_sec: f32,
+    pub thirst_heat_coeff: f32,
+    pub thirst_exertion_coeff: f32,
+    pub dehydration_damage_per_sec: f32,
+    pub drink_reach: f32,           // max distance beyond the body to water that can be drunk
+    pub puddle_rain_threshold: f32, // rain (0..1) above which puddles make any spot drinkable
+    pub move_cost_coeff_per_speed_per_sec: f32,
+    // Nervous-system upkeep: energy per second per brain weight and bias (0 = brains are free), so
+    // bigger brains only pay off when they earn their keep
+    pub brain_cost_per_param_per_sec: f32,
+    pub ambient_health_decay_per_sec: f32,
+    // Health regained per second while energy is above the threshold, paid for from that surplus
+    pub regen_energy_threshold: f32,
+    pub regen_health_per_sec: f32,
+    pub regen_energy_per_health: f32,
+    // Energy debt: gestation, birth, and mutation costs may push energy below 0, down to this floor
+    // (<= 0). By default creatures still starve at 0 energy; with survive_energy_debt they live on
+    // in debt, losing health at the penalty rate, and starve only at the floor.
+    pub energy_debt_floor: f32,
+    pub energy_debt_health_penalty_per_sec: f32,
+    pub survive_energy_debt: bool,
+    pub aging_health_decay_coeff: f32,
+    // Lifespan: aging decay reaches its peak at max_life ticks scaled by the heritable longevity gene
+    pub max_life: f32,
+    // Old-age decline of speed, stamina regeneration, and perception past an onset age
+    pub senescence: SenescenceConfig,
+    pub longevity_initial_sigma: f32, // spread of founders' longevity around 1
+    pub longevity_mutation_prob: f32,
+    pub longevity_mutation_sigma: f32,
+    pub gestation_base_cost_per_sec: f32,
+    pub gestation_cost_per_offspring_per_sec: f32,
+    pub gestation_period: f32, // measured in 'frames' units since we advance timer by dt*60
+    pub birth_event_cost_energy: f32,
+    // Birth placement: newborns avoid water unless the parent's swim_skill reaches aquatic_swim_skill
+    pub aquatic_swim_skill: f32,
+    pub birth_placement_attempts: u32,
+    // Conception preconditions (Creature::can_conceive); the cooldown is in gestation_period units
+    pub gestation_min_energy: f32,
+    pub gestation_min_health: f32,
+    pub gestation_min_age: u32, // ticks
+    pub postpartum_cooldown: f32,
+    // Late pregnancy slows the mother and raises gestation costs; starving mothers miscarry
+    pub pregnancy: PregnancyConfig,
+    // Mutation cost per birth: base plus per_std_change times each newborn's realized mutation
+    // magnitude (gene changes plus the L2 norm of its inherited brain's weight changes)
+    pub mutation_cost_energy_base: f32,
+    pub mutation_cost_per_std_change: f32,
+    // Litter size (heritable litter_size gene, 1..max_litter_size; founders start at 1 and mutation
+    // explores larger litters). Each newborn starts with newborn_energy / n^litter_size_tradeoff
+    // energy and a radius shrunk to match that share.
+    pub max_litter_size: u32,
+    pub litter_size_mutation_prob: f32,
+    pub litter_size_mutation_sigma: f32,
+    pub newborn_energy: f32,
+    pub newborn_radius: f32,
+    pub litter_size_tradeoff: f32,
+    // Newborns grow toward their adult size by investing part of their food intake
+    pub growth: GrowthConfig,
+    // Movement limits per tick (0 disables): velocity magnitude and per-tick velocity change, both
+    // scaled by the heritable agility gene
+    pub max_speed: f32,
+    pub max_accel: f32,
+    pub agility_initial_sigma: f32, // spread of founders' agility around 1 (0 keeps everyone at 1)
+    pub agility_mutation_prob: f32,
+    pub agility_mutation_sigma: f32,
+    // Telemetry thresholds (parity with JS simulationParams)
+    pub hunger_energy_threshold: f32,
+    pub fatigue_stamina_threshold: f32,
+    pub movement_threshold: f32,
+    pub stagnant_ticks_limit: u32,
+    // Environmental costs (parity scaffolding)
+    pub swim_energy_cost_per_sec: f32,
+    pub wind_drag_coeff: f32,
+    pub temp_cold_penalty_per_sec: f32,
+    pub temp_heat_penalty_per_sec: f32,
+    pub comfort_low_c: f32,
+    pub comfort_high_c: f32,
+    pub humidity_dehydration_coeff_per_sec: f32,
+    pub humidity_threshold: f32,
+    pub oxygen_thin_air_penalty_per_sec: f32,
+    pub thin_air_elevation_cutoff01: f32,
+    pub altitude_acclimatization_per_sec: f32, // how fast acclimatization tracks the local excess altitude
+    pub altitude_acclimatization_relief: f32,  // share of the oxygen penalty removed when fully acclimatized
+    pub noise_stress_penalty_per_sec: f32,
+    pub disease_energy_drain_per_sec: f32, // paid by everyone, or only the infected with `disease` on
+    // Contagion driven by contact, humidity, and crowding
+    pub disease: DiseaseConfig,
+    pub slope_uphill_cost_per_sec: f32, // per unit grade per unit speed
+    pub slope_speed_penalty: f32,       // speed lost per unit uphill grade
+    pub slope_downhill_boost: f32,      // speed gained per unit downhill grade
+    // Swimming (scaled by the heritable swim_skill gene)
+    pub swim_skill_cost_reduction: f32, // fraction of swim cost removed at skill 1
+    pub swim_speed_min_mult: f32,       // water speed multiplier at skill 0
+    pub swim_stamina_drain_per_sec: f32, // at skill 0; scales with (1 - skill)
+    pub drowning_damage_per_sec: f32,   // health loss in water once stamina is 0
+    pub swim_skill_mutation_prob: f32, // chance the gene mutates at all per birth
+    pub swim_skill_mutation_sigma: f32,
+    // Downstream flow in the water bands, pushing swimmers and drifting corpses
+    pub water_currents: WaterCurrentConfig,
+    // Flight (can_fly gene)
+    pub flyer_initial_fraction: f32,
+    pub flight_gene_flip_prob: f32,
+    pub flight_energy_cost_per_sec: f32,
+    pub flight_climb_rate_per_sec: f32, // altitude units (0..1) per second
+    // Scavenging: energy a carnivore eating next to a corpse draws from it
+    pub meat_intake_per_sec: f32,
+    // Realized plant/meat mix per creature; specialists digest their usual food better
+    pub diet_specialization: DietSpecializationConfig,
+    // CARRY action: carnivores drag corpses to cache them
+    pub corpse_carry: CorpseCarryConfig,
+    // Colonies that turn corpses and plant litter into soil fertility
+    pub decomposers: DecomposerConfig,
+    // Loose food items: decay, cap, and fruit dropped by fully grown plants
+    pub food_items: FoodItemConfig,
+    // Hibernation: the TORPOR action slashes metabolic costs for a long, slow-to-wake bout
+    pub torpor: TorporConfig,
+    // Burrowing
+    pub burrow_dig_cost_energy: f32,
+    pub burrow_radius: f32,
+    pub burrow_temp_shelter: f32, // fraction of cold/heat penalty removed while burrowed
+    pub max_burrows: u32,
+    // Frame pacing: step() clamps real dt to max_frame_dt_sec (a backgrounded tab or lag spike
+    // resumes instead of lurching) and splits the scaled dt into ticks of at most max_substep_dt_sec,
+    // up to max_substeps per call. Past that the remaining time is dropped, so ticks never lengthen.
+    pub max_frame_dt_sec: f32,
+    pub max_substep_dt_sec: f32,
+    pub max_substeps: u32,
+    // Serve entity exports from a copy of the last completed step() (consistent frames for hosts
+    // that read concurrently, at the cost of one copy of the entity arrays per step)
+    pub double_buffered_exports: bool,
+    // Rewind snapshot ring (0 disables). Off by default: a rewind scrubber enables it by setting a
+    // capacity, and the horizon starts at the tick it was switched on.
+    pub snapshot_every_ticks: u32,
+    pub snapshot_capacity: u32,
+    // Lockstep: number of recent per-tick state hashes to keep (0 disables per-tick hashing)
+    pub state_hash_history: u32,
+    // Applied commands kept for rewind replay and command_log (oldest dropped first); entries older
+    // than the oldest rewind snapshot are dropped as well
+    pub command_log_limit: u32,
+    // Debug: per-tick energy conservation report (World::energy_audit)
+    pub energy_audit: bool,
+    // Hash brains with the old JS simpleHash-over-JSON scheme instead of canonical xxHash64
+    // (only for bad-brain lists recorded before the switch)
+    pub legacy_brain_hash: bool,
+    // Export creatures, plants, corpses, elites, and burrows with their old snake_case keys
+    // (is_pregnant, energy_remaining, ...) instead of camelCase, for consumers not yet migrated
+    pub legacy_snake_case_entities: bool,
+    // Degenerate-brain detection: judge output variance every N ticks (0 disables; NaN checks
+    // always run), then flag, re-roll, or mutate (with this weight sigma) the offending brain
+    pub degenerate_window_ticks: u32,
+    pub degenerate_action: DegenerateAction,
+    pub degenerate_mutation_sigma: f32,
+    // Imported brains (import_brain, load_snapshot) have NaN parameters zeroed and the rest
+    // clamped to +/- this magnitude
+    pub brain_weight_limit: f32,
+    // Brain maintenance every N ticks (0 disables): hidden neurons that never fired since the last
+    // pass are re-initialized or removed, and weights below the sparsify magnitude are zeroed
+    pub prune_every_ticks: u32,
+    pub prune_mode: PruneMode,
+    pub sparsify_weight_below: f32,
+    // Offspring inherit a mutated copy of the parent's brain instead of a fresh random one.
+    // Structural mutations (per offspring) then grow or shrink hidden capacity within the caps.
+    pub inherit_brains: bool,
+    pub add_neuron_prob: f32,
+    pub remove_neuron_prob: f32,
+    pub add_layer_prob: f32,
+    pub max_hidden_neurons: u32, // per hidden layer
+    pub max_hidden_layers: u32,
+    pub structural_noise_sigma: f32,
+    // Weight/bias mutation applied to inherited brains and by World::mutate_brain
+    pub mutation: MutationConfig,
+    // Recombination of inherited brains with the nearest same-diet, same-topology mate
+    pub crossover: CrossoverConfig,
+    // Penalties for newborns of closely related parents (lineage-based relatedness)
+    pub inbreeding: InbreedingConfig,
+    // Optional females and males: only females gestate, males compete for them with costly ornaments
+    pub sexes: SexConfig,
+    pub elitism: ElitismConfig,
+    pub novelty: NoveltyConfig,
+    // Within-lifetime Hebbian/Oja weight updates
+    pub plasticity: PlasticityConfig,
+    // Extra inputs: direction and distance to the nearest plant, corpse, and water, plus the thirst
+    // level (10 channels)
+    pub resource_sensing: bool,
+    // SHARE action: energy transfers to adjacent low-energy creatures, kin first
+    pub energy_sharing: EnergySharingConfig,
+    // Diffusing smell from plants and corpses; its local gradient is fed as 2 extra inputs
+    pub scent: ScentConfig,
+    // Remembered food and fight locations, fed as extra inputs while enabled (8 channels)
+    pub spatial_memory: SpatialMemoryConfig,
+    // Extra inputs: direction, distance, and size of the 3 nearest herbivores, carnivores, plants,
+    // and corpses (48 channels)
+    pub multi_target_sensing: bool,
+    // Perception error applied to brain inputs
+    pub sensor_noise: SensorNoiseConfig,
+    // Selection fitness as a weighted sum of per-creature metrics (elites, hall of fame)
+    pub fitness: FitnessWeights,
+    // Controller keeping herbivores in a target band via plant regrowth and ambient decay
+    pub auto_balance: AutoBalanceConfig,
+    // Highlights digest: warn when a diet's population drops below this
+    pub extinction_warning_threshold: u32,
+    // Recent positions per creature for trails_json
+    pub trails: TrailConfig,
+    // Decaying creature-density heatmap per diet (density_grid)
+    pub density: DensityConfig,
+    // Per-biome carrying-capacity estimate and its history (carrying_capacity, capacity_history)
+    pub carrying_capacity: CarryingCapacityConfig,
+    // Corpse decay tunables
+    pub corpse_base_decay_per_sec: f32,
+    pub corpse_temp_decay_coeff: f32,
+    pub corpse_humidity_decay_coeff: f32,
+    pub corpse_rain_decay_coeff: f32,
+    pub corpse_wetness_decay_coeff: f32,
+    // Corpse yield: body mass (per unit radius) plus the energy left in reserve, and a decay time
+    // that grows with size
+    pub corpse_energy_per_radius: f32,
+    pub corpse_decay_time_per_radius: f32,
+    // Terrain/climate noise (fractal simplex)
+    pub noise_octaves: u32,
+    pub noise_lacunarity: f32,
+    pub noise_persistence: f32,
+    pub noise_scale: f32, // base frequency in 1/world-units
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rest_stamina_regen_per_sec: 2.0,
+            rest_health_regen_per_sec: 0.2,
+            harvest_plant_action_cost_per_second: 0.02,
+            harvest_rate_per_sec: 0.15,
+            plant_max_biomass: DEFAULT_PLANT_BIOMASS,
+            plant_regrowth_per_sec: 0.05,
+            seasons: SeasonConfig::default(),
+            blooms: BloomConfig::default(),
+            migration: MigrationConfig::default(),
+            attack_cost_per_hit_stamina: 2.0,
+            sprint_overflow_cost_per_sec: 0.03,
+            posture_cost_per_sec: 0.005,
+            brain_cost_per_param_per_sec: 0.0,
+            attack_cost_per_hit_energy: 0.04,
+            attack_reach: 2.0,
+            attack_damage_per_sec: 0.0,
+            kill_credit_window_ticks: 180,
+            thirst_threshold: 30.0,
+            thirst_recovery_per_sec: 5.0,
+            drink_cost_per_second: 0.01,
+            thirst_decay_per_sec: 0.02,
+            thirst_heat_coeff: 0.02,
+            thirst_exertion_coeff: 0.01,
+            dehydration_damage_per_sec: 0.3,
+            drink_reach: 6.0,
+            puddle_rain_threshold: 0.7,
+            move_cost_coeff_per_speed_per_sec: 0.02,
+            ambient_health_decay_per_sec: 0.02,
+            regen_energy_threshold: 70.0,
+            regen_health_per_sec: 0.05,
+            regen_energy_per_health: 2.0,
+            energy_debt_floor: -50.0,
+            energy_debt_health_penalty_per_sec: 2.0,
+            survive_energy_debt: false,
+            aging_health_decay_coeff: 0.5,
+            max_life: 60.0 * 60.0 * 60.0, // ~60 minutes at 60fps equivalent
+            longevity_initial_sigma: 0.1,
+            longevity_mutation_prob: 1.0,
+            longevity_mutation_sigma: 0.05,
+            gestation_base_cost_per_sec: 0.02,
+            gestation_cost_per_offspring_per_sec: 0.015,
+            gestation_period: 60.0 * 20.0, // ~20s at 60fps
+            birth_event_cost_energy: 4.0,
+            aquatic_swim_skill: 0.7,
+            birth_placement_attempts: 8,
+            gestation_min_energy: 60.0,
+            gestation_min_health: 50.0,
+            gestation_min_age: 60 * 30, // ~30s at 60fps
+            postpartum_cooldown: 60.0 * 10.0,
+            pregnancy: PregnancyConfig::default(),
+            mutation_cost_energy_base: 0.5,
+            mutation_cost_per_std_change: 0.8,
+            max_litter_size: 4,
+            litter_size_mutation_prob: 1.0,
+            litter_size_mutation_sigma: 0.1,
+            newborn_energy: 80.0,
+            newborn_radius: 4.0,
+            litter_size_tradeoff: 1.0,
+            growth: GrowthConfig::default(),
+            max_speed: 0.0,
+            max_accel: 0.0,
+            agility_initial_sigma: 0.0,
+            agility_mutation_prob: 1.0,
+            agility_mutation_sigma: 0.0,
+            hunger_energy_threshold: 30.0,
+            fatigue_stamina_threshold: 30.0,
+            movement_threshold: 0.02,
+            stagnant_ticks_limit: 600,
+            // Environmental defaults (mostly disabled until configured from JS)
+            swim_energy_cost_per_sec: 0.0,
+            wind_drag_coeff: 0.0,
+            temp_cold_penalty_per_sec: 0.0,
+            temp_heat_penalty_per_sec: 0.0,
+            comfort_low_c: 10.0,
+            comfort_high_c: 28.0,
+            humidity_dehydration_coeff_per_sec: 0.0,
+            humidity_threshold: 0.7,
+            oxygen_thin_air_penalty_per_sec: 0.0,
+            thin_air_elevation_cutoff01: 0.8,
+            altitude_acclimatization_per_sec: 0.0005,
+            altitude_acclimatization_relief: 0.75,
+            noise_stress_penalty_per_sec: 0.0,
+            disease_energy_drain_per_sec: 0.0,
+            disease: DiseaseConfig::default(),
+            slope_uphill_cost_per_sec: 0.0,
+            slope_speed_penalty: 3.0,
+            slope_downhill_boost: 1.0,
+            swim_skill_cost_reduction: 0.8,
+            swim_speed_min_mult: 0.4,
+            swim_stamina_drain_per_sec: 1.5,
+            drowning_damage_per_sec: 2.0,
+            swim_skill_mutation_prob: 1.0,
+            swim_skill_mutation_sigma: 0.05,
+            water_currents: WaterCurrentConfig::default(),
+            flyer_initial_fraction: 0.0,
+            flight_gene_flip_prob: 0.001,
+            flight_energy_cost_per_sec: 0.08,
+            flight_climb_rate_per_sec: 0.05,
+            meat_intake_per_sec: 0.3,
+            diet_specialization: DietSpecializationConfig::default(),
+            corpse_carry: CorpseCarryConfig::default(),
+            decomposers: DecomposerConfig::default(),
+            food_items: FoodItemConfig::default(),
+            torpor: TorporConfig::default(),
+            burrow_dig_cost_energy: 3.0,
+            burrow_radius: 6.0,
+            burrow_temp_shelter: 0.8,
+            max_burrows: 500,
+            max_frame_dt_sec: 0.25,
+            max_substep_dt_sec: 1.0 / 30.0,
+            max_substeps: 16,
+            double_buffered_exports: false,
+            snapshot_every_ticks: 600,
+            snapshot_capacity: 0,
+            state_hash_history: 0,
+            command_log_limit: 4096,
+            energy_audit: false,
+            legacy_brain_hash: false,
+            legacy_snake_case_entities: false,
+            degenerate_window_ticks: 300,
+            degenerate_action: DegenerateAction::Flag,
+            degenerate_mutation_sigma: 0.3,
+            brain_weight_limit: 100.0,
+            prune_every_ticks: 0,
+            prune_mode: PruneMode::Reinit,
+            sparsify_weight_below: 0.0,
+            inherit_brains: false,
+            add_neuron_prob: 0.03,
+            remove_neuron_prob: 0.02,
+            add_layer_prob: 0.005,
+            max_hidden_neurons: 32,
+            max_hidden_layers: 3,
+            structural_noise_sigma: 0.05,
+            mutation: MutationConfig::default(),
+            crossover: CrossoverConfig::default(),
+            inbreeding: InbreedingConfig::default(),
+            sexes: SexConfig::default(),
+            senescence: SenescenceConfig::default(),
+            elitism: ElitismConfig::default(),
+            novelty: NoveltyConfig::default(),
+            plasticity: PlasticityConfig::default(),
+            resource_sensing: false,
+            energy_sharing: EnergySharingConfig::default(),
+            scent: ScentConfig::default(),
+            spatial_memory: SpatialMemoryConfig::default(),
+            multi_target_sensing: false,
+            sensor_noise: SensorNoiseConfig::default(),
+            fitness: FitnessWeights::default(),
+            auto_balance: AutoBalanceConfig::default(),
+            extinction_warning_threshold: 5,
+            trails: TrailConfig::default(),
+            density: DensityConfig::default(),
+            carrying_capacity: CarryingCapacityConfig::default(),
+            corpse_base_decay_per_sec: 0.5,
+            corpse_temp_decay_coeff: 0.0,
+            corpse_humidity_decay_coeff: 0.0,
+            corpse_rain_decay_coeff: 0.0,
+            corpse_wetness_decay_coeff: 0.0,
+            corpse_energy_per_radius: 6.0,
+            corpse_decay_time_per_radius: 20.0,
+            noise_octaves: 4,
+            noise_lacunarity: 2.0,
+            noise_persistence: 0.5,
+            noise_scale: 0.0015,
+        }
+    }
+}
+
+// Mutation operator settings (serialized as the `mutation` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MutationConfig {
+    pub weight_prob: f32,  // chance each weight is perturbed
+    pub weight_sigma: f32, // perturbation std dev before annealing
+    pub reset_prob: f32,   // chance each weight is redrawn from the init distribution instead
+    pub bias_scale: f32,   // bias perturbation sigma relative to the weight sigma
+    pub anneal_rate: f32,  // sigma multiplier per generation (1 = no annealing)
+    pub min_sigma: f32,    // annealing floor
+}
+
+impl Default for MutationConfig {
+    fn default() -> Self {
+        Self { weight_prob: 0.2, weight_sigma: 0.1, reset_prob: 0.002, bias_scale: 0.5, anneal_rate: 1.0, min_sigma: 0.01 }
+    }
+}
+
+// How two parent brains are recombined
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum CrossoverMode {
+    #[default]
+    None,
+    Uniform, // each weight/bias independently from the mate with probability mix_ratio
+    Layer,   // each layer's weights and biases wholesale from the mate with probability mix_ratio
+    Blend,   // arithmetic blend: (1 - mix_ratio) * parent + mix_ratio * mate
+}
+
+// Crossover settings (serialized as the `crossover` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CrossoverConfig {
+    pub mode: CrossoverMode,
+    pub mix_ratio: f32,
+}
+
+impl Default for CrossoverConfig {
+    fn default() -> Self { Self { mode: CrossoverMode::None, mix_ratio: 0.5 } }
+}
+
+// Elitism (serialized as the `elitism` block of Config). Every `period_ticks` the `top_k` fittest
+// creatures, living or archived at death, become the elite set; each elite then spawns
+// `offspring_per_elite` children with inherited brains. With `protect`, living elites cannot die.
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ElitismConfig {
+    pub top_k: u32, // 0 disables
+    pub period_ticks: u32,
+    pub offspring_per_elite: u32,
+    pub protect: bool,
+}
+
+impl Default for ElitismConfig {
+    fn default() -> Self { Self { top_k: 0, period_ticks: 1800, offspring_per_elite: 1, protect: true } }
+}
+
+// Weights over tracked lifetime metrics (see evolution::FitnessMetrics). The default, lifespan
+// only, reproduces the original survival-time ranking. `damage_avoided` scores negative damage taken.
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FitnessWeights {
+    pub lifespan: f32, // per tick alive
+    pub offspring: f32,
+    pub energy_harvested: f32,
+    pub kills: f32,
+    pub distance_traveled: f32, // per world unit
+    pub damage_avoided: f32,
+}
+
+impl Default for FitnessWeights {
+    fn default() -> Self { Self { lifespan: 1.0, offspring: 0.0, energy_harvested: 0.0, kills: 0.0, distance_traveled: 0.0, damage_avoided: 0.0 } }
+}
+
+// Gaussian noise (sigma, in channel units) and dropout (probability the reading is 0) per sensed
+// input. `channels` overrides the defaults by input name (see World::brain_io_schema); constant
+// inputs are never perturbed and noisy readings are clamped to the channel's range.
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SensorNoiseConfig {
+    pub enabled: bool,
+    pub sigma: f32,
+    pub dropout: f32,
+    pub channels: BTreeMap<String, SenseNoise>,
+}
+
+impl Default for SensorNoiseConfig {
+    fn default() -> Self { Self { enabled: false, sigma: 0.0, dropout: 0.0, channels: BTreeMap::new() } }
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SenseNoise {
+    pub sigma: f32,
+    pub dropout: f32,
+}
+
+impl MutationConfig {
+    // Effective perturbation sigma for offspring of the given generation
+    pub fn sigma_at(&self, generation: u32) -> f32 {
+        let annealed = self.weight_sigma * self.anneal_rate.powi(generation.min(i32::MAX as u32) as i32);
+        annealed.max(self.min_sigma.min(self.weight_sigma))
+    }
+}
+
+// Apply a partial camelCase config object on top of `base`; nested objects merge key by key,
+// so `{ disease: { enabled: true } }` keeps the other disease settings
+pub(crate) fn merge_config(base: &Config, patch: &serde_json::Value) -> Result<Config, String> {
+    let mut merged = serde_json::to_value(base).map_err(|e| e.to_string())?;
+    if !merged.is_object() || !patch.is_object() { return Err("config overrides must be an object".to_string()); }
+    merge_json(&mut merged, patch);
+    serde_json::from_value::<Config>(merged).map_err(|e| format!("invalid config overrides: {}", e))
+}
+
+// Err naming the first top-level key of `patch` that is not a Config field (merge_config alone
+// would carry a misspelled key through and drop it on deserialization)
+pub(crate) fn check_config_keys(patch: &serde_json::Value) -> Result<(), String> {
+    let known = serde_json::to_value(Config::default()).map_err(|e| e.to_string())?;
+    let (Some(known), Some(patch)) = (known.as_object(), patch.as_object()) else { return Ok(()); };
+    match patch.keys().find(|k| !known.contains_key(*k)) {
+        Some(k) => Err(format!("unknown config field '{}'", k)),
+        None => Ok(()),
+    }
+}
+
+// Overlay `patch` onto `target`: objects recurse, anything else replaces
+fn merge_json(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    match (target, patch) {
+        (serde_json::Value::Object(t), serde_json::Value::Object(p)) => {
+            for (k, v) in p {
+                match t.get_mut(k) {
+                    Some(slot) => merge_json(slot, v),
+                    None => { t.insert(k.clone(), v.clone()); }
+                }
+            }
+        }
+        (t, p) => *t = p.clone(),
+    }
+}
@@ -0,0 +1,430 @@
+use serde::{Serialize, Deserialize};
+
+use crate::action_log::ActionLog;
+use crate::brain::{brain_heap_bytes, Brain, NeuronActivity, OutputWatch};
+use crate::config::Config;
+use crate::evolution::FitnessMetrics;
+use crate::harvest::DEFAULT_PLANT_BIOMASS;
+use crate::memory::SpatialMemory;
+use crate::migration::MigrationState;
+use crate::novelty::BehaviorTrace;
+use crate::rng::RngLCG;
+use crate::sex::Sex;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Creature {
+    pub id: String,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub radius: f32,
+    pub health: f32,
+    pub energy: f32,
+    pub stamina: f32,
+    pub max_stamina: f32,
+    pub thirst: f32, // 0..100, lower = thirstier
+    pub lifespan: u32,
+    pub diet: Diet,
+    pub brain: Brain,
+    pub genes: Genes,
+    pub altitude: f32, // 0 = on the ground, 1 = cruising altitude (flyers only)
+    pub burrow_id: Option<u32>, // Some while sheltering underground
+    // Reproduction (parity scaffolding)
+    pub is_pregnant: bool,
+    pub gestation_timer: f32,
+    pub offspring_count: u32, // size of the current (or last) litter
+    // Two-sex mode (Config::sexes): None in asexual worlds; the father of the current pregnancy
+    #[serde(default, skip_serializing_if = "Option::is_none")] pub sex: Option<Sex>,
+    #[serde(default, skip_serializing_if = "Option::is_none")] pub sire_id: Option<String>,
+    // Telemetry: ActionFlag / FeelingFlag bits set this tick
+    pub actions_mask: u32,
+    pub feelings_mask: u32,
+    pub stagnant_ticks: u32,
+    // 0 for founders; offspring are parent + 1
+    #[serde(default)] pub generation: u32,
+    // Founder id shared by all descendants (kin detection)
+    #[serde(default)] pub lineage: String,
+    // Birth parent (None for founders) and the relatedness of its parents (Config::inbreeding)
+    #[serde(default, skip_serializing_if = "Option::is_none")] pub parent_id: Option<String>,
+    #[serde(default)] pub inbreeding: f32,
+    // Contagion state (Config::disease): currently sick, and ticks of immunity left after recovering
+    #[serde(default)] pub infected: bool,
+    #[serde(default)] pub immunity_timer: f32,
+    // Adaptation to thin air (0..1): rises toward how far above Config::thin_air_elevation_cutoff01
+    // the creature lives and relieves the oxygen penalty
+    #[serde(default)] pub acclimatization: f32,
+    // Realized diet: share of recent food intake that came from plants (Config::diet_specialization)
+    #[serde(default = "default_plant_share")] pub plant_share: f32,
+    // Last-tick telemetry (not serialized in creatures_json)
+    #[serde(skip_serializing, default)] pub last_env_total: f32,
+    #[serde(skip_serializing, default)] pub last_env_swim: f32,
+    #[serde(skip_serializing, default)] pub last_env_wind: f32,
+    #[serde(skip_serializing, default)] pub last_env_cold: f32,
+    #[serde(skip_serializing, default)] pub last_env_heat: f32,
+    #[serde(skip_serializing, default)] pub last_env_humid: f32,
+    #[serde(skip_serializing, default)] pub last_env_oxy: f32,
+    #[serde(skip_serializing, default)] pub last_env_noise: f32,
+    #[serde(skip_serializing, default)] pub last_env_disease: f32,
+    #[serde(skip_serializing, default)] pub last_env_slope: f32,
+    #[serde(skip_serializing, default)] pub last_locomotion: f32,
+    // Per-tick signed energy deltas by category (exported via energy_ledger_f32)
+    #[serde(skip_serializing, default)] pub ledger: EnergyLedger,
+    // Set when degenerate-brain detection flags this brain (Config::degenerate_action = flag)
+    #[serde(default)] pub degenerate_brain: bool,
+    #[serde(skip, default)] pub output_watch: OutputWatch,
+    #[serde(skip, default)] pub neuron_activity: NeuronActivity,
+    // Novelty search (Config::novelty): lifetime behaviour trace and the latest novelty score
+    #[serde(skip, default)] pub behavior: BehaviorTrace,
+    #[serde(default)] pub novelty: f32,
+    // Lifetime metrics and the weighted fitness they score to under Config::fitness
+    #[serde(default)] pub metrics: FitnessMetrics,
+    #[serde(default)] pub fitness: f32,
+    // Weight changes learned this lifetime (Config::plasticity), same shape as brain.weights
+    #[serde(default, skip_serializing_if = "Vec::is_empty")] pub learned_weights: Vec<Vec<f32>>,
+    // Last seen food and last fight locations (Config::spatial_memory)
+    #[serde(default, skip_serializing_if = "SpatialMemory::is_empty")] pub memory: SpatialMemory,
+    // Forage rate, best remembered region, and last tallied quadrant (Config::migration)
+    #[serde(default, skip_serializing_if = "MigrationState::is_empty")] pub migration: MigrationState,
+    // Seconds until this creature can share energy again (Config::energy_sharing)
+    #[serde(default)] pub share_cooldown: f32,
+    // Time left before this creature may conceive again after giving birth (for males, before mating again)
+    #[serde(default)] pub postpartum_timer: f32,
+    // Radius of the corpse being dragged (Config::corpse_carry); 0 when not carrying
+    #[serde(default)] pub carry_load: f32,
+    // Seconds into the current torpor bout (0 when awake) and sluggish wake-up time left (Config::torpor)
+    #[serde(default)] pub torpor_sec: f32,
+    #[serde(default)] pub wake_timer: f32,
+    // Source of the largest single-tick health loss so far (reported as the cause of death)
+    #[serde(default)] pub harm_cause: DeathCause,
+    #[serde(skip, default)] pub harm_peak: f32,
+    // Carnivore that bit this creature most recently, and when (kill credit, see World::credit_kills)
+    #[serde(default, skip_serializing_if = "Option::is_none")] pub last_bite: Option<Bite>,
+    // Last few discrete actions with their ticks (surfaced through World::watched_creature)
+    #[serde(skip, default)] pub recent_actions: ActionLog,
+}
+
+impl Creature {
+    // Fresh adult with default vitals; callers override fields via struct update syntax
+    pub(crate) fn new(id: String, x: f32, y: f32, diet: Diet, brain: Brain) -> Self {
+        Creature {
+            lineage: id.clone(),
+            parent_id: None,
+            inbreeding: 0.0,
+            infected: false,
+            immunity_timer: 0.0,
+            acclimatization: 0.0,
+            plant_share: if diet == Diet::Herbivore { 1.0 } else { 0.5 },
+            id,
+            x,
+            y,
+            vx: 0.0,
+            vy: 0.0,
+            radius: 5.0,
+            health: 100.0,
+            energy: 100.0,
+            stamina: 100.0,
+            max_stamina: 100.0,
+            thirst: 100.0,
+            lifespan: 0,
+            diet,
+            brain,
+            genes: Genes::default(),
+            altitude: 0.0,
+            burrow_id: None,
+            is_pregnant: false,
+            gestation_timer: 0.0,
+            offspring_count: 1,
+            sex: None,
+            sire_id: None,
+            actions_mask: 0,
+            feelings_mask: 0,
+            stagnant_ticks: 0,
+            generation: 0,
+            last_env_total: 0.0,
+            last_env_swim: 0.0,
+            last_env_wind: 0.0,
+            last_env_cold: 0.0,
+            last_env_heat: 0.0,
+            last_env_humid: 0.0,
+            last_env_oxy: 0.0,
+            last_env_noise: 0.0,
+            last_env_disease: 0.0,
+            last_env_slope: 0.0,
+            last_locomotion: 0.0,
+            ledger: EnergyLedger::default(),
+            degenerate_brain: false,
+            output_watch: OutputWatch::default(),
+            neuron_activity: NeuronActivity::default(),
+            behavior: BehaviorTrace::default(),
+            novelty: 0.0,
+            metrics: FitnessMetrics::default(),
+            fitness: 0.0,
+            learned_weights: Vec::new(),
+            memory: SpatialMemory::default(),
+            migration: MigrationState::default(),
+            share_cooldown: 0.0,
+            postpartum_timer: 0.0,
+            carry_load: 0.0,
+            torpor_sec: 0.0,
+            wake_timer: 0.0,
+            harm_cause: DeathCause::default(),
+            harm_peak: 0.0,
+            last_bite: None,
+            recent_actions: ActionLog::default(),
+        }
+    }
+
+    // Age as a fraction of this creature's max life (`max_life` scaled by longevity), 0..1
+    pub(crate) fn age_norm(&self, max_life: f32) -> f32 {
+        (self.lifespan as f32 / (max_life * self.genes.longevity).max(1.0)).clamp(0.0, 1.0)
+    }
+
+    // Whether this creature may become pregnant: fit enough, old enough, not pregnant, and past
+    // its postpartum cooldown. Mating must check this before setting is_pregnant.
+    pub fn can_conceive(&self, cfg: &Config) -> bool {
+        !self.is_pregnant
+            && self.postpartum_timer <= 0.0
+            && self.energy >= cfg.gestation_min_energy
+            && self.health >= cfg.gestation_min_health
+            && self.lifespan >= cfg.gestation_min_age
+    }
+
+    // Lose up to `amount` health to `cause`; the largest loss within a tick sets harm_cause
+    pub(crate) fn harm(&mut self, cause: DeathCause, amount: f32) {
+        let before = self.health;
+        self.health = (self.health - amount).max(0.0);
+        let lost = before - self.health;
+        self.metrics.damage_taken += lost;
+        if lost > 0.0 && lost >= self.harm_peak {
+            self.harm_peak = lost;
+            self.harm_cause = cause;
+        }
+    }
+
+    // Apply an energy change clamped to [floor, 100] and record the realized delta in the ledger.
+    // Unlike the original per-site `.max(0.0)` clamps, a value already outside the range is never
+    // pulled into it: a debit while below the floor (e.g. gestation debt) or a credit while above
+    // 100 leaves energy unchanged instead of snapping it to the bound, which would move energy
+    // outside the ledger.
+    pub(crate) fn apply_energy(&mut self, cat: LedgerCat, delta: f32, floor: f32) {
+        let before = self.energy;
+        self.energy = (self.energy + delta).clamp(floor.min(before), 100.0f32.max(before));
+        self.ledger.0[cat as usize] += self.energy - before;
+    }
+
+    // Heap owned outside the brain: ids, learned weights, action log, degenerate-brain windows
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let bitten_by = self.last_bite.as_ref().map(|b| &b.by);
+        let strings = [Some(&self.id), Some(&self.lineage), self.parent_id.as_ref(), self.sire_id.as_ref(), bitten_by];
+        strings.into_iter().flatten().map(|s| s.capacity()).sum::<usize>()
+            + self.learned_weights.iter().map(|w| std::mem::size_of::<Vec<f32>>() + w.capacity() * std::mem::size_of::<f32>()).sum::<usize>()
+            + self.recent_actions.heap_bytes()
+            + self.output_watch.heap_bytes()
+            + self.neuron_activity.heap_bytes()
+    }
+}
+
+// Bytes held by a creature list: inline structs plus each creature's heap and brain
+pub(crate) fn creature_vec_bytes(v: &Vec<Creature>) -> usize {
+    v.capacity() * std::mem::size_of::<Creature>() + v.iter().map(|c| c.heap_bytes() + brain_heap_bytes(&c.brain)).sum::<usize>()
+}
+
+// Why a creature died: starvation when energy ran out, otherwise its latest harm source
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum DeathCause {
+    #[default]
+    OldAge, // ambient health decay
+    Starvation,
+    Dehydration,
+    Drowning,
+    Predation, // bitten to death by a carnivore
+}
+
+// Latest bite taken by a creature: the biter's id and the tick it landed
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Bite {
+    pub by: String,
+    pub tick: u64,
+}
+
+// Heritable traits (serialized as `genes`, camelCase to match the JS gene records)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Genes {
+    pub swim_skill: f32, // 0 = sinks like a stone, 1 = fully aquatic
+    pub can_fly: bool,
+    pub longevity: f32, // multiplier on Config::max_life
+    pub litter_size: f32, // offspring per birth, rounded (Config::max_litter_size caps it)
+    pub agility: f32, // multiplier on Config::max_speed and max_accel
+    pub ornament: f32, // sexual display (Config::sexes): attracts mates, costs males energy
+    pub adult_size: f32, // multiplier on Config::growth.adult_radius, the size juveniles grow toward
+}
+
+impl Default for Genes {
+    fn default() -> Self { Self { swim_skill: 0.5, can_fly: false, longevity: 1.0, litter_size: 1.0, agility: 1.0, ornament: 0.0, adult_size: 1.0 } }
+}
+
+const LONGEVITY_RANGE: (f32, f32) = (0.1, 10.0);
+const AGILITY_RANGE: (f32, f32) = (0.25, 4.0);
+
+// With probability `prob`, step `v` by a Gaussian of `sigma` and clamp it to `range`
+pub(crate) fn mutate_trait(rng: &mut RngLCG, v: f32, prob: f32, sigma: f32, range: (f32, f32)) -> f32 {
+    if rng.next_f32_01() >= prob { return v; }
+    (v + rng.normal() * sigma).clamp(range.0, range.1)
+}
+
+// Next "c<n>" id from a world's creature counter (monotonic, so ids are never reused)
+pub(crate) fn take_creature_id(counter: &mut u64) -> String {
+    let id = format!("c{}", *counter);
+    *counter += 1;
+    id
+}
+
+impl Genes {
+    // Genes for the `n`th creature spawned without parents. They come from a stream of their own
+    // keyed by the world seed and `n`, so the world RNG sees the same position / diet / brain draws
+    // as before genes existed and a seed keeps its founders.
+    pub(crate) fn founder(seed: u32, n: u64, cfg: &Config) -> Self {
+        let mut rng = RngLCG::new(seed ^ 0x6E4E_5EED ^ (n as u32).wrapping_mul(0x9E37_79B9));
+        Self::random(&mut rng, cfg)
+    }
+
+    fn random(rng: &mut RngLCG, cfg: &Config) -> Self {
+        Self {
+            swim_skill: rng.next_f32_01(),
+            can_fly: rng.next_f32_01() < cfg.flyer_initial_fraction,
+            longevity: (1.0 + rng.normal() * cfg.longevity_initial_sigma).clamp(LONGEVITY_RANGE.0, LONGEVITY_RANGE.1),
+            litter_size: 1.0,
+            agility: (1.0 + rng.normal() * cfg.agility_initial_sigma).clamp(AGILITY_RANGE.0, AGILITY_RANGE.1),
+            ornament: 0.0,
+            adult_size: 1.0,
+        }
+    }
+
+    // Offspring genes: each trait takes a Gaussian step with its own probability and sigma, clamped
+    // to its range. Also returns the realized mutation magnitude (sum of absolute trait changes, a
+    // flight flip counting as 1).
+    pub(crate) fn inherit(&self, rng: &mut RngLCG, cfg: &Config) -> (Self, f32) {
+        let max_litter = cfg.max_litter_size.max(1) as f32;
+        let child = Self {
+            swim_skill: mutate_trait(rng, self.swim_skill, cfg.swim_skill_mutation_prob, cfg.swim_skill_mutation_sigma, (0.0, 1.0)),
+            can_fly: self.can_fly != (rng.next_f32_01() < cfg.flight_gene_flip_prob),
+            longevity: mutate_trait(rng, self.longevity, cfg.longevity_mutation_prob, cfg.longevity_mutation_sigma, LONGEVITY_RANGE),
+            litter_size: mutate_trait(rng, self.litter_size, cfg.litter_size_mutation_prob, cfg.litter_size_mutation_sigma, (1.0, max_litter)),
+            agility: mutate_trait(rng, self.agility, cfg.agility_mutation_prob, cfg.agility_mutation_sigma, AGILITY_RANGE),
+            ornament: self.ornament, // inherited from both parents in two-sex mode (SexConfig::inherit_ornament)
+            adult_size: self.adult_size, // mutated at birth while Config::growth is on
+        };
+        let flip = if child.can_fly != self.can_fly { 1.0 } else { 0.0 };
+        let change = (child.swim_skill - self.swim_skill).abs()
+            + (child.longevity - self.longevity).abs()
+            + (child.litter_size - self.litter_size).abs()
+            + (child.agility - self.agility).abs()
+            + flip;
+        (child, change)
+    }
+
+    // Offspring produced per birth
+    pub(crate) fn litter(&self, cfg: &Config) -> u32 {
+        (self.litter_size.round() as u32).clamp(1, cfg.max_litter_size.max(1))
+    }
+}
+
+// Energy ledger categories, in export order. Credits are positive, debits negative.
+#[derive(Clone, Copy)]
+pub enum LedgerCat {
+    Intake,
+    Harvest,
+    Sprint,
+    SprintOverflow,
+    Posture,
+    Attack,
+    Drink,
+    Locomotion,
+    Environment,
+    Gestation,
+    Birth,
+    Mutation,
+    Flight,
+    Burrow,
+    Share,
+    Regen,
+    Brain,
+    Display,
+    Growth,
+    Digestion,
+}
+
+pub const LEDGER_LEN: usize = 20;
+pub const LEDGER_CATEGORIES: [&str; LEDGER_LEN] = [
+    "intake", "harvest", "sprint", "sprintOverflow", "posture", "attack",
+    "drink", "locomotion", "environment", "gestation", "birth", "mutation",
+    "flight", "burrow", "share", "regen", "brain", "display", "growth", "digestion",
+];
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+pub struct EnergyLedger(pub [f32; LEDGER_LEN]);
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Plant {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    // Food left to harvest (regrows up to Config::plant_max_biomass)
+    #[serde(default = "default_plant_biomass")] pub biomass: f32,
+    // Set on bloom flowers: the tick they wilt (they don't regrow or seed)
+    #[serde(default, skip_serializing_if = "Option::is_none")] pub bloom_until: Option<u64>,
+}
+
+fn default_plant_biomass() -> f32 { DEFAULT_PLANT_BIOMASS }
+
+fn default_plant_share() -> f32 { 0.5 }
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Corpse {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    pub energy_remaining: f32,
+    pub initial_decay_time: f32,
+    pub decay_timer: f32,
+    // Id of the creature dragging this corpse
+    #[serde(default, skip_serializing_if = "Option::is_none")] pub carried_by: Option<String>,
+    // Last-tick decay telemetry (not serialized in corpses_json)
+    #[serde(skip_serializing, default)] pub last_decay_total: f32,
+    #[serde(skip_serializing, default)] pub last_decay_base: f32,
+    #[serde(skip_serializing, default)] pub last_decay_temp: f32,
+    #[serde(skip_serializing, default)] pub last_decay_humid: f32,
+    #[serde(skip_serializing, default)] pub last_decay_rain: f32,
+    #[serde(skip_serializing, default)] pub last_decay_wet: f32,
+}
+
+// Persistent underground shelter dug by a creature
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Burrow {
+    pub id: u32,
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    pub dug_tick: u64,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub enum Diet { Herbivore, Carnivore }
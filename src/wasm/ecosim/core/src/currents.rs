@@ -0,0 +1,40 @@
+// Water currents: the water bands (one channel across the wrapped top/bottom edge) flow along the
+// map, fastest mid-channel and still at the shore, with a steady drift toward the nearer bank.
+// Swimmers are pushed downstream (skilled swimmers resist) and loose corpses drift until they
+// strand on a bank, carrying their nutrients with them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::environment::in_water_at;
+
+// Current settings (serialized as the `waterCurrents` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WaterCurrentConfig {
+    pub enabled: bool,
+    pub speed: f32,              // downstream (+x) world units per tick at mid-channel
+    pub bank_drift: f32,         // cross-channel drift toward the nearer bank, as a fraction of speed
+    pub swimmer_resistance: f32, // fraction of the push cancelled at swim skill 1
+}
+
+impl Default for WaterCurrentConfig {
+    fn default() -> Self { Self { enabled: false, speed: 0.6, bank_drift: 0.15, swimmer_resistance: 0.7 } }
+}
+
+impl WaterCurrentConfig {
+    // Current vector (per tick) at height `y` of a world `height` tall; zero on land or when disabled
+    pub(crate) fn at(&self, y: f32, height: f32) -> (f32, f32) {
+        if !self.enabled || !in_water_at(y, height) { return (0.0, 0.0); }
+        // Distance from the channel centre line (the wrapped edge), 0 mid-channel .. 1 at the bank
+        let half_width = (height * 0.12).max(1.0);
+        let d = (y.min(height - y) / half_width).clamp(0.0, 1.0);
+        let toward_bank = if y < height * 0.5 { 1.0 } else { -1.0 };
+        (self.speed * (1.0 - d * d), toward_bank * self.bank_drift * self.speed)
+    }
+
+    // Share of the current a creature with `swim_skill` is carried by
+    pub(crate) fn swimmer_push(&self, swim_skill: f32) -> f32 {
+        1.0 - self.swimmer_resistance.clamp(0.0, 1.0) * swim_skill
+    }
+}
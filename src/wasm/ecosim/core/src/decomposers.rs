@@ -0,0 +1,191 @@
+// Decomposers: colonies (fungal mats, insect swarms) that feed on corpses and on plant litter (the
+// biomass living plants shed) and turn them into soil fertility far faster than passive rotting.
+// Fertility boosts plant regrowth where it lies and slowly leaches away. Colonies grow on what they
+// eat, starve without it, bud new colonies nearby, and settle on unclaimed corpses from spores, so
+// they spread slowly toward food. Corpses that rot away untouched leave their remains as litter.
+
+use serde::{Deserialize, Serialize};
+
+use crate::World;
+
+// Decomposer settings (serialized as the `decomposers` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DecomposerConfig {
+    pub enabled: bool,
+    pub cell_size: f32,          // world units per soil grid cell
+    pub litterfall_per_sec: f32, // fraction of each plant's biomass shed as litter per second
+    pub passive_per_sec: f32,    // fraction of litter that turns into fertility per second on its own
+    pub leach_per_sec: f32,      // fraction of fertility lost per second
+    pub fertility_boost: f32,    // plant growth multiplier is 1 + boost * f / (f + fertility_half)
+    pub fertility_half: f32,
+    pub reach: f32,              // how far a colony feeds on corpses
+    pub feed_per_sec: f32,       // matter eaten per second per unit of colony biomass
+    pub conversion: f32,         // fraction of eaten matter returned to the soil as fertility
+    pub growth_efficiency: f32,  // colony biomass gained per unit eaten
+    pub upkeep_per_sec: f32,     // colony biomass lost per second; colonies die at 0
+    pub bud_biomass: f32,        // a colony this big splits, budding a new one nearby
+    pub bud_distance: f32,
+    pub spore_per_sec: f32,      // chance per second that a corpse no colony reaches is colonized
+    pub max_colonies: u32,
+}
+
+impl Default for DecomposerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, cell_size: 40.0, litterfall_per_sec: 0.002, passive_per_sec: 0.005, leach_per_sec: 0.002,
+            fertility_boost: 1.0, fertility_half: 5.0, reach: 12.0, feed_per_sec: 0.2, conversion: 0.8,
+            growth_efficiency: 0.1, upkeep_per_sec: 0.005, bud_biomass: 3.0, bud_distance: 15.0, spore_per_sec: 0.01,
+            max_colonies: 200,
+        }
+    }
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Decomposer {
+    pub x: f32,
+    pub y: f32,
+    pub biomass: f32,
+    pub born_tick: u64,
+}
+
+// Litter and fertility on a coarse grid (row-major); empty while decomposers are off
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SoilGrid {
+    cols: u32,
+    rows: u32,
+    cell: f32,
+    litter: Vec<f32>,
+    fertility: Vec<f32>,
+}
+
+impl SoilGrid {
+    pub(crate) fn heap_bytes(&self) -> usize { (self.litter.capacity() + self.fertility.capacity()) * std::mem::size_of::<f32>() }
+
+    fn index(&self, x: f32, y: f32) -> usize {
+        let gx = ((x / self.cell).max(0.0) as u32).min(self.cols - 1);
+        let gy = ((y / self.cell).max(0.0) as u32).min(self.rows - 1);
+        (gy * self.cols + gx) as usize
+    }
+
+    // (Re)allocate an empty grid when the world size or cell size changes
+    fn ensure(&mut self, width: f32, height: f32, cell: f32) {
+        let cell = cell.max(1.0);
+        let cols = (width / cell).ceil().max(1.0) as u32;
+        let rows = (height / cell).ceil().max(1.0) as u32;
+        if self.cols != cols || self.rows != rows || self.cell != cell {
+            let n = (cols * rows) as usize;
+            *self = Self { cols, rows, cell, litter: vec![0.0; n], fertility: vec![0.0; n] };
+        }
+    }
+
+    pub(crate) fn litter_at(&self, x: f32, y: f32) -> f32 {
+        if self.litter.is_empty() { return 0.0; }
+        self.litter[self.index(x, y)]
+    }
+
+    pub(crate) fn fertility_at(&self, x: f32, y: f32) -> f32 {
+        if self.fertility.is_empty() { return 0.0; }
+        self.fertility[self.index(x, y)]
+    }
+
+    pub(crate) fn add_litter(&mut self, x: f32, y: f32, amount: f32) {
+        if self.litter.is_empty() { return; }
+        let i = self.index(x, y);
+        self.litter[i] += amount;
+    }
+}
+
+impl DecomposerConfig {
+    // Plant growth multiplier from soil fertility `f` (1 while decomposers are off)
+    pub(crate) fn growth_mult(&self, f: f32) -> f32 {
+        if !self.enabled { return 1.0; }
+        let f = f.max(0.0);
+        1.0 + self.fertility_boost.max(0.0) * f / (f + self.fertility_half.max(1e-3))
+    }
+}
+
+impl World {
+    pub fn decomposers(&self) -> &[Decomposer] { self.front.as_ref().map_or(&self.decomposers, |f| &f.decomposers) }
+
+    // Litterfall, spores, colony feeding/growth/budding, then passive rotting and leaching
+    pub(crate) fn update_decomposers(&mut self, t_sec: f32) {
+        let cfg = &self.config.decomposers;
+        if !cfg.enabled {
+            if !self.decomposers.is_empty() || self.soil.heap_bytes() > 0 {
+                self.decomposers.clear();
+                self.soil = SoilGrid::default();
+            }
+            return;
+        }
+        self.soil.ensure(self.width, self.height, cfg.cell_size);
+        let shed = (cfg.litterfall_per_sec.max(0.0) * t_sec).min(1.0);
+        for p in &mut self.plants {
+            let fall = p.biomass * shed;
+            p.biomass -= fall;
+            self.soil.add_litter(p.x, p.y, fall);
+            self.flows.decomposition += fall;
+        }
+        // Spores settle on corpses no colony is feeding on yet
+        let (reach2, cap) = (cfg.reach.max(0.0).powi(2), cfg.max_colonies as usize);
+        let near = |d: &Decomposer, x: f32, y: f32| (d.x - x).powi(2) + (d.y - y).powi(2) <= reach2;
+        for co in &self.corpses {
+            if self.decomposers.len() >= cap { break; }
+            if co.energy_remaining <= 0.0 || self.decomposers.iter().any(|d| near(d, co.x, co.y)) { continue; }
+            if self.rng.next_f32_01() < cfg.spore_per_sec.max(0.0) * t_sec {
+                self.decomposers.push(Decomposer { x: co.x, y: co.y, biomass: 1.0, born_tick: self.tick });
+            }
+        }
+        // Feed on corpses in reach first, then on the litter underfoot
+        for k in 0..self.decomposers.len() {
+            let d = &self.decomposers[k];
+            let mut appetite = cfg.feed_per_sec.max(0.0) * d.biomass * t_sec;
+            let mut eaten = 0.0;
+            for co in self.corpses.iter_mut().filter(|co| near(d, co.x, co.y)) {
+                let bite = appetite.min(co.energy_remaining.max(0.0));
+                co.energy_remaining -= bite;
+                appetite -= bite;
+                eaten += bite;
+                self.flows.decomposition += bite;
+                if appetite <= 0.0 { break; }
+            }
+            let i = self.soil.index(d.x, d.y);
+            let bite = appetite.min(self.soil.litter[i]);
+            self.soil.litter[i] -= bite;
+            eaten += bite;
+            self.soil.fertility[i] += eaten * cfg.conversion.clamp(0.0, 1.0);
+            let d = &mut self.decomposers[k];
+            d.biomass += eaten * cfg.growth_efficiency.max(0.0) - cfg.upkeep_per_sec.max(0.0) * t_sec;
+        }
+        self.decomposers.retain(|d| d.biomass > 0.0);
+        // Well-fed colonies bud off a neighbour
+        for k in 0..self.decomposers.len() {
+            if self.decomposers.len() >= cap { break; }
+            if self.decomposers[k].biomass < cfg.bud_biomass.max(0.1) { continue; }
+            let angle = self.rng.next_f32_01() * std::f32::consts::TAU;
+            let d = &mut self.decomposers[k];
+            d.biomass *= 0.5;
+            let x = (d.x + angle.cos() * cfg.bud_distance).rem_euclid(self.width.max(1.0));
+            let y = (d.y + angle.sin() * cfg.bud_distance).clamp(0.0, self.height);
+            let bud = Decomposer { x, y, biomass: d.biomass, born_tick: self.tick };
+            self.decomposers.push(bud);
+        }
+        let rot = (cfg.passive_per_sec.max(0.0) * t_sec).min(1.0);
+        let keep = (1.0 - cfg.leach_per_sec.max(0.0) * t_sec).clamp(0.0, 1.0);
+        for (l, f) in self.soil.litter.iter_mut().zip(&mut self.soil.fertility) {
+            let r = *l * rot;
+            *l -= r;
+            *f = (*f + r) * keep;
+        }
+    }
+
+    // Plant growth multiplier from the soil at (x, y)
+    pub(crate) fn soil_growth_at(&self, x: f32, y: f32) -> f32 {
+        self.config.decomposers.growth_mult(self.soil.fertility_at(x, y))
+    }
+}
@@ -0,0 +1,75 @@
+// Habitat-usage heatmap: a decaying creature-density grid per diet, accumulated during step so
+// hosts can show where herbivores congregate and predators patrol over the long run.
+// Not part of snapshots; the grid restarts empty after a restore.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Diet;
+use crate::World;
+
+// Density settings (serialized as the `density` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DensityConfig {
+    pub enabled: bool,
+    pub resolution: u32,    // cells per side
+    pub half_life_sec: f32, // occupancy weight halves every this many seconds (0 = never fades)
+}
+
+impl Default for DensityConfig {
+    fn default() -> Self { Self { enabled: false, resolution: 64, half_life_sec: 120.0 } }
+}
+
+// Per cell: decayed creature-seconds spent there, one layer per diet
+#[derive(Default)]
+pub(crate) struct DensityGrid {
+    resolution: usize,
+    herbivores: Vec<f32>,
+    carnivores: Vec<f32>,
+}
+
+impl DensityGrid {
+    pub(crate) fn heap_bytes(&self) -> usize {
+        (self.herbivores.capacity() + self.carnivores.capacity()) * std::mem::size_of::<f32>()
+    }
+}
+
+impl World {
+    pub(crate) fn accumulate_density(&mut self, t_sec: f32) {
+        let cfg = &self.config.density;
+        if !cfg.enabled {
+            if self.density.resolution > 0 { self.density = DensityGrid::default(); }
+            return;
+        }
+        let n = cfg.resolution.clamp(1, 512) as usize;
+        if self.density.resolution != n {
+            self.density = DensityGrid { resolution: n, herbivores: vec![0.0; n * n], carnivores: vec![0.0; n * n] };
+        }
+        if cfg.half_life_sec > 0.0 {
+            let fade = 0.5f32.powf(t_sec / cfg.half_life_sec);
+            for v in self.density.herbivores.iter_mut().chain(self.density.carnivores.iter_mut()) { *v *= fade; }
+        }
+        for c in &self.creatures {
+            let gx = ((c.x / self.width * n as f32) as usize).min(n - 1);
+            let gy = ((c.y / self.height * n as f32) as usize).min(n - 1);
+            let layer = if c.diet == Diet::Herbivore { &mut self.density.herbivores } else { &mut self.density.carnivores };
+            layer[gy * n + gx] += t_sec;
+        }
+    }
+
+    // Density layer "herbivore" | "carnivore" | "all" as a resolution x resolution row-major grid
+    // (empty while config.density is off); None for an unknown layer
+    pub fn density_grid(&self, layer: &str) -> Option<Vec<f32>> {
+        let d = &self.density;
+        match layer {
+            "herbivore" => Some(d.herbivores.clone()),
+            "carnivore" => Some(d.carnivores.clone()),
+            "all" => Some(d.herbivores.iter().zip(&d.carnivores).map(|(h, c)| h + c).collect()),
+            _ => None,
+        }
+    }
+
+    // Cells per side of density_grid (0 while disabled)
+    pub fn density_resolution(&self) -> u32 { self.density.resolution as u32 }
+}
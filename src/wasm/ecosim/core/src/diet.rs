@@ -0,0 +1,47 @@
+// Realized diet and digestive specialization: every meal moves a creature's plant_share (the share
+// of its recent food that came from plants) toward the kind of food it just ate. With
+// specialization on, food is digested better the larger its share of the recent diet, so
+// generalists pay for flexibility and lineages that can eat both drift into plant or meat niches.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::{Creature, LedgerCat};
+
+// Diet specialization settings (serialized as the `dietSpecialization` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DietSpecializationConfig {
+    pub enabled: bool,              // apply the efficiency curve (plant_share is tracked either way)
+    pub memory: f32,                // food units over which plant_share forgets older meals
+    pub specialist_efficiency: f32, // energy per unit of food making up the whole recent diet
+    pub novice_efficiency: f32,     // energy per unit of food never eaten recently
+    pub curve: f32,                 // efficiency = novice + (specialist - novice) * share^curve
+}
+
+impl Default for DietSpecializationConfig {
+    fn default() -> Self { Self { enabled: false, memory: 50.0, specialist_efficiency: 1.0, novice_efficiency: 0.5, curve: 2.0 } }
+}
+
+impl DietSpecializationConfig {
+    // Energy per unit of a food making up `share` of the recent diet
+    fn efficiency(&self, share: f32) -> f32 {
+        if !self.enabled { return 1.0; }
+        let (lo, hi) = (self.novice_efficiency.max(0.0), self.specialist_efficiency.max(0.0));
+        lo + (hi - lo) * share.clamp(0.0, 1.0).powf(self.curve.max(0.01))
+    }
+
+    // Credit `c` with `amount` of plant or meat: the efficiency is judged against the diet so far
+    // (the gain or loss is booked as 'digestion'), then the meal joins it
+    pub(crate) fn digest(&self, c: &mut Creature, plant: bool, amount: f32) {
+        let share = if plant { c.plant_share } else { 1.0 - c.plant_share };
+        let eff = self.efficiency(share);
+        c.apply_energy(LedgerCat::Intake, amount, 0.0);
+        if eff != 1.0 { c.apply_energy(LedgerCat::Digestion, amount * (eff - 1.0), 0.0); }
+        let target = if plant { 1.0 } else { 0.0 };
+        c.plant_share += (target - c.plant_share) * amount / (amount + self.memory.max(1e-3));
+    }
+}
+
+// 0 for an even plant/meat mix, 1 for a pure diet
+pub(crate) fn specialization(plant_share: f32) -> f32 { (2.0 * plant_share - 1.0).abs() }
@@ -0,0 +1,111 @@
+// Contagion: infected creatures pass disease to neighbours in contact range, and outbreaks can
+// start on their own. Both are scaled by local humidity and crowding, so epidemics take hold in
+// swampy, dense regions. Scavengers can also catch it from rotting meat, more so the further the
+// corpse has decayed and the warmer it is. Only infected creatures pay
+// Config::disease_energy_drain_per_sec while this is enabled; recovered creatures stay immune for
+// a while.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Corpse;
+use crate::World;
+
+// Contagion settings (serialized as the `disease` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DiseaseConfig {
+    pub enabled: bool,
+    pub contact_radius: f32,
+    pub transmission_per_sec: f32, // infection chance per second per infected contact, before scaling
+    pub outbreak_per_sec: f32,     // spontaneous infection chance per second, before scaling
+    pub humidity_factor: f32,      // chances scale by 1 + this * humidity (0..1)
+    pub crowding_factor: f32,      // and by 1 + this * creatures in contact range
+    pub recovery_per_sec: f32,
+    pub immunity_ticks: f32,
+    pub carrion_per_sec: f32,      // infection chance per second of eating a fully decayed corpse
+    pub carrion_onset: f32,        // decay progress (0..1) before which meat is safe
+    pub carrion_temp_factor: f32,  // carrion chance scales by 1 + this * warmth ((temp - 20 C) / 15, 0..2)
+}
+
+impl Default for DiseaseConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, contact_radius: 20.0, transmission_per_sec: 0.02, outbreak_per_sec: 0.0001,
+            humidity_factor: 3.0, crowding_factor: 0.25, recovery_per_sec: 0.005, immunity_ticks: 1800.0,
+            carrion_per_sec: 0.05, carrion_onset: 0.3, carrion_temp_factor: 1.0,
+        }
+    }
+}
+
+impl DiseaseConfig {
+    // Infection chance per second of feeding on `co` at `temp_c` (0 while disease is off)
+    pub(crate) fn carrion_risk(&self, co: &Corpse, temp_c: f32) -> f32 {
+        if !self.enabled { return 0.0; }
+        let progress = 1.0 - co.decay_timer / co.initial_decay_time.max(1e-3);
+        let onset = self.carrion_onset.clamp(0.0, 0.99);
+        let rot = ((progress - onset) / (1.0 - onset)).clamp(0.0, 1.0);
+        let warmth = ((temp_c - 20.0) / 15.0).clamp(0.0, 2.0);
+        self.carrion_per_sec.max(0.0) * rot * (1.0 + self.carrion_temp_factor.max(0.0) * warmth)
+    }
+}
+
+// Population and infected counts over a cols x rows grid covering the world (row-major), for
+// infection-rate overlays
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InfectionGrid {
+    pub cols: u32,
+    pub rows: u32,
+    pub population: Vec<u32>,
+    pub infected: Vec<u32>,
+}
+
+impl World {
+    // One contagion pass over the living population: new infections from contacts and spontaneous
+    // outbreaks (judged against the infection state at the start of the pass), then recoveries
+    pub(crate) fn spread_disease(&mut self, t_sec: f32) {
+        let cfg = &self.config.disease;
+        if !cfg.enabled { return; }
+        let r2 = cfg.contact_radius * cfg.contact_radius;
+        let infected: Vec<bool> = self.creatures.iter().map(|c| c.infected).collect();
+        let mut newly = Vec::new();
+        for (i, c) in self.creatures.iter().enumerate() {
+            if infected[i] || c.immunity_timer > 0.0 { continue; }
+            let (mut crowd, mut sick) = (0u32, 0u32);
+            for (j, o) in self.creatures.iter().enumerate() {
+                if i == j || (o.x - c.x).powi(2) + (o.y - c.y).powi(2) > r2 { continue; }
+                crowd += 1;
+                if infected[j] { sick += 1; }
+            }
+            let humid = self.climate.humidity01(c.x, c.y, self.tick);
+            let scale = (1.0 + cfg.humidity_factor.max(0.0) * humid) * (1.0 + cfg.crowding_factor.max(0.0) * crowd as f32);
+            let chance = (cfg.transmission_per_sec * sick as f32 + cfg.outbreak_per_sec).max(0.0) * scale * t_sec;
+            if self.rng.next_f32_01() < chance { newly.push(i); }
+        }
+        let (recovery, immunity) = (cfg.recovery_per_sec.max(0.0) * t_sec, cfg.immunity_ticks.max(0.0));
+        for c in &mut self.creatures {
+            c.immunity_timer = (c.immunity_timer - t_sec).max(0.0);
+            if c.infected && self.rng.next_f32_01() < recovery {
+                c.infected = false;
+                c.immunity_timer = immunity;
+            }
+        }
+        for i in newly { self.creatures[i].infected = true; }
+    }
+
+    // Infection counts on a resolution x resolution grid (resolution clamped to 1..=256)
+    pub fn infection_grid(&self, resolution: u32) -> InfectionGrid {
+        let n = resolution.clamp(1, 256);
+        let mut grid = InfectionGrid { cols: n, rows: n, population: vec![0; (n * n) as usize], infected: vec![0; (n * n) as usize] };
+        for c in &self.creatures {
+            let gx = ((c.x / self.width.max(1.0) * n as f32).max(0.0) as u32).min(n - 1);
+            let gy = ((c.y / self.height.max(1.0) * n as f32).max(0.0) as u32).min(n - 1);
+            let k = (gy * n + gx) as usize;
+            grid.population[k] += 1;
+            if c.infected { grid.infected[k] += 1; }
+        }
+        grid
+    }
+}
@@ -0,0 +1,193 @@
+// Ensemble runs: the same config stepped headless across several seeds, summarized with means,
+// spreads, and extinction rates, since a single seed is too noisy to judge a parameter change.
+// Two configs run on matched seeds give paired differences and effect sizes (A/B balance tests).
+
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::{World, WorldStats};
+
+// Mean, spread, and 95% confidence interval of one outcome across runs
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleSummary {
+    pub n: u32,
+    pub mean: f64,
+    pub std_dev: f64, // sample standard deviation (0 for fewer than two runs)
+    pub ci95_low: f64,
+    pub ci95_high: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl SampleSummary {
+    pub(crate) fn of(values: &[f64]) -> SampleSummary {
+        let n = values.len();
+        if n == 0 { return SampleSummary::default(); }
+        let mean = values.iter().sum::<f64>() / n as f64;
+        let std_dev = if n < 2 { 0.0 } else {
+            (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt()
+        };
+        let half = t95(n.saturating_sub(1)) * std_dev / (n as f64).sqrt();
+        SampleSummary {
+            n: n as u32, mean, std_dev, ci95_low: mean - half, ci95_high: mean + half,
+            min: values.iter().copied().fold(f64::INFINITY, f64::min),
+            max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+// Two-sided 95% Student t critical value for `df` degrees of freedom
+pub(crate) fn t95(df: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+        2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+        2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+    ];
+    match df {
+        0 => 0.0,
+        1..=30 => TABLE[df - 1],
+        _ => 1.96,
+    }
+}
+
+// Outcome of one seed
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EnsembleRun {
+    pub seed: u32,
+    #[serde(rename = "final")]
+    pub final_stats: WorldStats,
+    pub extinct_at_tick: Option<u64>, // first tick with no creatures left
+    pub herbivores_extinct: bool,
+    pub carnivores_extinct: bool,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EnsembleReport {
+    pub ticks: u32,
+    pub runs: Vec<EnsembleRun>,
+    pub final_creatures: SampleSummary,
+    pub final_herbivores: SampleSummary,
+    pub final_carnivores: SampleSummary,
+    pub final_plants: SampleSummary,
+    pub mean_energy: SampleSummary,
+    pub births: SampleSummary,
+    pub deaths: SampleSummary,
+    pub extinction_rate: f32, // share of runs where the whole population died out
+    pub herbivore_extinction_rate: f32,
+    pub carnivore_extinction_rate: f32,
+}
+
+impl World {
+    // Fresh width x height world for `seed` with `config`, stepped `ticks` fixed 1/60s ticks
+    pub(crate) fn run_seed(config: &Config, (width, height): (f32, f32), seed: u32, ticks: u32) -> EnsembleRun {
+        let mut w = World::new(width, height, seed);
+        w.set_config(config.clone());
+        // Headless runs never rewind
+        w.config.snapshot_capacity = 0;
+        let mut extinct_at_tick = None;
+        for _ in 0..ticks {
+            w.advance(1.0 / 60.0);
+            if extinct_at_tick.is_none() && w.creatures.is_empty() { extinct_at_tick = Some(w.tick); }
+        }
+        let final_stats = w.stats();
+        EnsembleRun {
+            seed,
+            herbivores_extinct: final_stats.herbivores == 0,
+            carnivores_extinct: final_stats.carnivores == 0,
+            final_stats,
+            extinct_at_tick,
+        }
+    }
+
+    // Run `config` once per seed and summarize the final states. Independent of any existing world.
+    pub fn run_ensemble(config: &Config, size: (f32, f32), seeds: &[u32], ticks: u32) -> EnsembleReport {
+        EnsembleReport::from_runs(ticks, seeds.iter().map(|&s| World::run_seed(config, size, s, ticks)).collect())
+    }
+
+    // Run both configs on the same seeds (same starting world per seed) and compare them pairwise.
+    // Independent of any existing world.
+    pub fn compare_configs(a: &Config, b: &Config, size: (f32, f32), seeds: &[u32], ticks: u32) -> ConfigComparison {
+        let a = World::run_ensemble(a, size, seeds, ticks);
+        let b = World::run_ensemble(b, size, seeds, ticks);
+        let metrics = PAIRED_METRICS.iter().map(|&(metric, f)| {
+            let (va, vb): (Vec<f64>, Vec<f64>) = a.runs.iter().zip(&b.runs).map(|(ra, rb)| (f(ra), f(rb))).unzip();
+            let diffs: Vec<f64> = va.iter().zip(&vb).map(|(x, y)| y - x).collect();
+            let diff = SampleSummary::of(&diffs);
+            // Paired Cohen's d (d_z); 0 when the runs never differ
+            let effect_size = if diff.std_dev > 0.0 { diff.mean / diff.std_dev } else { 0.0 };
+            PairedMetric {
+                metric, mean_a: SampleSummary::of(&va).mean, mean_b: SampleSummary::of(&vb).mean,
+                significant: diff.n >= 2 && (diff.ci95_low > 0.0 || diff.ci95_high < 0.0),
+                diff, effect_size,
+            }
+        }).collect();
+        ConfigComparison { ticks, seeds: seeds.to_vec(), metrics, a, b }
+    }
+}
+
+type MetricFn = fn(&EnsembleRun) -> f64;
+
+// Outcomes compared by World::compare_configs
+const PAIRED_METRICS: [(&str, MetricFn); 8] = [
+    ("creatures", |r| r.final_stats.creatures as f64),
+    ("herbivores", |r| r.final_stats.herbivores as f64),
+    ("carnivores", |r| r.final_stats.carnivores as f64),
+    ("plants", |r| r.final_stats.plants as f64),
+    ("meanEnergy", |r| r.final_stats.mean_energy as f64),
+    ("births", |r| r.final_stats.births_total as f64),
+    ("deaths", |r| r.final_stats.deaths_total as f64),
+    ("extinct", |r| if r.final_stats.creatures == 0 { 1.0 } else { 0.0 }),
+];
+
+// One outcome under both configs; diff is B minus A per seed
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PairedMetric {
+    pub metric: &'static str,
+    pub mean_a: f64,
+    pub mean_b: f64,
+    pub diff: SampleSummary,
+    pub effect_size: f64, // mean difference / standard deviation of the differences
+    pub significant: bool, // 95% interval of the difference excludes zero
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigComparison {
+    pub ticks: u32,
+    pub seeds: Vec<u32>,
+    pub metrics: Vec<PairedMetric>,
+    pub a: EnsembleReport,
+    pub b: EnsembleReport,
+}
+
+impl EnsembleReport {
+    fn from_runs(ticks: u32, runs: Vec<EnsembleRun>) -> EnsembleReport {
+        let summary = |f: &dyn Fn(&WorldStats) -> f64| SampleSummary::of(&runs.iter().map(|r| f(&r.final_stats)).collect::<Vec<_>>());
+        let rate = |f: &dyn Fn(&EnsembleRun) -> bool| {
+            if runs.is_empty() { 0.0 } else { runs.iter().filter(|r| f(r)).count() as f32 / runs.len() as f32 }
+        };
+        EnsembleReport {
+            ticks,
+            final_creatures: summary(&|s| s.creatures as f64),
+            final_herbivores: summary(&|s| s.herbivores as f64),
+            final_carnivores: summary(&|s| s.carnivores as f64),
+            final_plants: summary(&|s| s.plants as f64),
+            mean_energy: summary(&|s| s.mean_energy as f64),
+            births: summary(&|s| s.births_total as f64),
+            deaths: summary(&|s| s.deaths_total as f64),
+            extinction_rate: rate(&|r| r.final_stats.creatures == 0),
+            herbivore_extinction_rate: rate(&|r| r.herbivores_extinct),
+            carnivore_extinction_rate: rate(&|r| r.carnivores_extinct),
+            runs,
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::creature::Diet;
+use crate::rng::RngLCG;
+use crate::migration::MigrationConfig;
+use crate::seasons::SeasonConfig;
+use crate::World;
+
+// Point environment sample (see World::sample_env)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvSample {
+    pub temperature_c: f32,
+    pub humidity01: f32,
+    pub rain01: f32,
+    pub wetness01: f32,
+    pub wind: f32,
+    pub elevation01: f32,
+    pub noise01: f32,
+    pub biome: Biome,
+    pub in_water: bool,
+    // Water current (per tick; zero on land or with currents off)
+    pub current_x: f32,
+    pub current_y: f32,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub enum Biome { Water, Mountain, Tundra, Desert, Forest, Grassland }
+
+pub(crate) const BIOMES: [Biome; 6] = [Biome::Water, Biome::Mountain, Biome::Tundra, Biome::Desert, Biome::Forest, Biome::Grassland];
+
+// Who lives in one biome right now (see World::biome_stats)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BiomeStats {
+    pub biome: Biome,
+    pub area_share: f32, // fraction of the map currently in this biome (32x32 sample)
+    pub creatures: u32,
+    pub herbivores: u32,
+    pub carnivores: u32,
+    pub plants: u32,
+    pub plant_biomass: f32,
+    pub mean_energy: f32,
+    pub mean_health: f32,
+    pub mean_age_ticks: f32,
+}
+
+impl World {
+    // Upload a cols x rows row-major grid of speed multipliers covering the world (1 = normal,
+    // 0 = impassable); Err (and unchanged) if the size doesn't match
+    pub fn set_speed_grid(&mut self, cols: u32, rows: u32, values: &[f32]) -> Result<(), String> {
+        let g = HostGrid::new(cols, rows, values, (0.0, f32::MAX)).ok_or_else(|| grid_size_error(cols, rows, values))?;
+        self.speed_grid = Some(g);
+        Ok(())
+    }
+
+    // Go back to the built-in terrain speed noise
+    pub fn clear_speed_grid(&mut self) { self.speed_grid = None; }
+
+    // Upload a cols x rows row-major heightmap (0..1, clamped) covering the world. It replaces the
+    // generated elevation everywhere: temperature lapse, rain shadow, slopes, biomes, and thin air.
+    // Err (and unchanged) if the size doesn't match.
+    pub fn set_elevation_grid(&mut self, cols: u32, rows: u32, values: &[f32]) -> Result<(), String> {
+        let g = HostGrid::new(cols, rows, values, (0.0, 1.0)).ok_or_else(|| grid_size_error(cols, rows, values))?;
+        self.climate.elevation_grid = Some(g);
+        Ok(())
+    }
+
+    // Go back to the generated elevation
+    pub fn clear_elevation_grid(&mut self) { self.climate.elevation_grid = None; }
+
+    pub(crate) fn biome_at(&self, x: f32, y: f32) -> Biome {
+        let elev01 = self.climate.elevation01(x, y);
+        let temp_c = self.climate.temperature_c(x, y, self.tick);
+        classify_biome(in_water_at(y, self.height), elev01, temp_c, self.climate.humidity01(x, y, self.tick))
+    }
+
+    // Population, plant, and vitals breakdown for every biome (empty ones included), by where each
+    // creature and plant stands this tick. Temperature and seasons move biome borders over time.
+    pub fn biome_stats(&self) -> Vec<BiomeStats> {
+        let mut stats: Vec<BiomeStats> = BIOMES.iter().map(|&biome| BiomeStats {
+            biome, area_share: 0.0, creatures: 0, herbivores: 0, carnivores: 0, plants: 0, plant_biomass: 0.0,
+            mean_energy: 0.0, mean_health: 0.0, mean_age_ticks: 0.0,
+        }).collect();
+        let slot = |b: Biome| BIOMES.iter().position(|&o| o == b).unwrap_or(0);
+        const N: usize = 32;
+        let (cw, ch) = (self.width / N as f32, self.height / N as f32);
+        for gy in 0..N {
+            for gx in 0..N {
+                stats[slot(self.biome_at((gx as f32 + 0.5) * cw, (gy as f32 + 0.5) * ch))].area_share += 1.0 / (N * N) as f32;
+            }
+        }
+        for c in &self.creatures {
+            let s = &mut stats[slot(self.biome_at(c.x, c.y))];
+            s.creatures += 1;
+            if c.diet == Diet::Herbivore { s.herbivores += 1; } else { s.carnivores += 1; }
+            s.mean_energy += c.energy;
+            s.mean_health += c.health;
+            s.mean_age_ticks += c.lifespan as f32;
+        }
+        for p in &self.plants {
+            let s = &mut stats[slot(self.biome_at(p.x, p.y))];
+            s.plants += 1;
+            s.plant_biomass += p.biomass;
+        }
+        for s in &mut stats {
+            let n = s.creatures.max(1) as f32;
+            s.mean_energy /= n;
+            s.mean_health /= n;
+            s.mean_age_ticks /= n;
+        }
+        stats
+    }
+
+    pub(crate) fn env_at(&self, x: f32, y: f32) -> EnvSample {
+        let temperature_c = self.climate.temperature_c(x, y, self.tick);
+        let humidity01 = self.climate.humidity01(x, y, self.tick);
+        let elevation01 = self.climate.elevation01(x, y);
+        let in_water = in_water_at(y, self.height);
+        let (current_x, current_y) = self.config.water_currents.at(y, self.height);
+        EnvSample {
+            temperature_c,
+            humidity01,
+            rain01: sample_rain01(x, y, self.tick),
+            wetness01: self.climate.wetness01(x, y, self.tick),
+            wind: sample_wind_speed(x, y, self.tick),
+            elevation01,
+            noise01: sample_noise01(x, y, self.tick),
+            biome: classify_biome(in_water, elevation01, temperature_c, humidity01),
+            in_water,
+            current_x,
+            current_y,
+        }
+    }
+}
+
+// Very lightweight pseudo-noise for terrain speed multiplier [0.6, 1.0]
+pub(crate) fn terrain_speed_at(x: f32, y: f32, t: u64) -> f32 {
+    let tt = (t % 10_000) as f32 * 0.001;
+    let v = f32::sin(x * 0.003 + tt) * f32::cos(y * 0.002 - tt) * 0.5 + 0.5;
+    0.6 + v * 0.4
+}
+
+// Host-authored values stretched over the whole world, row-major: movement speed multipliers
+// (roads, mud, rock fields; see World::set_speed_grid) or elevation (World::set_elevation_grid)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HostGrid {
+    cols: u32,
+    rows: u32,
+    values: Vec<f32>,
+}
+
+impl HostGrid {
+    // None unless values has cols * rows entries; values are clamped to `range` (non-finite ones
+    // become its low end)
+    pub(crate) fn new(cols: u32, rows: u32, values: &[f32], (lo, hi): (f32, f32)) -> Option<HostGrid> {
+        if cols == 0 || rows == 0 || values.len() != cols as usize * rows as usize { return None; }
+        let values = values.iter().map(|&v| if v.is_finite() { v.clamp(lo, hi) } else { lo }).collect();
+        Some(HostGrid { cols, rows, values })
+    }
+
+    pub(crate) fn heap_bytes(&self) -> usize { self.values.capacity() * std::mem::size_of::<f32>() }
+
+    fn at(&self, gx: i64, gy: i64) -> f32 {
+        let gx = gx.clamp(0, self.cols as i64 - 1) as u32;
+        let gy = gy.clamp(0, self.rows as i64 - 1) as u32;
+        self.values[(gy * self.cols + gx) as usize]
+    }
+
+    // Value of the cell containing (x, y) in a width x height world
+    pub(crate) fn sample(&self, x: f32, y: f32, width: f32, height: f32) -> f32 {
+        self.at((x / width.max(1.0) * self.cols as f32) as i64, (y / height.max(1.0) * self.rows as f32) as i64)
+    }
+
+    // Bilinear blend of the four nearest cell centres (smooth terrain for slopes)
+    pub(crate) fn sample_smooth(&self, x: f32, y: f32, width: f32, height: f32) -> f32 {
+        let fx = x / width.max(1.0) * self.cols as f32 - 0.5;
+        let fy = y / height.max(1.0) * self.rows as f32 - 0.5;
+        let (gx, gy) = (fx.floor(), fy.floor());
+        let (tx, ty) = (fx - gx, fy - gy);
+        let (gx, gy) = (gx as i64, gy as i64);
+        let top = self.at(gx, gy) * (1.0 - tx) + self.at(gx + 1, gy) * tx;
+        let bottom = self.at(gx, gy + 1) * (1.0 - tx) + self.at(gx + 1, gy + 1) * tx;
+        top * (1.0 - ty) + bottom * ty
+    }
+}
+
+// Why HostGrid::new rejected an upload
+fn grid_size_error(cols: u32, rows: u32, values: &[f32]) -> String {
+    format!("{} values do not fill a {}x{} grid", values.len(), cols, rows)
+}
+
+// Ground speed multiplier: the uploaded grid if any, else the built-in noise
+pub(crate) fn ground_speed(grid: Option<&HostGrid>, x: f32, y: f32, (width, height): (f32, f32), t: u64) -> f32 {
+    match grid {
+        Some(g) => g.sample(x, y, width, height),
+        None => terrain_speed_at(x, y, t),
+    }
+}
+
+// --- Simple environment samplers (placeholders; JS provides richer ones) ---
+// Temperature, humidity, and elevation come from the seeded Climate model below.
+
+pub(crate) fn sample_rain01(_x: f32, y: f32, t: u64) -> f32 {
+    let tt = (t as f32) * 0.02;
+    let band = f32::sin(tt) * 0.5 + 0.5; // periodic rain bands
+    (band * (f32::sin(y * 0.002 + tt * 0.3) * 0.5 + 0.5)).clamp(0.0, 1.0)
+}
+
+pub(crate) fn sample_wind_speed(x: f32, y: f32, t: u64) -> f32 {
+    let tt = (t as f32) * 0.015;
+    // 0..1 scale
+    (f32::sin(x * 0.0009 + tt) * f32::cos(y * 0.0006 - tt) * 0.5 + 0.5).clamp(0.0, 1.0)
+}
+
+// Swim heuristic: treat top/bottom bands as water
+pub(crate) fn in_water_at(y: f32, height: f32) -> bool {
+    y < height * 0.12 || y > height * 0.88
+}
+
+// Drinkable spot: within `reach` of a water band, or under rain of at least `puddle_rain` (0..1),
+// which leaves temporary puddles
+pub(crate) fn can_drink_at(x: f32, y: f32, height: f32, t: u64, reach: f32, puddle_rain: f32) -> bool {
+    in_water_at(y - reach, height) || in_water_at(y + reach, height) || sample_rain01(x, y, t) >= puddle_rain
+}
+
+#[allow(clippy::approx_constant)]
+const BIRTH_ANGLE_SPAN: f32 = 6.2831;
+
+// Position for the k-th newborn of a parent at (x, y) in a world of size (width, height): jittered
+// candidates at growing distances, skipping water unless `allow_water`; falls back to the parent's
+// own spot when every attempt fails
+pub(crate) fn birth_spot(rng: &mut RngLCG, (x, y): (f32, f32), (width, height): (f32, f32), k: u32, allow_water: bool, attempts: u32) -> (f32, f32) {
+    for attempt in 0..attempts.max(1) {
+        // The original engine's truncated 2*pi, kept so birth positions match earlier runs
+        let angle = (k as f32) * 0.7 + rng.next_f32_01() * BIRTH_ANGLE_SPAN;
+        let r = 4.0 + rng.next_f32_01() * 6.0 + attempt as f32 * 4.0;
+        let nx = (x + angle.cos() * r).clamp(0.0, width);
+        let ny = (y + angle.sin() * r).clamp(0.0, height);
+        if allow_water || !in_water_at(ny, height) { return (nx, ny); }
+    }
+    (x, y)
+}
+
+// Coarse biome classification from the sampled climate values
+pub(crate) fn classify_biome(in_water: bool, elev01: f32, temp_c: f32, humid01: f32) -> Biome {
+    if in_water { Biome::Water }
+    else if elev01 > 0.75 { Biome::Mountain }
+    else if temp_c < 13.0 { Biome::Tundra }
+    else if temp_c > 25.0 && humid01 < 0.35 { Biome::Desert }
+    else if humid01 > 0.55 { Biome::Forest }
+    else { Biome::Grassland }
+}
+
+pub(crate) fn sample_noise01(x: f32, y: f32, t: u64) -> f32 {
+    let tt = (t as f32) * 0.05;
+    (f32::sin(x * 0.004 + tt) * f32::sin(y * 0.003 - tt) * 0.5 + 0.5).clamp(0.0, 1.0)
+}
+
+// Fractal noise tunables and the seasonal cycle (copied from Config so samplers don't need the
+// whole config)
+#[derive(Clone, Copy)]
+pub(crate) struct NoiseParams {
+    octaves: u32,
+    lacunarity: f32,
+    persistence: f32,
+    scale: f32,
+    seasons: SeasonConfig,
+    migration: MigrationConfig,
+}
+
+impl NoiseParams {
+    pub(crate) fn from_config(cfg: &Config) -> Self {
+        Self {
+            octaves: cfg.noise_octaves.clamp(1, 8),
+            lacunarity: cfg.noise_lacunarity.max(1.0),
+            persistence: cfg.noise_persistence.clamp(0.0, 1.0),
+            scale: cfg.noise_scale.max(1e-6),
+            seasons: cfg.seasons,
+            migration: cfg.migration,
+        }
+    }
+}
+
+// 2D simplex noise (Gustavson) over a seed-shuffled permutation table
+struct Simplex { perm: [u8; 512] }
+
+impl Simplex {
+    fn new(rng: &mut RngLCG) -> Self {
+        let mut p: [u8; 256] = std::array::from_fn(|i| i as u8);
+        for i in (1..256).rev() {
+            let j = (rng.next_u32() % (i as u32 + 1)) as usize;
+            p.swap(i, j);
+        }
+        Self { perm: std::array::from_fn(|i| p[i & 255]) }
+    }
+
+    // Single octave in roughly -1..1
+    fn noise2(&self, xin: f32, yin: f32) -> f32 {
+        const F2: f32 = 0.366_025_4; // (sqrt(3) - 1) / 2
+        const G2: f32 = 0.211_324_87; // (3 - sqrt(3)) / 6
+        const GRAD: [(f32, f32); 8] = [(1.0, 1.0), (-1.0, 1.0), (1.0, -1.0), (-1.0, -1.0), (1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0)];
+        let s = (xin + yin) * F2;
+        let i = (xin + s).floor();
+        let j = (yin + s).floor();
+        let t = (i + j) * G2;
+        let x0 = xin - (i - t);
+        let y0 = yin - (j - t);
+        let (i1, j1) = if x0 > y0 { (1usize, 0usize) } else { (0, 1) };
+        let x1 = x0 - i1 as f32 + G2;
+        let y1 = y0 - j1 as f32 + G2;
+        let x2 = x0 - 1.0 + 2.0 * G2;
+        let y2 = y0 - 1.0 + 2.0 * G2;
+        let ii = (i as i32 & 255) as usize;
+        let jj = (j as i32 & 255) as usize;
+        let perm = &self.perm;
+        let corner = |g: u8, x: f32, y: f32| {
+            let t = 0.5 - x * x - y * y;
+            if t < 0.0 { 0.0 } else { let (gx, gy) = GRAD[(g & 7) as usize]; t * t * t * t * (gx * x + gy * y) }
+        };
+        let n0 = corner(perm[ii + perm[jj] as usize], x0, y0);
+        let n1 = corner(perm[ii + i1 + perm[jj + j1] as usize], x1, y1);
+        let n2 = corner(perm[ii + 1 + perm[jj + 1] as usize], x2, y2);
+        70.0 * (n0 + n1 + n2)
+    }
+
+    // Fractal Brownian motion normalized to roughly -1..1
+    fn fbm(&self, x: f32, y: f32, p: &NoiseParams) -> f32 {
+        let (mut sum, mut norm, mut amp, mut freq) = (0.0, 0.0, 1.0, p.scale);
+        for _ in 0..p.octaves {
+            sum += amp * self.noise2(x * freq, y * freq);
+            norm += amp;
+            amp *= p.persistence;
+            freq *= p.lacunarity;
+        }
+        if norm > 0.0 { sum / norm } else { 0.0 }
+    }
+}
+
+// Seeded terrain/climate model: elevation continents (or an uploaded heightmap), latitude +
+// altitude temperature, humidity
+pub(crate) struct Climate {
+    width: f32,
+    height: f32,
+    pub(crate) params: NoiseParams,
+    pub(crate) elevation_grid: Option<HostGrid>,
+    elevation: Simplex,
+    temperature: Simplex,
+    humidity: Simplex,
+}
+
+impl Climate {
+    pub(crate) fn new(seed: u32, (width, height): (f32, f32), cfg: &Config) -> Self {
+        // Separate stream so terrain generation never perturbs the simulation RNG sequence
+        let mut rng = RngLCG::new(seed ^ 0x9E37_79B9);
+        Self {
+            width,
+            height,
+            params: NoiseParams::from_config(cfg),
+            elevation_grid: None,
+            elevation: Simplex::new(&mut rng),
+            temperature: Simplex::new(&mut rng),
+            humidity: Simplex::new(&mut rng),
+        }
+    }
+
+    pub(crate) fn elevation01(&self, x: f32, y: f32) -> f32 {
+        if let Some(g) = &self.elevation_grid { return g.sample_smooth(x, y, self.width, self.height); }
+        (self.elevation.fbm(x, y, &self.params) * 0.5 + 0.5).clamp(0.0, 1.0)
+    }
+
+    pub(crate) fn temperature_c(&self, x: f32, y: f32, t: u64) -> f32 {
+        let tt = (t as f32) * 0.01;
+        // Warm equator in the middle of the map, cold poles at the top/bottom edges
+        let lat = ((y / self.height.max(1.0)) - 0.5).abs() * 2.0;
+        let base = 30.0 - 22.0 * lat;
+        let local = self.temperature.fbm(x, y, &self.params) * 4.0;
+        // Lapse rate: highlands are colder
+        let lapse = (self.elevation01(x, y) - 0.5).max(0.0) * 24.0;
+        let drift = f32::sin(tt) * 2.0;
+        let swing = self.params.migration.temp_offset_c(&self.params.seasons, y, self.height, t);
+        base + local - lapse + drift + self.params.seasons.temp_offset_c(t) + swing
+    }
+
+    pub(crate) fn humidity01(&self, x: f32, y: f32, t: u64) -> f32 {
+        let tt = (t as f32) * 0.008;
+        let base = self.humidity.fbm(x, y, &self.params) * 0.5 + 0.5;
+        let drift = f32::sin(x * 0.0007 + y * 0.0005 + tt) * 0.1;
+        // Rain shadow: air dries out over high ground
+        let dry = (self.elevation01(x, y) - 0.6).max(0.0) * 0.8;
+        (base + drift - dry).clamp(0.0, 1.0)
+    }
+
+    // Signed elevation rise per 100 world units along the (vx, vy) heading; 0 when stationary
+    pub(crate) fn grade_along(&self, x: f32, y: f32, vx: f32, vy: f32) -> f32 {
+        const H: f32 = 4.0;
+        let sp = (vx * vx + vy * vy).sqrt();
+        if sp < 1e-6 { return 0.0; }
+        let gx = (self.elevation01(x + H, y) - self.elevation01(x - H, y)) / (2.0 * H);
+        let gy = (self.elevation01(x, y + H) - self.elevation01(x, y - H)) / (2.0 * H);
+        (gx * vx + gy * vy) / sp * 100.0
+    }
+
+    pub(crate) fn wetness01(&self, x: f32, y: f32, t: u64) -> f32 {
+        // Wetness lags rain a bit
+        let r = sample_rain01(x, y, t.saturating_sub(50));
+        (r * 0.7 + self.humidity01(x, y, t) * 0.3).clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::VecDeque;
+
+use serde::{Serialize, Deserialize};
+
+use crate::brain::{DegenerateAction, DegenerateReason};
+use crate::creature::DeathCause;
+use crate::invariants::Violation;
+
+// Hosts that never drain events lose the oldest ones past this many
+const MAX_PENDING_EVENTS: usize = 1024;
+
+// Notable simulation occurrences, queued until the host drains them. Variants with multi-word
+// fields carry their own rename_all (tsify can't read rename_all_fields).
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WorldEvent {
+    // A creature's brain was detected as degenerate; `hash` (pre-action) joined the bad-brain set
+    DegenerateBrain { tick: u64, id: String, hash: String, reason: DegenerateReason, action: DegenerateAction },
+    // `from` gave `amount` energy and `to` received `received` (after transfer losses)
+    EnergyShared { tick: u64, from: String, to: String, amount: f32, received: f32, kin: bool },
+    Death { tick: u64, id: String, cause: DeathCause, generation: u32, lifespan: u32 },
+    // A pregnant creature's energy fell below Config::pregnancy.miscarriage_energy and it lost its
+    // litter `progress` (0..1) of the way through gestation
+    Miscarriage { tick: u64, id: String, offspring: u32, progress: f32 },
+    // A creature was hand-fed (World::feed_creature); `amount` is the energy it actually took in
+    HandFed { tick: u64, id: String, amount: f32 },
+    // Food was dropped into the world (World::place_food)
+    FoodPlaced { tick: u64, x: f32, y: f32, energy: f32 },
+    // The auto-balancer (Config::auto_balance) retuned the world for `herbivores` herbivores
+    #[serde(rename_all = "camelCase")]
+    BalanceAdjusted { tick: u64, herbivores: u32, plant_regrowth_per_sec: f32, ambient_health_decay_per_sec: f32 },
+    // Post-step validation (feature `invariant-checks`) found a broken invariant
+    InvariantViolation { tick: u64, violation: Violation },
+}
+
+pub(crate) fn push_event(queue: &mut VecDeque<WorldEvent>, event: WorldEvent) {
+    if queue.len() >= MAX_PENDING_EVENTS { queue.pop_front(); }
+    queue.push_back(event);
+}
@@ -0,0 +1,295 @@
+// Neuroevolution operators applied when offspring inherit their parent's brain
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::brain::{brain_hash, remove_neuron, Brain};
+use crate::creature::{take_creature_id, Bite, Creature, DeathCause};
+use crate::config::{Config, CrossoverConfig, CrossoverMode, FitnessWeights, MutationConfig};
+use crate::brain::init_brain_avoiding_bad;
+use crate::plasticity::heritable_brain;
+use crate::rng::RngLCG;
+use crate::World;
+
+// Mutated copy of `parent` (recombined with `mate` when crossover is enabled) for a
+// generation-`generation` offspring with its weight mutation magnitude, or None when the result
+// is in the bad-brain set
+pub(crate) fn inherit_brain(parent: &Brain, mate: Option<&Brain>, generation: u32, cfg: &Config, rng: &mut RngLCG, bad: &HashSet<String>) -> Option<(Brain, f32)> {
+    let mut child = mate.and_then(|m| crossover(parent, m, &cfg.crossover, rng))
+        .unwrap_or_else(|| Brain { activations: None, ..parent.clone() });
+    mutate_structure(&mut child, cfg, rng);
+    let change = mutate_weights(&mut child, &cfg.mutation, generation, rng);
+    if !bad.is_empty() && bad.contains(&brain_hash(&child, cfg.legacy_brain_hash)) { return None; }
+    Some((child, change))
+}
+
+// Lifetime counters feeding the configurable fitness (Config::fitness)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FitnessMetrics {
+    pub offspring: u32,
+    pub energy_harvested: f32,
+    pub kills: u32, // prey that died of this one's bites (see World::credit_kills), plus World::record_kill credits
+    pub attack_attempts: u32, // attacks started (ATTACK rising edges, prey in reach)
+    pub distance_traveled: f32,
+    pub damage_taken: f32, // health lost to drowning, predation, and ambient decay
+}
+
+// Selection score (higher is fitter); used by elitism and exported per creature for hall-of-fame
+// ranking. Novelty is blended in per config.novelty.
+pub(crate) fn fitness(c: &Creature, cfg: &Config) -> f32 {
+    let w = &cfg.fitness;
+    let m = &c.metrics;
+    let novelty = if cfg.novelty.enabled { cfg.novelty.fitness_weight * c.novelty } else { 0.0 };
+    w.lifespan * c.lifespan as f32
+        + w.offspring * m.offspring as f32
+        + w.energy_harvested * m.energy_harvested
+        + w.kills * m.kills as f32
+        + w.distance_traveled * m.distance_traveled
+        - w.damage_avoided * m.damage_taken
+        + novelty
+}
+
+// Fittest first; ties broken by id so selection stays deterministic
+fn sort_by_fitness(v: &mut [Creature], cfg: &Config) {
+    v.sort_by(|a, b| fitness(b, cfg).total_cmp(&fitness(a, cfg)).then_with(|| a.id.cmp(&b.id)));
+}
+
+impl World {
+    // Merge a partial camelCase weights object ({ "lifespan": 1, "kills": 50, ... }) into
+    // config.fitness; Err (and unchanged) if it isn't a valid weights object
+    pub fn set_fitness_weights(&mut self, patch: &serde_json::Value) -> Result<(), String> {
+        let mut merged = serde_json::to_value(&self.config.fitness).map_err(|e| e.to_string())?;
+        match (merged.as_object_mut(), patch.as_object()) {
+            (Some(m), Some(p)) => { for (k, v) in p { m.insert(k.clone(), v.clone()); } }
+            _ => return Err("fitness weights must be an object".to_string()),
+        }
+        let w = serde_json::from_value::<FitnessWeights>(merged).map_err(|e| format!("invalid fitness weights: {}", e))?;
+        self.config.fitness = w;
+        self.refresh_fitness();
+        Ok(())
+    }
+
+    // Credit each creature about to die of predation (bites took its last health; starvation wins
+    // when energy is also out) to whoever bit it last, if that was within
+    // Config::kill_credit_window_ticks. Deaths from any other cause are nobody's kill.
+    pub(crate) fn credit_kills(&mut self, starve_at: f32) {
+        let window = self.config.kill_credit_window_ticks as u64;
+        let dying: Vec<Bite> = self.creatures.iter()
+            .filter(|c| c.health <= 0.0 && c.energy > starve_at && c.harm_cause == DeathCause::Predation && !self.is_protected_elite(&c.id))
+            .filter_map(|c| c.last_bite.clone())
+            .filter(|b| self.tick - b.tick <= window)
+            .collect();
+        for bite in dying {
+            if let Some(c) = self.creatures.iter_mut().find(|c| c.id == bite.by) {
+                c.metrics.kills += 1;
+                self.kills_total += 1;
+            }
+        }
+    }
+
+    // Credit a host-resolved kill to the living creature `killer_id`; false if there is none
+    pub fn record_kill(&mut self, killer_id: &str) -> bool {
+        let Some(c) = self.creatures.iter_mut().find(|c| c.id == killer_id) else { return false; };
+        c.metrics.kills += 1;
+        self.kills_total += 1;
+        true
+    }
+
+    // Re-score living creatures and re-rank the elite archive under the current weights
+    pub(crate) fn refresh_fitness(&mut self) {
+        for c in &mut self.creatures { c.fitness = fitness(c, &self.config); }
+        for e in &mut self.elites { e.fitness = fitness(e, &self.config); }
+        sort_by_fitness(&mut self.elites, &self.config);
+    }
+
+    pub(crate) fn is_protected_elite(&self, id: &str) -> bool {
+        self.config.elitism.top_k > 0 && self.config.elitism.protect && self.elites.iter().any(|e| e.id == id)
+    }
+
+    // Consider a dying creature for the elite archive
+    pub(crate) fn offer_elite(&mut self, c: &Creature) {
+        let k = self.config.elitism.top_k as usize;
+        if k == 0 { return; }
+        if self.elites.len() >= k && self.elites.last().is_some_and(|worst| fitness(worst, &self.config) >= fitness(c, &self.config)) { return; }
+        self.elites.retain(|e| e.id != c.id);
+        let mut archived = c.clone();
+        archived.brain.activations = None;
+        self.elites.push(archived);
+        sort_by_fitness(&mut self.elites, &self.config);
+        self.elites.truncate(k);
+    }
+
+    // Periodic elitism step: re-rank living creatures against the archive, then let every elite reproduce
+    pub(crate) fn run_elitism(&mut self) {
+        let k = self.config.elitism.top_k as usize;
+        if k == 0 { return; }
+        let mut pool: Vec<Creature> = self.elites.iter()
+            .filter(|e| !self.creatures.iter().any(|c| c.id == e.id))
+            .cloned()
+            .collect();
+        pool.extend(self.creatures.iter().cloned());
+        for c in &mut pool { c.brain.activations = None; }
+        sort_by_fitness(&mut pool, &self.config);
+        pool.truncate(k);
+        self.elites = pool;
+
+        let layer_sizes = self.brain_layer_sizes();
+        let mut born = Vec::new();
+        for e in &self.elites {
+            // Living elites give birth where they stand; archived ones reseed at random
+            let living = self.creatures.iter().find(|c| c.id == e.id);
+            for _ in 0..self.config.elitism.offspring_per_elite {
+                let (x, y) = match living {
+                    Some(c) => (
+                        (c.x + self.rng.uniform(-8.0, 8.0)).clamp(0.0, self.width),
+                        (c.y + self.rng.uniform(-8.0, 8.0)).clamp(0.0, self.height),
+                    ),
+                    None => (self.rng.uniform(0.0, self.width), self.rng.uniform(0.0, self.height)),
+                };
+                let generation = e.generation + 1;
+                let brain = if e.brain.layer_sizes.first() == Some(&self.input_len()) {
+                    inherit_brain(&heritable_brain(e, &self.config.plasticity), None, generation, &self.config, &mut self.rng, &self.bad_brain_hashes).map(|(b, _)| b)
+                } else { None };
+                let brain = brain.unwrap_or_else(|| init_brain_avoiding_bad(layer_sizes.clone(), &mut self.rng, &self.bad_brain_hashes, self.config.legacy_brain_hash));
+                let (genes, _) = e.genes.inherit(&mut self.rng, &self.config);
+                let id = take_creature_id(&mut self.next_creature_id);
+                born.push(Creature { radius: 4.0, energy: 80.0, genes, generation, lineage: e.lineage.clone(), parent_id: Some(e.id.clone()), ..Creature::new(id, x, y, e.diet, brain) });
+            }
+        }
+        log::debug!("elitism: {} elites, {} offspring at tick {}", self.elites.len(), born.len(), self.tick);
+        for c in &born { self.highlights.on_birth(self.tick, c); }
+        self.flows.births += born.iter().map(|c| c.energy).sum::<f32>();
+        self.births_total += born.len() as u64;
+        self.creatures.extend(born);
+    }
+}
+
+// Brain of the nearest other creature with the same diet and brain topology
+pub(crate) fn nearest_mate<'a>(c: &Creature, a: &'a [Creature], b: &'a [Creature]) -> Option<&'a Creature> {
+    let mut best: Option<(&Creature, f32)> = None;
+    for o in a.iter().chain(b.iter()) {
+        if o.diet != c.diet || o.brain.layer_sizes != c.brain.layer_sizes { continue; }
+        let dx = o.x - c.x; let dy = o.y - c.y; let d2 = dx * dx + dy * dy;
+        if best.is_none_or(|(_, bd)| d2 < bd) { best = Some((o, d2)); }
+    }
+    best.map(|(mate, _)| mate)
+}
+
+// Recombine two same-topology brains; None when crossover is off or the topologies differ
+pub(crate) fn crossover(a: &Brain, b: &Brain, x: &CrossoverConfig, rng: &mut RngLCG) -> Option<Brain> {
+    if x.mode == CrossoverMode::None || a.layer_sizes != b.layer_sizes || !a.is_well_formed() || !b.is_well_formed() { return None; }
+    let mut child = Brain { activations: None, ..a.clone() };
+    let (Some(cw), Some(cb)) = (child.weights.as_mut(), child.biases.as_mut()) else { return None; };
+    let (Some(bw), Some(bb)) = (b.weights.as_ref(), b.biases.as_ref()) else { return None; };
+    let r = x.mix_ratio.clamp(0.0, 1.0);
+    for ((w, b_), (mw, mb)) in cw.iter_mut().zip(cb.iter_mut()).zip(bw.iter().zip(bb)) {
+        match x.mode {
+            CrossoverMode::None => {}
+            CrossoverMode::Uniform => {
+                for (v, m) in w.iter_mut().zip(mw).chain(b_.iter_mut().zip(mb)) {
+                    if rng.next_f32_01() < r { *v = *m; }
+                }
+            }
+            CrossoverMode::Layer => {
+                if rng.next_f32_01() < r { w.copy_from_slice(mw); b_.copy_from_slice(mb); }
+            }
+            CrossoverMode::Blend => {
+                for (v, m) in w.iter_mut().zip(mw).chain(b_.iter_mut().zip(mb)) { *v = (1.0 - r) * *v + r * m; }
+            }
+        }
+    }
+    Some(child)
+}
+
+// Grow or shrink hidden capacity according to the structural mutation probabilities
+fn mutate_structure(brain: &mut Brain, cfg: &Config, rng: &mut RngLCG) {
+    if !brain.is_well_formed() { return; }
+    let sigma = cfg.structural_noise_sigma;
+    if rng.next_f32_01() < cfg.add_layer_prob { add_hidden_layer(brain, cfg.max_hidden_layers as usize, sigma, rng); }
+    if rng.next_f32_01() < cfg.add_neuron_prob { add_hidden_neuron(brain, cfg.max_hidden_neurons, sigma, rng); }
+    if rng.next_f32_01() < cfg.remove_neuron_prob {
+        let shrinkable: Vec<usize> = (1..brain.layer_sizes.len() - 1).filter(|&li| brain.layer_sizes[li] > 1).collect();
+        if !shrinkable.is_empty() {
+            let li = shrinkable[pick(rng, shrinkable.len())];
+            let j = pick(rng, brain.layer_sizes[li] as usize);
+            remove_neuron(brain, li, j);
+        }
+    }
+}
+
+// Perturb or redraw individual weights and biases; sigma anneals with the offspring generation.
+// Returns the L2 norm of the changes.
+pub(crate) fn mutate_weights(brain: &mut Brain, m: &MutationConfig, generation: u32, rng: &mut RngLCG) -> f32 {
+    if !brain.is_well_formed() { return 0.0; }
+    let sigma = m.sigma_at(generation);
+    let (Some(weights), Some(biases)) = (brain.weights.as_mut(), brain.biases.as_mut()) else { return 0.0; };
+    let mut sq = 0.0f32;
+    for (li, (w, b)) in weights.iter_mut().zip(biases.iter_mut()).enumerate() {
+        // Same He-like scale as init_brain for resets
+        let init_scale = (2.0f32 / (brain.layer_sizes[li] as f32).max(1.0)).sqrt();
+        for v in w.iter_mut() {
+            let before = *v;
+            let roll = rng.next_f32_01();
+            if roll < m.reset_prob { *v = rng.uniform(-1.0, 1.0) * init_scale; }
+            else if roll < m.reset_prob + m.weight_prob { *v += rng.normal() * sigma; }
+            sq += (*v - before) * (*v - before);
+        }
+        for v in b.iter_mut() {
+            if rng.next_f32_01() < m.weight_prob {
+                let d = rng.normal() * sigma * m.bias_scale;
+                *v += d;
+                sq += d * d;
+            }
+        }
+    }
+    sq.sqrt()
+}
+
+fn pick(rng: &mut RngLCG, n: usize) -> usize { ((rng.next_f32_01() * n as f32) as usize).min(n.saturating_sub(1)) }
+
+// Duplicate a random hidden neuron (incoming row and bias plus noise); its outgoing weights start
+// near zero so the network's behaviour barely changes until they evolve
+fn add_hidden_neuron(brain: &mut Brain, max_neurons: u32, sigma: f32, rng: &mut RngLCG) {
+    let growable: Vec<usize> = (1..brain.layer_sizes.len() - 1).filter(|&li| brain.layer_sizes[li] < max_neurons).collect();
+    if growable.is_empty() { return; }
+    let li = growable[pick(rng, growable.len())];
+    let n_in = brain.layer_sizes[li - 1] as usize;
+    let n_here = brain.layer_sizes[li] as usize;
+    let n_next = brain.layer_sizes[li + 1] as usize;
+    let k = pick(rng, n_here);
+    let (Some(weights), Some(biases)) = (brain.weights.as_mut(), brain.biases.as_mut()) else { return; };
+    let row: Vec<f32> = weights[li - 1][k * n_in..(k + 1) * n_in].iter().map(|w| w + rng.normal() * sigma).collect();
+    weights[li - 1].extend(row);
+    let bias = biases[li - 1][k] + rng.normal() * sigma;
+    biases[li - 1].push(bias);
+    let old = std::mem::take(&mut weights[li]);
+    let mut next = Vec::with_capacity(n_next * (n_here + 1));
+    for r in 0..n_next {
+        next.extend_from_slice(&old[r * n_here..(r + 1) * n_here]);
+        next.push(rng.normal() * sigma);
+    }
+    weights[li] = next;
+    brain.layer_sizes[li] += 1;
+}
+
+// Insert a hidden layer before the output layer, initialized to (noisy) identity so the last
+// hidden layer's non-negative ReLU outputs pass through unchanged
+fn add_hidden_layer(brain: &mut Brain, max_layers: usize, sigma: f32, rng: &mut RngLCG) {
+    let hidden = brain.layer_sizes.len() - 2;
+    if hidden == 0 || hidden >= max_layers { return; }
+    let at = brain.layer_sizes.len() - 1;
+    let n = brain.layer_sizes[at - 1] as usize;
+    let (Some(weights), Some(biases)) = (brain.weights.as_mut(), brain.biases.as_mut()) else { return; };
+    let mut w = vec![0.0f32; n * n];
+    for (i, v) in w.iter_mut().enumerate() {
+        let identity = if i / n == i % n { 1.0 } else { 0.0 };
+        *v = identity + rng.normal() * sigma;
+    }
+    weights.insert(at - 1, w);
+    biases.insert(at - 1, vec![0.0; n]);
+    brain.layer_sizes.insert(at, n as u32);
+}
+
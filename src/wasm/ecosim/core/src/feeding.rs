@@ -0,0 +1,46 @@
+// Hand feeding: god-mode calls that give a creature energy directly or drop a food item.
+// Both go through the command queue, so they land in the replay log and a replay (or a lockstep
+// peer) applies them at the same tick; each emits an event when it takes effect.
+
+use crate::action_log::CreatureAction;
+use crate::events::{push_event, WorldEvent};
+use crate::history::Command;
+use crate::World;
+
+impl World {
+    // Queue `amount` energy for creature `id` before the next tick. Returns the command's sequence
+    // id, or None when there is no such creature.
+    pub fn feed_creature(&mut self, id: &str, amount: f32) -> Option<u64> {
+        if !self.creatures.iter().any(|c| c.id == id) {
+            log::warn!("feed_creature: no creature '{}'", id);
+            return None;
+        }
+        self.queue_command(self.tick, Command::FeedCreature { id: id.to_string(), amount })
+    }
+
+    // Queue a food drop of `energy` at (x, y) before the next tick; returns the sequence id
+    pub fn place_food(&mut self, x: f32, y: f32, energy: f32) -> Option<u64> {
+        self.queue_command(self.tick, Command::PlaceFood { x, y, energy })
+    }
+
+    pub(crate) fn apply_feed_creature(&mut self, id: &str, amount: f32) {
+        let Some(c) = self.creatures.iter_mut().find(|c| c.id == id) else {
+            log::warn!("feedCreature command: no creature '{}'", id);
+            return;
+        };
+        let before = c.energy;
+        c.energy = (c.energy + amount.max(0.0)).min(100.0f32.max(before));
+        let fed = c.energy - before;
+        // The creature pass resets the ledger; the intake credit is booked after it
+        self.hand_fed.push((c.id.clone(), fed));
+        c.recent_actions.push(self.tick, CreatureAction::HandFed, fed);
+        push_event(&mut self.events, WorldEvent::HandFed { tick: self.tick, id: id.to_string(), amount: fed });
+    }
+
+    // The drop is a food item with the default decay (Config::food_items)
+    pub(crate) fn apply_place_food(&mut self, x: f32, y: f32, energy: f32) {
+        if !self.spawn_food(x, y, energy, None) { return; }
+        let Some(f) = self.food.last() else { return };
+        push_event(&mut self.events, WorldEvent::FoodPlaced { tick: self.tick, x: f.x, y: f.y, energy: f.energy });
+    }
+}
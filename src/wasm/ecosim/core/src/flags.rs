@@ -0,0 +1,103 @@
+// Bit registries for Creature::actions_mask and Creature::feelings_mask. Each discriminant is the
+// bit position; hosts take names and masks from here (the ActionFlag/FeelingFlag JS enums or
+// World::flag_registry) instead of hard-coding bit numbers.
+
+use serde::Serialize;
+
+#[cfg_attr(feature = "ts", wasm_bindgen::prelude::wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActionFlag {
+    Resting = 0,
+    Eating = 1,
+    Sprinting = 2,
+    Attacking = 3, // bit prey in reach this tick
+    Drinking = 4,
+    Flying = 5,
+    Burrowed = 6,
+    Digging = 7,
+    Sharing = 8,
+    Carrying = 9,
+    Torpid = 10,
+}
+
+#[cfg_attr(feature = "ts", wasm_bindgen::prelude::wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeelingFlag {
+    Thirsty = 0,
+    Hungry = 1,
+    Fatigued = 2,
+    Restless = 3, // stagnant for Config::stagnant_ticks_limit ticks
+    Drowning = 4,
+}
+
+impl ActionFlag {
+    pub const ALL: [ActionFlag; 11] = [
+        ActionFlag::Resting, ActionFlag::Eating, ActionFlag::Sprinting, ActionFlag::Attacking, ActionFlag::Drinking,
+        ActionFlag::Flying, ActionFlag::Burrowed, ActionFlag::Digging, ActionFlag::Sharing, ActionFlag::Carrying,
+        ActionFlag::Torpid,
+    ];
+
+    pub const fn mask(self) -> u32 { 1 << self as u32 }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ActionFlag::Resting => "resting",
+            ActionFlag::Eating => "eating",
+            ActionFlag::Sprinting => "sprinting",
+            ActionFlag::Attacking => "attacking",
+            ActionFlag::Drinking => "drinking",
+            ActionFlag::Flying => "flying",
+            ActionFlag::Burrowed => "burrowed",
+            ActionFlag::Digging => "digging",
+            ActionFlag::Sharing => "sharing",
+            ActionFlag::Carrying => "carrying",
+            ActionFlag::Torpid => "torpid",
+        }
+    }
+}
+
+impl FeelingFlag {
+    pub const ALL: [FeelingFlag; 5] = [
+        FeelingFlag::Thirsty, FeelingFlag::Hungry, FeelingFlag::Fatigued, FeelingFlag::Restless, FeelingFlag::Drowning,
+    ];
+
+    pub const fn mask(self) -> u32 { 1 << self as u32 }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FeelingFlag::Thirsty => "thirsty",
+            FeelingFlag::Hungry => "hungry",
+            FeelingFlag::Fatigued => "fatigued",
+            FeelingFlag::Restless => "restless",
+            FeelingFlag::Drowning => "drowning",
+        }
+    }
+}
+
+// One named bit of a mask
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlagInfo {
+    pub bit: u32,
+    pub mask: u32,
+    pub name: &'static str,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlagRegistry {
+    pub actions: Vec<FlagInfo>,
+    pub feelings: Vec<FlagInfo>,
+}
+
+impl crate::World {
+    // Bit position, mask, and name of every actions_mask and feelings_mask flag, in bit order
+    pub fn flag_registry() -> FlagRegistry {
+        FlagRegistry {
+            actions: ActionFlag::ALL.iter().map(|&f| FlagInfo { bit: f as u32, mask: f.mask(), name: f.name() }).collect(),
+            feelings: FeelingFlag::ALL.iter().map(|&f| FlagInfo { bit: f as u32, mask: f.mask(), name: f.name() }).collect(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// Food items: loose morsels (fallen fruit, dropped food, scripted manna) that hold energy, lose it
+// over time, and vanish once spent. Creatures sense them alongside plants and eat them the same
+// way, but items never regrow. Hosts spawn them directly (spawn_food) or through the command queue
+// (place_food); fully grown plants can also drop fruit, which takes its energy from the plant.
+
+use serde::{Deserialize, Serialize};
+
+use crate::action_log::CreatureAction;
+use crate::creature::LedgerCat;
+use crate::flags::ActionFlag;
+use crate::World;
+
+// Items with less energy than this are gone
+const SPENT_BELOW: f32 = 0.01;
+
+// Food item settings (serialized as the `foodItems` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FoodItemConfig {
+    pub decay_per_sec: f32,  // fraction of an item's energy lost per second, unless spawned with its own rate
+    pub max_items: u32,      // spawning and fruit drop stop at this many items
+    pub fruit_drop: bool,    // fully grown plants drop fruit
+    pub fruit_per_sec: f32,  // chance per second that a fully grown plant drops a fruit
+    pub fruit_energy: f32,   // taken from the plant's biomass
+    pub fruit_spread: f32,   // max distance of a fruit from its plant
+}
+
+impl Default for FoodItemConfig {
+    fn default() -> Self {
+        Self { decay_per_sec: 0.01, max_items: 500, fruit_drop: false, fruit_per_sec: 0.002, fruit_energy: 4.0, fruit_spread: 10.0 }
+    }
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FoodItem {
+    pub x: f32,
+    pub y: f32,
+    pub energy: f32,
+    pub decay_per_sec: f32,
+    pub born_tick: u64,
+}
+
+// Nearest item with energy left within `reach` of (x, y)
+pub(crate) fn food_target(items: &[FoodItem], x: f32, y: f32, reach: f32) -> Option<usize> {
+    items.iter().enumerate()
+        .filter(|(_, f)| f.energy > 0.0)
+        .map(|(i, f)| (i, (f.x - x).hypot(f.y - y)))
+        .filter(|&(_, d)| d <= reach)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+impl World {
+    pub fn food_items(&self) -> &[FoodItem] { self.front.as_ref().map_or(&self.food, |f| &f.food) }
+
+    // Drop a food item holding `energy` at (x, y), losing `decay_per_sec` of it per second
+    // (Config::food_items.decay_per_sec when None). False when the item cap is reached.
+    pub fn spawn_food(&mut self, x: f32, y: f32, energy: f32, decay_per_sec: Option<f32>) -> bool {
+        if self.food.len() >= self.config.food_items.max_items as usize {
+            log::warn!("spawn_food: item cap {} reached", self.config.food_items.max_items);
+            return false;
+        }
+        if let Some(g) = self.ghost.as_deref_mut() { g.spawn_food(x, y, energy, decay_per_sec); }
+        let (x, y) = (x.rem_euclid(self.width.max(1.0)), y.clamp(0.0, self.height));
+        let decay_per_sec = decay_per_sec.unwrap_or(self.config.food_items.decay_per_sec).max(0.0);
+        self.food.push(FoodItem { x, y, energy: energy.max(0.0), decay_per_sec, born_tick: self.tick });
+        true
+    }
+
+    // Resolve this tick's (creature index, item index) bites; eaters of one item split what is there
+    pub(crate) fn resolve_food_bites(&mut self, bites: &[(usize, usize)], t_sec: f32) {
+        if bites.is_empty() { return; }
+        let want = self.config.harvest_rate_per_sec.max(0.0) * t_sec;
+        let mut demand = vec![0u32; self.food.len()];
+        for &(_, f) in bites { demand[f] += 1; }
+        let share: Vec<f32> = self.food.iter().zip(&demand)
+            .map(|(f, &n)| if n == 0 { 0.0 } else { want.min(f.energy / n as f32) })
+            .collect();
+        for &(i, f) in bites {
+            let got = share[f];
+            if got <= 0.0 { continue; }
+            let c = &mut self.creatures[i];
+            c.apply_energy(LedgerCat::Intake, got, 0.0);
+            c.metrics.energy_harvested += got;
+            c.actions_mask |= ActionFlag::Eating.mask();
+            c.recent_actions.push(self.tick, CreatureAction::AteFood, got);
+        }
+        for (f, (&n, s)) in self.food.iter_mut().zip(demand.iter().zip(share)) { f.energy -= s * n as f32; }
+    }
+
+    // Fruit drop, then decay; spent items are removed
+    pub(crate) fn update_food(&mut self, t_sec: f32) {
+        let cfg = &self.config.food_items;
+        let cap = cfg.max_items as usize;
+        if cfg.fruit_drop && cfg.fruit_energy > 0.0 {
+            let (full, chance) = (self.config.plant_max_biomass, cfg.fruit_per_sec.max(0.0) * t_sec);
+            for p in self.plants.iter_mut().filter(|p| p.bloom_until.is_none()) {
+                if self.food.len() >= cap { break; }
+                if p.biomass < full || self.rng.next_f32_01() >= chance { continue; }
+                let angle = self.rng.next_f32_01() * std::f32::consts::TAU;
+                let r = self.rng.next_f32_01() * cfg.fruit_spread.max(0.0);
+                let energy = cfg.fruit_energy.min(p.biomass);
+                p.biomass -= energy;
+                let x = (p.x + angle.cos() * r).rem_euclid(self.width.max(1.0));
+                let y = (p.y + angle.sin() * r).clamp(0.0, self.height);
+                self.food.push(FoodItem { x, y, energy, decay_per_sec: cfg.decay_per_sec.max(0.0), born_tick: self.tick });
+            }
+        }
+        for f in &mut self.food {
+            let lost = f.energy * (f.decay_per_sec * t_sec).clamp(0.0, 1.0);
+            f.energy -= lost;
+            self.flows.food_decay += lost;
+        }
+        self.flows.food_decay += self.food.iter().filter(|f| f.energy < SPENT_BELOW).map(|f| f.energy).sum::<f32>();
+        self.food.retain(|f| f.energy >= SPENT_BELOW);
+    }
+}
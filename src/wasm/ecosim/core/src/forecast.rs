@@ -0,0 +1,69 @@
+// Weather forecast: rain, temperature, and wind are pure functions of position, tick, and seed, so
+// future weather can be read off the model directly without stepping the world. Bloom schedules are
+// just as predictable, so upcoming blooms ride along.
+
+use serde::Serialize;
+
+use crate::environment::{sample_rain01, sample_wind_speed};
+use crate::blooms::UpcomingBloom;
+use crate::seasons::Season;
+use crate::World;
+
+const FORECAST_FRAMES: u64 = 8;
+const FORECAST_RESOLUTION: usize = 8;
+
+// Weather at one future tick on a cols x rows grid (row-major, cell centres), plus world means
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ForecastFrame {
+    pub tick: u64,
+    pub season: Option<Season>,
+    pub mean_rain01: f32,
+    pub mean_temperature_c: f32,
+    pub mean_wind: f32,
+    pub rain01: Vec<f32>,
+    pub temperature_c: Vec<f32>,
+    pub wind: Vec<f32>,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Forecast {
+    pub cols: u32,
+    pub rows: u32,
+    pub frames: Vec<ForecastFrame>,
+    pub blooms: Vec<UpcomingBloom>, // under way now or starting within the window
+}
+
+impl World {
+    // Upcoming weather at up to 8 evenly spaced ticks over the next `ticks_ahead` ticks (the last
+    // frame is exactly ticks_ahead out), plus scheduled blooms. Read-only: the simulation RNG is untouched.
+    pub fn forecast(&self, ticks_ahead: u64) -> Forecast {
+        let n = FORECAST_RESOLUTION;
+        let frames = FORECAST_FRAMES.min(ticks_ahead);
+        let (cw, ch) = (self.width / n as f32, self.height / n as f32);
+        let frames = (1..=frames).map(|k| {
+            let tick = self.tick + ticks_ahead * k / frames;
+            let mut frame = ForecastFrame {
+                tick, season: self.config.seasons.season_at(tick), mean_rain01: 0.0, mean_temperature_c: 0.0, mean_wind: 0.0,
+                rain01: Vec::with_capacity(n * n), temperature_c: Vec::with_capacity(n * n), wind: Vec::with_capacity(n * n),
+            };
+            for gy in 0..n {
+                for gx in 0..n {
+                    let (x, y) = ((gx as f32 + 0.5) * cw, (gy as f32 + 0.5) * ch);
+                    frame.rain01.push(sample_rain01(x, y, tick));
+                    frame.temperature_c.push(self.climate.temperature_c(x, y, tick));
+                    frame.wind.push(sample_wind_speed(x, y, tick));
+                }
+            }
+            let mean = |v: &[f32]| v.iter().sum::<f32>() / v.len() as f32;
+            frame.mean_rain01 = mean(&frame.rain01);
+            frame.mean_temperature_c = mean(&frame.temperature_c);
+            frame.mean_wind = mean(&frame.wind);
+            frame
+        }).collect();
+        Forecast { cols: n as u32, rows: n as u32, frames, blooms: self.upcoming_blooms(ticks_ahead) }
+    }
+}
@@ -0,0 +1,56 @@
+// Double-buffered exports (Config::double_buffered_exports): at the end of every step() the entity
+// arrays are copied into a read buffer that the export getters serve, so a host reading from
+// another thread or between sub-steps always sees one completed frame. Edits made between steps
+// (spawns, commands) show up after the next step; restores and resets drop the buffer until then.
+
+use crate::creature::{creature_vec_bytes, Burrow, Corpse, Creature, Plant};
+use crate::decomposers::Decomposer;
+use crate::food::FoodItem;
+use crate::World;
+
+#[derive(Default)]
+pub(crate) struct FrontBuffer {
+    pub(crate) tick: u64,
+    pub(crate) creatures: Vec<Creature>,
+    pub(crate) plants: Vec<Plant>,
+    pub(crate) corpses: Vec<Corpse>,
+    pub(crate) burrows: Vec<Burrow>,
+    pub(crate) decomposers: Vec<Decomposer>,
+    pub(crate) food: Vec<FoodItem>,
+    pub(crate) elites: Vec<Creature>,
+}
+
+impl FrontBuffer {
+    pub(crate) fn heap_bytes(&self) -> usize {
+        std::mem::size_of::<FrontBuffer>()
+            + creature_vec_bytes(&self.creatures)
+            + creature_vec_bytes(&self.elites)
+            + self.plants.capacity() * std::mem::size_of::<Plant>()
+            + self.corpses.capacity() * std::mem::size_of::<Corpse>()
+            + self.burrows.capacity() * std::mem::size_of::<Burrow>()
+            + self.decomposers.capacity() * std::mem::size_of::<Decomposer>()
+            + self.food.capacity() * std::mem::size_of::<FoodItem>()
+    }
+}
+
+impl World {
+    // Copy the completed frame into the read buffer (reusing its allocations)
+    pub(crate) fn publish_frame(&mut self) {
+        if !self.config.double_buffered_exports {
+            self.front = None;
+            return;
+        }
+        let front = self.front.get_or_insert_with(Box::default);
+        front.tick = self.tick;
+        front.creatures.clone_from(&self.creatures);
+        front.plants.clone_from(&self.plants);
+        front.corpses.clone_from(&self.corpses);
+        front.burrows.clone_from(&self.burrows);
+        front.decomposers.clone_from(&self.decomposers);
+        front.food.clone_from(&self.food);
+        front.elites.clone_from(&self.elites);
+    }
+
+    // Tick of the frame the export getters currently serve
+    pub fn frame_tick(&self) -> u64 { self.front.as_ref().map_or(self.tick, |f| f.tick) }
+}
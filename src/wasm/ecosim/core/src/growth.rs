@@ -0,0 +1,60 @@
+// Juvenile growth: newborns start small and invest a share of what they eat in growing toward the
+// adult size set by their adult_size gene. Growth is paid for out of food intake, so a cohort born
+// into a famine stays small once its juvenile window closes.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::{mutate_trait, LedgerCat};
+use crate::rng::RngLCG;
+use crate::World;
+
+const ADULT_SIZE_RANGE: (f32, f32) = (0.5, 2.0);
+
+// Growth settings (serialized as the `growth` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GrowthConfig {
+    pub enabled: bool,
+    pub adult_radius: f32,      // adult radius for an adult_size gene of 1
+    pub intake_fraction: f32,   // share of each tick's food intake a juvenile puts into growth
+    pub energy_per_radius: f32, // energy converted per unit of radius gained
+    pub max_rate_per_sec: f32,  // radius gained per second at most
+    pub juvenile_ticks: u32,    // growth stops at this age; the size reached by then is final
+    pub size_mutation_prob: f32,
+    pub size_mutation_sigma: f32,
+}
+
+impl Default for GrowthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, adult_radius: 5.0, intake_fraction: 0.5, energy_per_radius: 20.0, max_rate_per_sec: 0.01,
+            juvenile_ticks: 60 * 120, size_mutation_prob: 0.5, size_mutation_sigma: 0.05,
+        }
+    }
+}
+
+impl GrowthConfig {
+    pub(crate) fn inherit_size(&self, rng: &mut RngLCG, parent: f32) -> f32 {
+        mutate_trait(rng, parent, self.size_mutation_prob, self.size_mutation_sigma, ADULT_SIZE_RANGE)
+    }
+}
+
+impl World {
+    // After feeding: juveniles below their adult radius convert part of this tick's intake into size
+    pub(crate) fn grow_juveniles(&mut self, t_sec: f32) {
+        let cfg = &self.config.growth;
+        if !cfg.enabled { return; }
+        let per_radius = cfg.energy_per_radius.max(1e-6);
+        let max_step = cfg.max_rate_per_sec.max(0.0) * t_sec;
+        for c in &mut self.creatures {
+            if c.lifespan >= cfg.juvenile_ticks { continue; }
+            let room = cfg.adult_radius * c.genes.adult_size - c.radius;
+            let budget = cfg.intake_fraction.clamp(0.0, 1.0) * c.ledger.0[LedgerCat::Intake as usize].max(0.0);
+            let grow = (budget / per_radius).min(max_step).min(room);
+            if grow <= 0.0 { continue; }
+            c.radius += grow;
+            c.apply_energy(LedgerCat::Growth, -grow * per_radius, 0.0);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Plant harvesting: eaters target one plant each and drain its biomass at their harvest rate;
+// when a plant holds less than its eaters want, they split what is there. Plants regrow slowly.
+
+use crate::action_log::CreatureAction;
+use crate::creature::Plant;
+use crate::flags::ActionFlag;
+use crate::World;
+
+// Biomass of plants restored from snapshots that predate harvesting (Config::plant_max_biomass default)
+pub(crate) const DEFAULT_PLANT_BIOMASS: f32 = 10.0;
+
+// Nearest plant with biomass left whose edge is within `reach` of (x, y)
+pub(crate) fn harvest_target(plants: &[Plant], x: f32, y: f32, reach: f32) -> Option<usize> {
+    plants.iter().enumerate()
+        .filter(|(_, p)| p.biomass > 0.0)
+        .map(|(i, p)| (i, (p.x - x).hypot(p.y - y) - p.radius))
+        .filter(|&(_, gap)| gap <= reach)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+impl World {
+    // Resolve this tick's (creature index, plant index) bites, then regrow every plant
+    pub(crate) fn resolve_harvest(&mut self, bites: &[(usize, usize)], t_sec: f32) {
+        let want = self.config.harvest_rate_per_sec.max(0.0) * t_sec;
+        let mut demand = vec![0u32; self.plants.len()];
+        for &(_, p) in bites { demand[p] += 1; }
+        let share: Vec<f32> = self.plants.iter().zip(&demand)
+            .map(|(p, &n)| if n == 0 { 0.0 } else { want.min(p.biomass / n as f32) })
+            .collect();
+        for &(i, p) in bites {
+            let got = share[p];
+            if got <= 0.0 { continue; }
+            let c = &mut self.creatures[i];
+            self.config.diet_specialization.digest(c, true, got);
+            c.behavior.plant_intake += got;
+            c.metrics.energy_harvested += got;
+            c.actions_mask |= ActionFlag::Eating.mask();
+            c.recent_actions.push(self.tick, CreatureAction::AtePlant { plant: p as u32 }, got);
+            self.tally.biomass_eaten += got;
+        }
+        self.tally.plants_eaten += share.iter().zip(&demand).filter(|&(&s, &n)| s > 0.0 && n > 0).count() as u32;
+        let max = self.config.plant_max_biomass.max(0.0);
+        let regrow = self.config.plant_regrowth_per_sec.max(0.0) * t_sec;
+        // Local temperature and season scale regrowth (see Config::seasons)
+        let growth: Vec<f32> = self.plants.iter().map(|p| self.plant_growth_at(p.x, p.y)).collect();
+        for (((p, &n), s), g) in self.plants.iter_mut().zip(&demand).zip(share).zip(growth) {
+            let left = p.biomass - s * n as f32;
+            // Bloom flowers only shrink
+            p.biomass = if p.bloom_until.is_some() { left.max(0.0) } else { (left + regrow * g).clamp(0.0, max) };
+            self.flows.plant_growth += p.biomass - left;
+        }
+    }
+}
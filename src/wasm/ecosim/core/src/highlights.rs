@@ -0,0 +1,102 @@
+// Highlights: a bounded digest of notable moments (first kills, lifespan records, new brain
+// topologies, extinctions) so hosts can show a narrative feed without filtering the event stream.
+// Not part of snapshots; it restarts empty after a restore.
+
+use std::collections::{HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::{Creature, Diet};
+
+// Oldest highlights are dropped past this many
+const MAX_HIGHLIGHTS: usize = 64;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Highlight {
+    // First creature of its generation credited with a kill
+    FirstKill { tick: u64, id: String, generation: u32 },
+    // Longest life so far among descendants (founders set the bar silently)
+    RecordLifespan { tick: u64, id: String, generation: u32, lifespan: u32 },
+    // Newborn whose brain topology has never been seen before (a new "species")
+    #[serde(rename_all = "camelCase")]
+    NewSpecies { tick: u64, id: String, generation: u32, layer_sizes: Vec<u32> },
+    // The last member of a founder lineage that had produced offspring died
+    LineageExtinct { tick: u64, lineage: String },
+    // A diet's population fell below Config::extinction_warning_threshold
+    ExtinctionWarning { tick: u64, diet: Diet, count: u32 },
+}
+
+#[derive(Default)]
+pub(crate) struct HighlightDigest {
+    entries: VecDeque<Highlight>,
+    record_lifespan: u32,
+    kill_generations: HashSet<u32>,
+    topologies: HashSet<Vec<u32>>,
+    // Lineages with in-engine births; only these are reported when they die out
+    dynasties: HashSet<String>,
+    // Lineages that lost a member this tick, checked for extinction after removal
+    bereaved: Vec<String>,
+    // Per diet (herbivore, carnivore): warn on the next drop below the threshold; armed while at or
+    // above it, so worlds that start small don't warn immediately
+    armed: [bool; 2],
+}
+
+impl HighlightDigest {
+    pub(crate) fn entries(&self) -> &VecDeque<Highlight> { &self.entries }
+
+    // Entries plus the kill/topology/dynasty sets (approximate: set overhead ignored)
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.entries.capacity() * std::mem::size_of::<Highlight>()
+            + self.kill_generations.capacity() * std::mem::size_of::<u32>()
+            + self.topologies.iter().map(|t| std::mem::size_of::<Vec<u32>>() + t.capacity() * std::mem::size_of::<u32>()).sum::<usize>()
+            + self.dynasties.iter().chain(&self.bereaved).map(|s| std::mem::size_of::<String>() + s.capacity()).sum::<usize>()
+    }
+
+    fn push(&mut self, h: Highlight) {
+        if self.entries.len() >= MAX_HIGHLIGHTS { self.entries.pop_front(); }
+        self.entries.push_back(h);
+    }
+
+    pub(crate) fn on_birth(&mut self, tick: u64, c: &Creature) {
+        if !self.dynasties.contains(&c.lineage) { self.dynasties.insert(c.lineage.clone()); }
+        if self.topologies.insert(c.brain.layer_sizes.clone()) {
+            self.push(Highlight::NewSpecies { tick, id: c.id.clone(), generation: c.generation, layer_sizes: c.brain.layer_sizes.clone() });
+        }
+    }
+
+    pub(crate) fn on_death(&mut self, tick: u64, c: &Creature) {
+        if c.lifespan > self.record_lifespan {
+            self.record_lifespan = c.lifespan;
+            if c.generation > 0 { self.push(Highlight::RecordLifespan { tick, id: c.id.clone(), generation: c.generation, lifespan: c.lifespan }); }
+        }
+        if self.dynasties.contains(&c.lineage) && !self.bereaved.contains(&c.lineage) { self.bereaved.push(c.lineage.clone()); }
+    }
+
+    // Once per tick over the surviving population: kills, lineage extinctions, and population
+    // warnings. Topologies of creatures not born in-engine (founders, spawns) join the baseline.
+    pub(crate) fn after_step(&mut self, tick: u64, living: &[Creature], warn_below: u32) {
+        for c in living {
+            if !self.topologies.contains(&c.brain.layer_sizes) { self.topologies.insert(c.brain.layer_sizes.clone()); }
+            if c.metrics.kills > 0 && self.kill_generations.insert(c.generation) {
+                self.push(Highlight::FirstKill { tick, id: c.id.clone(), generation: c.generation });
+            }
+        }
+        for lineage in std::mem::take(&mut self.bereaved) {
+            if !living.iter().any(|c| c.lineage == lineage) {
+                self.dynasties.remove(&lineage);
+                self.push(Highlight::LineageExtinct { tick, lineage });
+            }
+        }
+        for (k, diet) in [Diet::Herbivore, Diet::Carnivore].into_iter().enumerate() {
+            let count = living.iter().filter(|c| c.diet == diet).count() as u32;
+            if count >= warn_below {
+                self.armed[k] = true;
+            } else if self.armed[k] {
+                self.armed[k] = false;
+                self.push(Highlight::ExtinctionWarning { tick, diet, count });
+            }
+        }
+    }
+}
@@ -0,0 +1,716 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::{Serialize, Deserialize};
+
+use crate::brain::BrainMode;
+use crate::casing::{rekey_entities, snake_to_camel};
+use crate::config::{merge_config, Config};
+use crate::action_log::ActionLog;
+use crate::brain::{NeuronActivity, OutputWatch};
+use crate::creature::{creature_vec_bytes, Burrow, Corpse, Creature, Plant};
+use crate::decomposers::{Decomposer, SoilGrid};
+use crate::food::FoodItem;
+use crate::environment::{Climate, NoiseParams, HostGrid};
+use crate::novelty::BehaviorTrace;
+use crate::perf::PerfCounters;
+use crate::rl::RewardSource;
+use crate::audit::EnergyFlows;
+use crate::highlights::HighlightDigest;
+use crate::scent::ScentField;
+use crate::sensing::CustomInputs;
+use crate::summary::StepTally;
+use crate::hot::HotArrays;
+use crate::capacity::CapacityLog;
+use crate::migration::MigrationLog;
+use crate::trails::TrailLog;
+use crate::density::DensityGrid;
+use crate::rng::RngLCG;
+use crate::World;
+
+// External command for lockstep/replay; applied at the start of its scheduled tick
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Command {
+    SpawnCreature { x: f32, y: f32 },
+    SpawnPlant { x: f32, y: f32, radius: Option<f32> },
+    SetBrainMode { mode: String },
+    // God-mode feeding (World::feed_creature, World::place_food)
+    FeedCreature { id: String, amount: f32 },
+    PlaceFood { x: f32, y: f32, energy: f32 },
+    SetConfig {
+        #[cfg_attr(feature = "ts", tsify(type = "Partial<Config>"))]
+        overrides: serde_json::Value,
+    },
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledCommand {
+    pub tick: u64,
+    pub seq: u64,
+    pub command: Command,
+}
+
+// 64-bit FNV-1a
+struct Fnv64(u64);
+
+impl Fnv64 {
+    fn new() -> Self { Self(0xcbf2_9ce4_8422_2325) }
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for &b in bytes { self.0 ^= b as u64; self.0 = self.0.wrapping_mul(0x0100_0000_01b3); }
+    }
+    fn write_u32(&mut self, v: u32) { self.write_bytes(&v.to_le_bytes()); }
+    fn write_f32(&mut self, v: f32) { self.write_u32(v.to_bits()); }
+    // Feed a value's msgpack encoding (serialized straight into the hash, no buffer)
+    fn write_serde<T: Serialize + ?Sized>(&mut self, value: &T) {
+        if let Err(e) = rmp_serde::encode::write(self, value) { log::warn!("state hash: {}", e); }
+    }
+}
+
+impl std::io::Write for Fnv64 {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> { self.write_bytes(buf); Ok(buf.len()) }
+    fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+}
+
+// Snapshot format history (bump and add a step to migrate_snapshot_value on layout changes):
+// 1 = initial msgpack snapshots (no version fields), 2 = formatVersion/configSchemaVersion embedded,
+// 3 = creature/plant/corpse/burrow fields camelCase
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 3;
+// Config schema history: 1 = initial. Added fields need no step (Config deserializes with defaults);
+// renamed or reshaped fields do.
+pub const CONFIG_SCHEMA_VERSION: u32 = 1;
+
+// Per-creature state that creature exports skip but a resumed run needs (Snapshot::runtime, in
+// creature order)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CreatureRuntime {
+    pub output_watch: OutputWatch,
+    pub neuron_activity: NeuronActivity,
+    pub behavior: BehaviorTrace,
+    pub recent_actions: ActionLog,
+}
+
+impl CreatureRuntime {
+    fn take(c: &mut Creature) -> Self {
+        Self {
+            output_watch: std::mem::take(&mut c.output_watch),
+            neuron_activity: std::mem::take(&mut c.neuron_activity),
+            behavior: std::mem::take(&mut c.behavior),
+            recent_actions: std::mem::take(&mut c.recent_actions),
+        }
+    }
+
+    fn put(self, c: &mut Creature) {
+        c.output_watch = self.output_watch;
+        c.neuron_activity = self.neuron_activity;
+        c.behavior = self.behavior;
+        c.recent_actions = self.recent_actions;
+    }
+}
+
+// Full simulation state captured for rewind (activations stripped)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    pub format_version: u32,
+    pub config_schema_version: u32,
+    pub tick: u64,
+    pub width: f32,
+    pub height: f32,
+    pub seed: u32,
+    pub rng_state: u32,
+    pub brain_mode: BrainMode,
+    pub config: Config,
+    pub creatures: Vec<Creature>,
+    #[serde(default)]
+    pub runtime: Vec<CreatureRuntime>,
+    pub plants: Vec<Plant>,
+    pub corpses: Vec<Corpse>,
+    pub burrows: Vec<Burrow>,
+    pub next_burrow_id: u32,
+    // Creature id counter (None in older snapshots: resumed past the highest numeric id in use)
+    #[serde(default)]
+    pub next_creature_id: Option<u64>,
+    pub births_total: u64,
+    pub deaths_total: u64,
+    #[serde(default)]
+    pub attack_attempts_total: u64,
+    #[serde(default)]
+    pub kills_total: u64,
+    #[serde(default)]
+    pub elites: Vec<Creature>,
+    #[serde(default)]
+    pub novelty_archive: Vec<[f32; 3]>,
+    // Custom input channels the brains were sized for (values are host-supplied, not saved)
+    #[serde(default)]
+    pub custom_input_count: u32,
+    #[serde(default)]
+    pub scent: ScentField,
+    #[serde(default)]
+    pub decomposers: Vec<Decomposer>,
+    #[serde(default)]
+    pub soil: SoilGrid,
+    #[serde(default)]
+    pub food: Vec<FoodItem>,
+    #[serde(default)]
+    pub speed_grid: Option<HostGrid>,
+    #[serde(default)]
+    pub elevation_grid: Option<HostGrid>,
+    // Bad-brain hashes, including those flagged at runtime, sorted (None in older snapshots: the
+    // current set is kept)
+    #[serde(default)]
+    pub bad_brain_hashes: Option<Vec<String>>,
+}
+
+// Borrowed view of the live world that serializes exactly like the Snapshot World::snapshot would
+// build, so the state hash can stream the world without copying it. Keep it field-for-field in
+// step with Snapshot.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SnapshotRef<'a> {
+    format_version: u32,
+    config_schema_version: u32,
+    tick: u64,
+    width: f32,
+    height: f32,
+    seed: u32,
+    rng_state: u32,
+    brain_mode: BrainMode,
+    config: &'a Config,
+    #[serde(serialize_with = "creatures_without_activations")]
+    creatures: &'a [Creature],
+    #[serde(serialize_with = "creature_runtimes")]
+    runtime: &'a [Creature],
+    plants: &'a [Plant],
+    corpses: &'a [Corpse],
+    burrows: &'a [Burrow],
+    next_burrow_id: u32,
+    next_creature_id: Option<u64>,
+    births_total: u64,
+    deaths_total: u64,
+    attack_attempts_total: u64,
+    kills_total: u64,
+    elites: &'a [Creature],
+    novelty_archive: &'a [[f32; 3]],
+    custom_input_count: u32,
+    scent: &'a ScentField,
+    decomposers: &'a [Decomposer],
+    soil: &'a SoilGrid,
+    food: &'a [FoodItem],
+    speed_grid: &'a Option<HostGrid>,
+    elevation_grid: &'a Option<HostGrid>,
+    bad_brain_hashes: Option<Vec<&'a String>>,
+}
+
+// Creatures as a snapshot stores them. Only the watched creature holds activations between ticks,
+// so at most that one is copied to drop them.
+fn creatures_without_activations<S: serde::Serializer>(creatures: &&[Creature], s: S) -> Result<S::Ok, S::Error> {
+    struct Stored<'a>(&'a Creature);
+    impl Serialize for Stored<'_> {
+        fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            if self.0.brain.activations.is_none() { return self.0.serialize(s); }
+            let mut c = self.0.clone();
+            c.brain.activations = None;
+            c.serialize(s)
+        }
+    }
+    s.collect_seq(creatures.iter().map(Stored))
+}
+
+// The CreatureRuntime entries a snapshot would take out of each creature
+fn creature_runtimes<S: serde::Serializer>(creatures: &&[Creature], s: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RuntimeRef<'a> {
+        output_watch: &'a OutputWatch,
+        neuron_activity: &'a NeuronActivity,
+        behavior: &'a BehaviorTrace,
+        recent_actions: &'a ActionLog,
+    }
+    s.collect_seq(creatures.iter().map(|c| RuntimeRef {
+        output_watch: &c.output_watch,
+        neuron_activity: &c.neuron_activity,
+        behavior: &c.behavior,
+        recent_actions: &c.recent_actions,
+    }))
+}
+
+// Approximate bytes held by one stored snapshot (see World::memory_report)
+pub(crate) fn snapshot_heap_bytes(snap: &Snapshot) -> usize {
+    let runtime = |r: &CreatureRuntime| r.output_watch.heap_bytes() + r.neuron_activity.heap_bytes() + r.recent_actions.heap_bytes();
+    let grid = |g: &Option<HostGrid>| g.as_ref().map_or(0, |g| g.heap_bytes());
+    std::mem::size_of::<Snapshot>()
+        + creature_vec_bytes(&snap.creatures)
+        + creature_vec_bytes(&snap.elites)
+        + snap.runtime.capacity() * std::mem::size_of::<CreatureRuntime>() + snap.runtime.iter().map(runtime).sum::<usize>()
+        + snap.plants.capacity() * std::mem::size_of::<Plant>()
+        + snap.corpses.capacity() * std::mem::size_of::<Corpse>()
+        + snap.burrows.capacity() * std::mem::size_of::<Burrow>()
+        + snap.food.capacity() * std::mem::size_of::<FoodItem>()
+        + snap.decomposers.capacity() * std::mem::size_of::<Decomposer>()
+        + snap.novelty_archive.capacity() * std::mem::size_of::<[f32; 3]>()
+        + snap.soil.heap_bytes() + snap.scent.heap_bytes() + grid(&snap.speed_grid) + grid(&snap.elevation_grid)
+        + snap.bad_brain_hashes.as_ref().map_or(0, |v| v.iter().map(|h| std::mem::size_of::<String>() + h.capacity()).sum::<usize>())
+}
+
+// Decode a msgpack snapshot of any known format version, migrating older layouts forward
+pub(crate) fn decode_snapshot(bytes: &[u8]) -> Result<Snapshot, String> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Header {
+        #[serde(default = "legacy_snapshot_version")]
+        format_version: u32,
+        #[serde(default)]
+        config_schema_version: u32,
+    }
+    let header = rmp_serde::from_slice::<Header>(bytes).map_err(|e| e.to_string())?;
+    if header.format_version > SNAPSHOT_FORMAT_VERSION || header.config_schema_version > CONFIG_SCHEMA_VERSION {
+        return Err(format!(
+            "snapshot format {} / config schema {} is newer than supported ({} / {})",
+            header.format_version, header.config_schema_version, SNAPSHOT_FORMAT_VERSION, CONFIG_SCHEMA_VERSION
+        ));
+    }
+    if header.format_version == SNAPSHOT_FORMAT_VERSION && header.config_schema_version == CONFIG_SCHEMA_VERSION {
+        return rmp_serde::from_slice::<Snapshot>(bytes).map_err(|e| e.to_string());
+    }
+    let mut value = rmp_serde::from_slice::<serde_json::Value>(bytes).map_err(|e| e.to_string())?;
+    migrate_snapshot_value(&mut value, header.format_version)?;
+    serde_json::from_value::<Snapshot>(value).map_err(|e| e.to_string())
+}
+
+fn legacy_snapshot_version() -> u32 { 1 }
+
+// Step an untyped snapshot forward one format version at a time
+fn migrate_snapshot_value(value: &mut serde_json::Value, from: u32) -> Result<(), String> {
+    let obj = value.as_object_mut().ok_or("snapshot must be a map")?;
+    let mut version = from;
+    while version < SNAPSHOT_FORMAT_VERSION {
+        match version {
+            // v1 -> v2: version fields introduced; v1 configs are schema 1
+            1 => { obj.insert("configSchemaVersion".into(), 1.into()); }
+            // v2 -> v3: entity fields were snake_case
+            2 => {
+                for key in ["creatures", "plants", "corpses", "burrows", "elites"] {
+                    if let Some(v) = obj.get_mut(key) { rekey_entities(v, snake_to_camel); }
+                }
+            }
+            v => return Err(format!("no migration from snapshot format {}", v)),
+        }
+        version += 1;
+    }
+    obj.insert("formatVersion".into(), SNAPSHOT_FORMAT_VERSION.into());
+    // Config fields missing from older schemas take their defaults on deserialization
+    obj.insert("configSchemaVersion".into(), CONFIG_SCHEMA_VERSION.into());
+    Ok(())
+}
+
+impl World {
+    // Apply queued commands scheduled for the current tick (before it advances), in seq order
+    pub(crate) fn apply_due_commands(&mut self) {
+        let due = self.pending_commands.iter().take_while(|c| c.tick <= self.tick).count();
+        if due == 0 { return; }
+        let batch: Vec<ScheduledCommand> = self.pending_commands.drain(..due).collect();
+        // The ghost holds its own copy of these commands; detach it so spawns aren't forwarded twice
+        let ghost = self.ghost.take();
+        for sc in batch {
+            self.apply_command(&sc.command);
+            self.command_log.push(sc);
+        }
+        self.ghost = ghost;
+        let limit = self.config.command_log_limit as usize;
+        if self.command_log.len() > limit {
+            let excess = self.command_log.len() - limit;
+            self.command_log.drain(..excess);
+        }
+    }
+
+    fn apply_command(&mut self, cmd: &Command) {
+        match cmd {
+            Command::SpawnCreature { x, y } => self.spawn_creature(*x, *y),
+            Command::SpawnPlant { x, y, radius } => self.spawn_plant(*x, *y, *radius),
+            Command::SetBrainMode { mode } => {
+                if let Err(e) = self.set_brain_mode(mode) { log::warn!("setBrainMode command: {}", e); }
+            }
+            Command::FeedCreature { id, amount } => self.apply_feed_creature(id, *amount),
+            Command::PlaceFood { x, y, energy } => self.apply_place_food(*x, *y, *energy),
+            Command::SetConfig { overrides } => {
+                match merge_config(&self.config, overrides) {
+                    Ok(cfg) => self.apply_config(cfg),
+                    Err(e) => log::warn!("setConfig command: {}", e),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn enqueue_command(&mut self, tick: u64, seq: u64, command: Command) {
+        if let Some(g) = self.ghost.as_deref_mut() { g.enqueue_command(tick, seq, command.clone()); }
+        let sc = ScheduledCommand { tick, seq, command };
+        let pos = self.pending_commands.partition_point(|c| (c.tick, c.seq) <= (sc.tick, sc.seq));
+        self.pending_commands.insert(pos, sc);
+        self.next_command_seq = self.next_command_seq.max(seq + 1);
+    }
+
+    // FNV-1a over everything advance reads: the full snapshot (genes, brains, pregnancies, burrows,
+    // food, soil, scent, elites, runtime traces; bit-exact floats) plus host-supplied custom inputs.
+    // Matches across a snapshot save/load at the same tick.
+    pub(crate) fn compute_state_hash(&self) -> u64 {
+        let mut h = Fnv64::new();
+        h.write_serde(&self.snapshot_ref());
+        self.custom_inputs.for_each_sorted(|id, values| {
+            h.write_bytes(id.as_bytes());
+            for &v in values { h.write_f32(v); }
+        });
+        h.0
+    }
+
+    // Standalone world restored from a snapshot (used for ghost runs)
+    pub(crate) fn from_snapshot(snap: Snapshot) -> World {
+        let climate = Climate::new(snap.seed, (snap.width, snap.height), &snap.config);
+        let mut w = World {
+            width: snap.width, height: snap.height, tick: 0, creatures: Vec::new(), plants: Vec::new(), corpses: Vec::new(),
+            burrows: Vec::new(), next_burrow_id: 0, next_creature_id: 0, decomposers: Vec::new(), food: Vec::new(), time_scale: 1.0, paused: false, births_total: 0, deaths_total: 0,
+            attack_attempts_total: 0, kills_total: 0,
+            seed: snap.seed, snapshots: VecDeque::new(), dt_log: VecDeque::new(), ghost: None,
+            pending_commands: Vec::new(), command_log: Vec::new(), next_command_seq: 0, state_hashes: VecDeque::new(), hand_fed: Vec::new(),
+            brain_mode: snap.brain_mode, rng: RngLCG::new(snap.seed), bad_brain_hashes: HashSet::new(), config: snap.config.clone(),
+            perf: PerfCounters::default(), climate, external_outputs: HashMap::new(), events: VecDeque::new(), elites: Vec::new(), novelty_archive: Vec::new(),
+            reward_source: RewardSource::default(), transitions: HashMap::new(), custom_inputs: CustomInputs::default(),
+            scent: ScentField::default(), soil: SoilGrid::default(), highlights: HighlightDigest::default(), flows: EnergyFlows::default(), tally: StepTally::default(), front: None, hot: HotArrays::default(), trails: TrailLog::default(), watch: None, capacity: CapacityLog::default(), migration: MigrationLog::default(), density: DensityGrid::default(), energy_audit: None, speed_grid: None,
+        };
+        w.restore(snap);
+        w
+    }
+
+    // Capture the tick-0 snapshot lazily and log this tick's dt for deterministic re-stepping
+    pub(crate) fn record_history(&mut self, dt: f32) {
+        if self.config.snapshot_every_ticks == 0 || self.config.snapshot_capacity == 0 { return; }
+        if self.snapshots.is_empty() { self.push_snapshot(); }
+        self.dt_log.push_back(dt);
+    }
+
+    // Drop rewind/lockstep history that no longer matches the live state
+    pub(crate) fn clear_history(&mut self) {
+        self.snapshots.clear();
+        self.dt_log.clear();
+        self.ghost = None;
+        self.pending_commands.clear();
+        self.command_log.clear();
+        self.state_hashes.clear();
+    }
+
+    pub(crate) fn push_snapshot(&mut self) {
+        if self.config.snapshot_capacity == 0 { return; }
+        self.snapshots.push_back(self.snapshot());
+        while self.snapshots.len() > self.config.snapshot_capacity as usize {
+            self.snapshots.pop_front();
+        }
+        // Drop dt entries older than the oldest retained snapshot
+        if let Some(oldest) = self.snapshots.front() {
+            let keep = (self.tick - oldest.tick) as usize;
+            while self.dt_log.len() > keep { self.dt_log.pop_front(); }
+            // Commands before the oldest snapshot can no longer be replayed by a rewind
+            let stale = self.command_log.partition_point(|c| c.tick < oldest.tick);
+            self.command_log.drain(..stale);
+        }
+    }
+
+    // The state snapshot() would capture, borrowed rather than copied (hashing only)
+    fn snapshot_ref(&self) -> SnapshotRef<'_> {
+        let mut bad_brain_hashes: Vec<&String> = self.bad_brain_hashes.iter().collect();
+        bad_brain_hashes.sort();
+        SnapshotRef {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            config_schema_version: CONFIG_SCHEMA_VERSION,
+            tick: self.tick,
+            width: self.width,
+            height: self.height,
+            seed: self.seed,
+            rng_state: self.rng.state,
+            brain_mode: self.brain_mode,
+            config: &self.config,
+            creatures: &self.creatures,
+            runtime: &self.creatures,
+            plants: &self.plants,
+            corpses: &self.corpses,
+            burrows: &self.burrows,
+            next_burrow_id: self.next_burrow_id,
+            next_creature_id: Some(self.next_creature_id),
+            births_total: self.births_total,
+            deaths_total: self.deaths_total,
+            attack_attempts_total: self.attack_attempts_total,
+            kills_total: self.kills_total,
+            elites: &self.elites,
+            novelty_archive: &self.novelty_archive,
+            custom_input_count: self.custom_inputs.count,
+            scent: &self.scent,
+            decomposers: &self.decomposers,
+            soil: &self.soil,
+            food: &self.food,
+            speed_grid: &self.speed_grid,
+            elevation_grid: &self.climate.elevation_grid,
+            bad_brain_hashes: Some(bad_brain_hashes),
+        }
+    }
+
+    // Current simulation state (restorable via load_snapshot / World::from_snapshot)
+    pub fn snapshot(&self) -> Snapshot {
+        let mut creatures = self.creatures.clone();
+        // Activations are recomputed on the next tick; don't store them
+        for c in &mut creatures { c.brain.activations = None; }
+        let runtime = creatures.iter_mut().map(CreatureRuntime::take).collect();
+        let mut bad_brain_hashes: Vec<String> = self.bad_brain_hashes.iter().cloned().collect();
+        bad_brain_hashes.sort();
+        Snapshot {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            config_schema_version: CONFIG_SCHEMA_VERSION,
+            tick: self.tick,
+            width: self.width,
+            height: self.height,
+            seed: self.seed,
+            rng_state: self.rng.state,
+            brain_mode: self.brain_mode,
+            config: self.config.clone(),
+            creatures,
+            runtime,
+            plants: self.plants.clone(),
+            corpses: self.corpses.clone(),
+            burrows: self.burrows.clone(),
+            next_burrow_id: self.next_burrow_id,
+            next_creature_id: Some(self.next_creature_id),
+            births_total: self.births_total,
+            deaths_total: self.deaths_total,
+            attack_attempts_total: self.attack_attempts_total,
+            kills_total: self.kills_total,
+            elites: self.elites.clone(),
+            novelty_archive: self.novelty_archive.clone(),
+            custom_input_count: self.custom_inputs.count,
+            scent: self.scent.clone(),
+            decomposers: self.decomposers.clone(),
+            soil: self.soil.clone(),
+            food: self.food.clone(),
+            speed_grid: self.speed_grid.clone(),
+            elevation_grid: self.climate.elevation_grid.clone(),
+            bad_brain_hashes: Some(bad_brain_hashes),
+        }
+    }
+
+    pub(crate) fn restore(&mut self, snap: Snapshot) {
+        if snap.seed != self.seed || snap.width != self.width || snap.height != self.height {
+            self.climate = Climate::new(snap.seed, (snap.width, snap.height), &snap.config);
+        }
+        self.climate.params = NoiseParams::from_config(&snap.config);
+        self.tick = snap.tick;
+        self.width = snap.width;
+        self.height = snap.height;
+        self.seed = snap.seed;
+        self.rng.state = snap.rng_state;
+        self.brain_mode = snap.brain_mode;
+        self.config = snap.config;
+        self.next_creature_id = snap.next_creature_id.unwrap_or_else(|| {
+            // Old ids were "c<n>" (founders) or "c<tick>"/"c<tick>e<k>" (births); skip past all of them
+            let numeric = |id: &str| id.strip_prefix('c').and_then(|s| s.split('e').next()).and_then(|s| s.parse::<u64>().ok());
+            let ids = snap.creatures.iter().chain(&snap.elites)
+                .flat_map(|c| [Some(c.id.as_str()), Some(c.lineage.as_str()), c.parent_id.as_deref()])
+                .flatten();
+            ids.filter_map(numeric).max().map_or(0, |n| n + 1).max(snap.tick + 1)
+        });
+        self.creatures = snap.creatures;
+        for (c, r) in self.creatures.iter_mut().zip(snap.runtime) { r.put(c); }
+        self.plants = snap.plants;
+        self.corpses = snap.corpses;
+        self.burrows = snap.burrows;
+        self.next_burrow_id = snap.next_burrow_id;
+        self.births_total = snap.births_total;
+        self.deaths_total = snap.deaths_total;
+        self.attack_attempts_total = snap.attack_attempts_total;
+        self.kills_total = snap.kills_total;
+        self.elites = snap.elites;
+        self.novelty_archive = snap.novelty_archive;
+        self.scent = snap.scent;
+        self.decomposers = snap.decomposers;
+        self.soil = snap.soil;
+        self.food = snap.food;
+        self.speed_grid = snap.speed_grid;
+        self.climate.elevation_grid = snap.elevation_grid;
+        if let Some(bad) = snap.bad_brain_hashes { self.bad_brain_hashes = bad.into_iter().collect(); }
+        if snap.custom_input_count != self.custom_inputs.count { self.custom_inputs = CustomInputs::with_count(snap.custom_input_count); }
+        // Recorded transitions refer to the abandoned timeline
+        self.transitions.clear();
+        self.front = None;
+        self.trails = TrailLog::default();
+        self.watch = None;
+        self.capacity = CapacityLog::default();
+        self.migration = MigrationLog::default();
+        self.density = DensityGrid::default();
+        // Queued output and inputs of the abandoned timeline
+        self.events.clear();
+        self.highlights = HighlightDigest::default();
+        self.tally = StepTally::default();
+        self.hand_fed.clear();
+        self.external_outputs.clear();
+    }
+
+    // Restore the newest snapshot at or before tick - ticks, then re-step with the logged dts.
+    // Queued commands are replayed; direct edits made since that snapshot are not.
+    pub(crate) fn rewind_by(&mut self, ticks: u64) -> bool {
+        let target = self.tick.saturating_sub(ticks);
+        let Some(idx) = self.snapshots.iter().rposition(|s| s.tick <= target) else { return false; };
+        let oldest_tick = match self.snapshots.front() { Some(s) => s.tick, None => return false };
+        let snap = self.snapshots[idx].clone();
+        let dts: Vec<f32> = self.dt_log.iter()
+            .skip((snap.tick - oldest_tick) as usize)
+            .take((target - snap.tick) as usize)
+            .copied()
+            .collect();
+        // History after the target is discarded; it will be rebuilt as the world re-steps
+        if self.ghost.take().is_some() { log::warn!("rewind: ghost run stopped (it cannot follow the main world back in time)"); }
+        self.snapshots.truncate(idx + 1);
+        self.dt_log.truncate((snap.tick - oldest_tick) as usize);
+        self.state_hashes.retain(|(t, _)| *t <= snap.tick);
+        // Commands applied at or after the snapshot tick go back into the queue and replay on the way forward
+        let replay_from = self.command_log.partition_point(|c| c.tick < snap.tick);
+        let replay: Vec<ScheduledCommand> = self.command_log.drain(replay_from..).collect();
+        for sc in replay { self.enqueue_command(sc.tick, sc.seq, sc.command); }
+        self.restore(snap);
+        for dt in dts { self.advance(dt); }
+        // The host already saw the re-stepped ticks' events on the original timeline
+        self.events.clear();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::creature::Diet;
+    use serde_json::json;
+
+    // A format-1 snapshot as the first msgpack release wrote it: no version fields, snake_case
+    // entity keys
+    fn v1_snapshot() -> serde_json::Value {
+        json!({
+            "tick": 42, "width": 600.0, "height": 400.0, "seed": 9, "rngState": 12345, "brainMode": "OG",
+            "config": { "maxLife": 3000.0 },
+            "creatures": [{
+                "id": "c0", "x": 10.0, "y": 20.0, "vx": 0.5, "vy": -0.5, "radius": 5.0, "health": 90.0,
+                "energy": 70.0, "stamina": 60.0, "max_stamina": 80.0, "thirst": 50.0, "lifespan": 41,
+                "diet": "Carnivore", "brain": { "layerSizes": [1, 1], "weights": [[0.5]], "biases": [[0.0]] },
+                "genes": { "swimSkill": 0.7, "canFly": true }, "altitude": 0.0, "burrow_id": null,
+                "is_pregnant": true, "gestation_timer": 1.5, "offspring_count": 1,
+                "actions_mask": 0, "feelings_mask": 0, "stagnant_ticks": 3
+            }],
+            "plants": [{ "x": 1.0, "y": 2.0, "radius": 3.0 }],
+            "corpses": [], "burrows": [], "nextBurrowId": 0, "birthsTotal": 4, "deathsTotal": 2
+        })
+    }
+
+    fn encode(value: &serde_json::Value) -> Vec<u8> { rmp_serde::to_vec_named(value).unwrap() }
+
+    fn assert_migrated(snap: &Snapshot) {
+        assert_eq!(snap.format_version, SNAPSHOT_FORMAT_VERSION);
+        assert_eq!(snap.config_schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(snap.tick, 42);
+        assert_eq!(snap.config.max_life, 3000.0);
+        let c = &snap.creatures[0];
+        assert_eq!(c.max_stamina, 80.0);
+        assert!(c.is_pregnant);
+        assert_eq!(c.gestation_timer, 1.5);
+        assert_eq!(c.stagnant_ticks, 3);
+        assert!(c.diet == Diet::Carnivore && c.genes.can_fly);
+        assert_eq!(snap.plants.len(), 1);
+        assert_eq!(snap.births_total, 4);
+    }
+
+    #[test]
+    fn decodes_v1_snapshot() {
+        assert_migrated(&decode_snapshot(&encode(&v1_snapshot())).unwrap());
+    }
+
+    #[test]
+    fn decodes_v2_snapshot() {
+        let mut v2 = v1_snapshot();
+        v2["formatVersion"] = 2.into();
+        v2["configSchemaVersion"] = 1.into();
+        assert_migrated(&decode_snapshot(&encode(&v2)).unwrap());
+    }
+
+    #[test]
+    fn rejects_future_and_unknown_versions() {
+        for (format, schema) in [(SNAPSHOT_FORMAT_VERSION + 1, CONFIG_SCHEMA_VERSION), (SNAPSHOT_FORMAT_VERSION, CONFIG_SCHEMA_VERSION + 1), (0, 1)] {
+            let mut snap = v1_snapshot();
+            snap["formatVersion"] = format.into();
+            snap["configSchemaVersion"] = schema.into();
+            assert!(decode_snapshot(&encode(&snap)).is_err(), "format {} / schema {} accepted", format, schema);
+        }
+    }
+
+    #[test]
+    fn rejects_unreadable_payloads() {
+        let mut missing = v1_snapshot();
+        missing.as_object_mut().unwrap().remove("creatures");
+        for bytes in [vec![0xc1], Vec::new(), encode(&json!([1, 2, 3])), encode(&json!("snapshot")), encode(&missing)] {
+            assert!(decode_snapshot(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn snapshot_ref_encodes_like_snapshot() {
+        let mut w = World::new(600.0, 400.0, 3);
+        w.config.novelty.enabled = true;
+        w.config.spatial_memory.enabled = true;
+        let watched = w.creatures[0].id.clone();
+        w.watch_creature(Some(watched));
+        for _ in 0..120 { w.advance(1.0 / 60.0); }
+        assert!(w.creatures.iter().any(|c| c.brain.activations.is_some()));
+        let borrowed = rmp_serde::to_vec(&w.snapshot_ref()).unwrap();
+        assert_eq!(borrowed, rmp_serde::to_vec(&w.snapshot()).unwrap());
+    }
+
+    // A fast time scale runs out of sub-steps and drops time instead of stretching ticks
+    #[test]
+    fn fast_time_scale_keeps_ticks_bounded() {
+        let mut w = World::new(600.0, 400.0, 5);
+        // One snapshot at tick 0 keeps the dt of every later tick in dt_log
+        w.config.snapshot_every_ticks = 1000;
+        w.config.snapshot_capacity = 4;
+        w.set_time_scale(100.0);
+        let bound = w.config.max_substep_dt_sec;
+        for _ in 0..3 { w.step(1.0); }
+        assert_eq!(w.tick, 3 * w.config.max_substeps as u64);
+        assert_eq!(w.dt_log.len() as u64, w.tick);
+        assert!(w.dt_log.iter().all(|&dt| dt > 0.0 && dt <= bound), "tick longer than {}s: {:?}", bound, w.dt_log);
+    }
+
+    // With no overrides, host interventions reach both worlds and the ghost never diverges
+    #[test]
+    fn empty_override_ghost_tracks_main_world() {
+        let mut w = World::new(600.0, 400.0, 11);
+        w.set_custom_inputs("*", &[0.25, -0.5]).unwrap();
+        w.queue_command(30, Command::SpawnCreature { x: 100.0, y: 150.0 });
+        w.start_ghost(&json!({})).unwrap();
+        let ghost_hash = |w: &World| w.ghost.as_ref().unwrap().compute_state_hash();
+        assert_eq!(ghost_hash(&w), w.compute_state_hash());
+        for t in 0..120u32 {
+            match t {
+                10 => { w.spawn_creature(300.0, 200.0); }
+                20 => { w.spawn_plant(50.0, 60.0, None); }
+                40 => { w.set_custom_inputs("*", &[1.0]).unwrap(); }
+                50 => { w.feed_creature(&w.creatures[0].id.clone(), 20.0); }
+                60 => { w.place_food(400.0, 100.0, 15.0); }
+                70 => {
+                    let ids: Vec<String> = w.creatures.iter().map(|c| c.id.clone()).collect();
+                    let width = w.creatures[0].brain.layer_sizes.last().copied().unwrap_or(0) as usize;
+                    w.set_brain_outputs(&ids, &vec![0.5; width * ids.len()]);
+                }
+                _ => {}
+            }
+            w.advance(1.0 / 60.0);
+            assert_eq!(ghost_hash(&w), w.compute_state_hash(), "ghost diverged at tick {}", w.tick);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Structure-of-arrays copies of the per-creature data a renderer reads every frame, refreshed at the
+// end of each step() in creatures() order. The buffers only reallocate when the population outgrows
+// them (capacity doubles), so hosts can keep views into them across frames; `version` changes
+// whenever a buffer moves.
+
+use crate::World;
+
+// x, y, radius per creature
+pub const POSITION_STRIDE: usize = 3;
+// energy, health, stamina, thirst per creature
+pub const VITALS_STRIDE: usize = 4;
+
+#[derive(Default)]
+pub(crate) struct HotArrays {
+    pub(crate) positions: Vec<f32>,
+    pub(crate) vitals: Vec<f32>,
+    pub(crate) count: usize,
+    pub(crate) version: u32,
+}
+
+impl HotArrays {
+    fn slots(&self) -> usize { self.positions.len() / POSITION_STRIDE }
+
+    // Grow to hold `n` creatures (never shrinks); bumps `version` if either buffer moved
+    fn reserve(&mut self, n: usize) {
+        if n <= self.slots() { return; }
+        let slots = n.next_power_of_two().max(64);
+        let before = (self.positions.as_ptr(), self.vitals.as_ptr());
+        self.positions.resize(slots * POSITION_STRIDE, 0.0);
+        self.vitals.resize(slots * VITALS_STRIDE, 0.0);
+        if (self.positions.as_ptr(), self.vitals.as_ptr()) != before { self.version = self.version.wrapping_add(1); }
+    }
+}
+
+impl World {
+    pub(crate) fn refresh_hot_arrays(&mut self) {
+        let mut hot = std::mem::take(&mut self.hot);
+        let creatures = self.creatures();
+        hot.reserve(creatures.len());
+        for (i, c) in creatures.iter().enumerate() {
+            hot.positions[i * POSITION_STRIDE..(i + 1) * POSITION_STRIDE].copy_from_slice(&[c.x, c.y, c.radius]);
+            hot.vitals[i * VITALS_STRIDE..(i + 1) * VITALS_STRIDE].copy_from_slice(&[c.energy, c.health, c.stamina, c.thirst]);
+        }
+        hot.count = creatures.len();
+        self.hot = hot;
+    }
+
+    // Whole position buffer (capacity slots; the first hot_count() are live)
+    pub fn hot_positions(&self) -> &[f32] { &self.hot.positions }
+
+    pub fn hot_vitals(&self) -> &[f32] { &self.hot.vitals }
+
+    // Creatures written by the last step()
+    pub fn hot_count(&self) -> usize { self.hot.count }
+
+    // Changes whenever hot_positions/hot_vitals move (rebuild any views then)
+    pub fn hot_version(&self) -> u32 { self.hot.version }
+}
@@ -0,0 +1,47 @@
+// Inbreeding: relatedness of a parent and its mate from lineage data, and the penalty paid by
+// newborns of closely related parents (weaker vitals, higher disease susceptibility)
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Creature;
+
+// Inbreeding settings (serialized as the `inbreeding` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct InbreedingConfig {
+    pub enabled: bool,
+    pub lineage_relatedness: f32,    // relatedness of same-lineage pairs that are not parent/child or siblings
+    pub threshold: f32,              // penalties apply from this relatedness up
+    pub vitals_penalty: f32,         // newborn energy and health shrink by this fraction per unit relatedness
+    pub disease_susceptibility: f32, // disease drain multiplier is 1 + this * inbreeding
+}
+
+impl Default for InbreedingConfig {
+    fn default() -> Self {
+        Self { enabled: false, lineage_relatedness: 0.125, threshold: 0.25, vitals_penalty: 0.5, disease_susceptibility: 2.0 }
+    }
+}
+
+impl InbreedingConfig {
+    // Newborn vitals multiplier for parents of relatedness `r`
+    pub(crate) fn vitals_mult(&self, r: f32) -> f32 {
+        if !self.enabled || r < self.threshold { return 1.0; }
+        (1.0 - self.vitals_penalty * r).clamp(0.0, 1.0)
+    }
+
+    // Disease drain multiplier for a creature with inbreeding coefficient `f`
+    pub(crate) fn disease_mult(&self, f: f32) -> f32 {
+        if !self.enabled || f < self.threshold { return 1.0; }
+        1.0 + self.disease_susceptibility.max(0.0) * f
+    }
+}
+
+// Relatedness of two creatures: 0.5 for parent/child and siblings, `lineage_relatedness` for other
+// descendants of the same founder, 0 across lineages
+pub(crate) fn relatedness(a: &Creature, b: &Creature, cfg: &InbreedingConfig) -> f32 {
+    if a.lineage.is_empty() || a.lineage != b.lineage { return 0.0; }
+    let parent_child = a.parent_id.as_deref() == Some(b.id.as_str()) || b.parent_id.as_deref() == Some(a.id.as_str());
+    let siblings = a.parent_id.is_some() && a.parent_id == b.parent_id;
+    if parent_child || siblings { 0.5 } else { cfg.lineage_relatedness.clamp(0.0, 0.5) }
+}
@@ -0,0 +1,79 @@
+// Post-step invariant checks (cargo feature `invariant-checks`): non-finite numbers in positions,
+// velocities, vitals, and brain parameters, entities outside the world, and negative radii are
+// reported as events instead of silently corrupting later ticks
+
+use serde::{Deserialize, Serialize};
+
+use crate::events::{push_event, WorldEvent};
+use crate::World;
+
+// Violations reported per tick at most; the rest are only counted in the log
+const MAX_VIOLATIONS_PER_TICK: usize = 16;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum EntityKind { Creature, Plant, Corpse }
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum InvariantReason { NonFinite, OutOfBounds, NegativeRadius }
+
+// One broken invariant; `id` is the creature id, or the plant/corpse index
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    pub kind: EntityKind,
+    pub id: String,
+    pub field: String,
+    pub reason: InvariantReason,
+    pub value: f32,
+}
+
+// Common checks for a positioned, sized entity plus its extra named values
+fn check_entity(out: &mut Vec<Violation>, kind: EntityKind, id: &dyn Fn() -> String, (x, y, radius): (f32, f32, f32), (w, h): (f32, f32), values: &[(&str, f32)]) {
+    let mut report = |field: &str, reason, value| out.push(Violation { kind, id: id(), field: field.to_string(), reason, value });
+    for &(field, v) in [("x", x), ("y", y), ("radius", radius)].iter().chain(values) {
+        if !v.is_finite() { report(field, InvariantReason::NonFinite, v); }
+    }
+    if x.is_finite() && !(0.0..=w).contains(&x) { report("x", InvariantReason::OutOfBounds, x); }
+    if y.is_finite() && !(0.0..=h).contains(&y) { report("y", InvariantReason::OutOfBounds, y); }
+    if radius < 0.0 { report("radius", InvariantReason::NegativeRadius, radius); }
+}
+
+impl World {
+    // Every broken invariant in the current state
+    pub fn find_violations(&self) -> Vec<Violation> {
+        let mut out = Vec::new();
+        let bounds = (self.width, self.height);
+        for c in &self.creatures {
+            let id = || c.id.clone();
+            let vitals = [("vx", c.vx), ("vy", c.vy), ("energy", c.energy), ("health", c.health), ("stamina", c.stamina), ("thirst", c.thirst)];
+            check_entity(&mut out, EntityKind::Creature, &id, (c.x, c.y, c.radius), bounds, &vitals);
+            let mut params = c.brain.weights.iter().flatten().chain(c.brain.biases.iter().flatten()).flatten();
+            if let Some(&v) = params.find(|v| !v.is_finite()) {
+                out.push(Violation { kind: EntityKind::Creature, id: id(), field: "brain".into(), reason: InvariantReason::NonFinite, value: v });
+            }
+        }
+        for (i, p) in self.plants.iter().enumerate() {
+            check_entity(&mut out, EntityKind::Plant, &|| i.to_string(), (p.x, p.y, p.radius), bounds, &[("biomass", p.biomass)]);
+        }
+        for (i, co) in self.corpses.iter().enumerate() {
+            let values = [("energyRemaining", co.energy_remaining), ("decayTimer", co.decay_timer)];
+            check_entity(&mut out, EntityKind::Corpse, &|| i.to_string(), (co.x, co.y, co.radius), bounds, &values);
+        }
+        out
+    }
+
+    // Post-step pass: queue an invariantViolation event per problem (capped) and log the total
+    pub(crate) fn check_invariants(&mut self) {
+        let found = self.find_violations();
+        if found.is_empty() { return; }
+        log::error!("tick {}: {} invariant violation(s), first: {:?}", self.tick, found.len(), found[0]);
+        for v in found.into_iter().take(MAX_VIOLATIONS_PER_TICK) {
+            push_event(&mut self.events, WorldEvent::InvariantViolation { tick: self.tick, violation: v });
+        }
+    }
+}
@@ -0,0 +1,1630 @@
+// Native simulation core for EvoSim: world state, creatures, brains, and environment.
+// Bindings (wasm, CLI, ...) wrap `World` and translate its plain Rust types.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::{Serialize, Deserialize};
+
+mod action_log;
+mod aging;
+mod audit;
+mod balancer;
+mod blooms;
+mod brain;
+mod capacity;
+mod carrying;
+mod casing;
+mod config;
+mod creature;
+mod currents;
+mod decomposers;
+mod density;
+mod diet;
+mod disease;
+mod ensemble;
+mod environment;
+mod events;
+mod feeding;
+mod food;
+mod forecast;
+mod evolution;
+mod flags;
+mod frames;
+mod growth;
+mod harvest;
+mod highlights;
+mod hot;
+mod history;
+mod inbreeding;
+mod invariants;
+mod memory;
+mod migration;
+mod novelty;
+mod perf;
+mod plasticity;
+mod pregnancy;
+mod rl;
+mod rng;
+mod scenarios;
+mod scent;
+mod seasons;
+mod sharing;
+mod sensing;
+mod sex;
+mod summary;
+mod torpor;
+mod trails;
+mod until;
+mod watch;
+
+pub use action_log::{ActionLog, ActionLogEntry, CreatureAction};
+pub use aging::SenescenceConfig;
+pub use audit::EnergyAudit;
+pub use balancer::AutoBalanceConfig;
+pub use blooms::{BloomConfig, BloomSpec, UpcomingBloom};
+pub use brain::{Brain, BrainBatch, BrainError, BrainMode, DegenerateAction, DegenerateReason, PruneMode};
+pub use capacity::{CapacitySample, CarryingCapacity, CarryingCapacityConfig, RegionCapacity};
+pub use carrying::CorpseCarryConfig;
+pub use casing::EntityExport;
+pub use config::{Config, CrossoverConfig, CrossoverMode, FitnessWeights, MutationConfig, SenseNoise, SensorNoiseConfig};
+pub use currents::WaterCurrentConfig;
+pub use decomposers::{Decomposer, DecomposerConfig, SoilGrid};
+pub use density::DensityConfig;
+pub use diet::DietSpecializationConfig;
+pub use disease::{DiseaseConfig, InfectionGrid};
+pub use creature::{Bite, Burrow, Corpse, Creature, DeathCause, Diet, EnergyLedger, Genes, LedgerCat, Plant, LEDGER_CATEGORIES, LEDGER_LEN};
+pub use ensemble::{ConfigComparison, EnsembleReport, EnsembleRun, PairedMetric, SampleSummary};
+pub use environment::{Biome, BiomeStats, EnvSample};
+pub use events::WorldEvent;
+pub use food::{FoodItem, FoodItemConfig};
+pub use forecast::{Forecast, ForecastFrame};
+pub use growth::GrowthConfig;
+pub use evolution::FitnessMetrics;
+pub use flags::{ActionFlag, FeelingFlag, FlagInfo, FlagRegistry};
+pub use novelty::{BehaviorDescriptor, NoveltyConfig};
+pub use memory::{MemorySlot, SpatialMemory, SpatialMemoryConfig};
+pub use migration::{MigrationConfig, MigrationState, QuadrantMovement, QUADRANTS};
+pub use inbreeding::InbreedingConfig;
+pub use invariants::{EntityKind, InvariantReason, Violation};
+pub use highlights::Highlight;
+pub use hot::{POSITION_STRIDE, VITALS_STRIDE};
+pub use history::{Command, CreatureRuntime, ScheduledCommand, Snapshot, CONFIG_SCHEMA_VERSION, SNAPSHOT_FORMAT_VERSION};
+pub use perf::{now_ms, set_clock};
+pub use plasticity::{PlasticityConfig, PlasticityRule};
+pub use pregnancy::PregnancyConfig;
+pub use rl::{RewardSource, Transition};
+pub use scenarios::ScenarioInfo;
+pub use scent::ScentConfig;
+pub use seasons::{Season, SeasonConfig};
+pub use sharing::EnergySharingConfig;
+pub use sensing::{BrainIoSchema, ChannelKind, IoChannel};
+pub use sex::{Sex, SexConfig};
+pub use summary::{DeathCounts, PhaseTimings, StepSummary};
+pub use torpor::TorporConfig;
+pub use trails::{Trail, TrailConfig};
+pub use until::{parse_stop_conditions, StepUntilOutcome, StopCondition};
+pub use watch::{WatchRecord, WatchedCreature};
+
+use audit::EnergyFlows;
+use brain::{brain_forward, brain_hash, brain_heap_bytes, brain_param_count, build_inputs, handle_degenerate, init_brain_avoiding_bad, prey_in_reach, prune_brain};
+use config::{check_config_keys, merge_config};
+use creature::creature_vec_bytes;
+use diet::specialization;
+use environment::{birth_spot, can_drink_at, ground_speed, in_water_at, sample_noise01, sample_rain01, sample_wind_speed, Climate, NoiseParams, HostGrid};
+use events::push_event;
+use evolution::{crossover, fitness, inherit_brain, mutate_weights, nearest_mate};
+use food::food_target;
+use harvest::harvest_target;
+use highlights::HighlightDigest;
+use history::{decode_snapshot, snapshot_heap_bytes};
+use inbreeding::relatedness;
+use perf::{lap, PerfCounters};
+use plasticity::{apply_plasticity, heritable_brain};
+use rl::{RewardBaseline, TransitionRecorder};
+use rng::RngLCG;
+use scent::ScentField;
+use sharing::pick_recipient;
+use sensing::{CustomInputs, SenseEnv};
+use frames::FrontBuffer;
+use hot::HotArrays;
+use capacity::CapacityLog;
+use migration::MigrationLog;
+use trails::TrailLog;
+use watch::Watch;
+use density::DensityGrid;
+use summary::StepTally;
+
+pub struct World {
+    width: f32,
+    height: f32,
+    tick: u64,
+    creatures: Vec<Creature>,
+    plants: Vec<Plant>,
+    corpses: Vec<Corpse>,
+    burrows: Vec<Burrow>,
+    next_burrow_id: u32,
+    // Counter behind "c<n>" creature ids (see creature::take_creature_id)
+    next_creature_id: u64,
+    // Corpse and litter eaters (see Config::decomposers)
+    decomposers: Vec<Decomposer>,
+    // Loose food: fruit, drops, manna (see Config::food_items)
+    food: Vec<FoodItem>,
+    // Speed control: step(real_dt) advances by real_dt * time_scale unless paused
+    time_scale: f32,
+    paused: bool,
+    // Lifetime population counters (reported in stats)
+    births_total: u64,
+    deaths_total: u64,
+    // Predation analytics: chases started and kills (attacked creatures that died, plus record_kill)
+    attack_attempts_total: u64,
+    kills_total: u64,
+    // Rewind support: periodic snapshots plus the dt of every tick since the oldest one
+    seed: u32,
+    snapshots: VecDeque<Snapshot>,
+    dt_log: VecDeque<f32>,
+    // Optional counterfactual copy stepped in lockstep with a modified config
+    ghost: Option<Box<World>>,
+    // Lockstep: commands waiting for their tick (sorted by tick, seq), applied log, recent state hashes
+    pending_commands: Vec<ScheduledCommand>,
+    command_log: Vec<ScheduledCommand>,
+    next_command_seq: u64,
+    state_hashes: VecDeque<(u64, u64)>,
+    // Energy given by this tick's feedCreature commands, booked to the ledgers after they reset
+    hand_fed: Vec<(String, f32)>,
+    brain_mode: BrainMode,
+    rng: RngLCG,
+    bad_brain_hashes: HashSet<String>,
+    config: Config,
+    perf: PerfCounters,
+    climate: Climate,
+    // Externally evaluated brain outputs by creature id, consumed by the next tick
+    external_outputs: HashMap<String, Vec<f32>>,
+    // Undrained events (see drain_events)
+    events: VecDeque<WorldEvent>,
+    // Elite archive, fittest first (see Config::elitism)
+    elites: Vec<Creature>,
+    // Behaviour descriptors of past novel creatures (see Config::novelty)
+    novelty_archive: Vec<[f32; 3]>,
+    // Reinforcement-learning transition recording for selected creatures (see collect_transitions)
+    reward_source: RewardSource,
+    transitions: HashMap<String, TransitionRecorder>,
+    // Host-supplied extra input channels (see set_custom_inputs)
+    custom_inputs: CustomInputs,
+    // Diffusing food smell (see Config::scent); empty while disabled
+    scent: ScentField,
+    // Litter and fertility left by decomposition (see Config::decomposers); empty while disabled
+    soil: SoilGrid,
+    // Notable-moment digest (see highlights)
+    highlights: HighlightDigest,
+    // Energy bookkeeping for the current tick and the latest audit report (Config::energy_audit)
+    flows: EnergyFlows,
+    // Births, deaths, attacks, and grazing during the last tick, or the last step() call's ticks
+    tally: StepTally,
+    // Last completed frame served by the export getters (Config::double_buffered_exports)
+    front: Option<Box<FrontBuffer>>,
+    // Per-creature SoA buffers for zero-copy host views, refreshed by step()
+    hot: HotArrays,
+    // Recent positions per creature (Config::trails)
+    trails: TrailLog,
+    // Creature followed with per-tick telemetry (World::watch_creature)
+    watch: Option<Watch>,
+    // Measured herbivore upkeep and capacity samples (Config::carrying_capacity)
+    capacity: CapacityLog,
+    // Quadrant movement per season (Config::migration)
+    migration: MigrationLog,
+    // Decaying per-diet occupancy heatmap (Config::density)
+    density: DensityGrid,
+    energy_audit: Option<EnergyAudit>,
+    // Host-uploaded terrain speed multipliers (see set_speed_grid)
+    speed_grid: Option<HostGrid>,
+}
+
+impl World {
+    pub fn new(width: f32, height: f32, seed: u32) -> World {
+        // Deterministic LCG RNG (parity with JS RNG)
+        let mut rng = RngLCG::new(seed);
+        let config = Config::default();
+
+        let mut creatures = Vec::new();
+        let mut next_creature_id = 0;
+        let bad_brains: HashSet<String> = HashSet::new();
+        for _ in 0..50 {
+            let x = rng.uniform(0.0, width);
+            let y = rng.uniform(0.0, height);
+            let vx = rng.uniform(-1.0, 1.0) * 2.0;
+            let vy = rng.uniform(-1.0, 1.0) * 2.0;
+            let diet = if rng.next_f32_01() > 0.8 { Diet::Carnivore } else { Diet::Herbivore };
+            let brain = init_brain_avoiding_bad(vec![14, 8, 8], &mut rng, &bad_brains, config.legacy_brain_hash);
+            let genes = Genes::founder(seed, next_creature_id, &config);
+            let id = creature::take_creature_id(&mut next_creature_id);
+            creatures.push(Creature { vx, vy, genes, ..Creature::new(id, x, y, diet, brain) });
+        }
+        let mut plants = Vec::new();
+        for _ in 0..150 {
+            plants.push(Plant{ x: rng.uniform(0.0, width), y: rng.uniform(0.0, height), radius: 3.0, biomass: config.plant_max_biomass, bloom_until: None });
+        }
+        let climate = Climate::new(seed, (width, height), &config);
+        World {
+            width, height, tick: 0, creatures, plants, corpses: Vec::new(), burrows: Vec::new(), next_burrow_id: 0, next_creature_id, decomposers: Vec::new(), food: Vec::new(), time_scale: 1.0, paused: false,
+            births_total: 0, deaths_total: 0, attack_attempts_total: 0, kills_total: 0, seed, snapshots: VecDeque::new(), dt_log: VecDeque::new(), ghost: None,
+            pending_commands: Vec::new(), command_log: Vec::new(), next_command_seq: 0, state_hashes: VecDeque::new(), hand_fed: Vec::new(),
+            brain_mode: BrainMode::OG, rng, bad_brain_hashes: bad_brains, config, perf: PerfCounters::default(), climate,
+            external_outputs: HashMap::new(), events: VecDeque::new(), elites: Vec::new(), novelty_archive: Vec::new(),
+            reward_source: RewardSource::default(), transitions: HashMap::new(), custom_inputs: CustomInputs::default(),
+            scent: ScentField::default(), soil: SoilGrid::default(), highlights: HighlightDigest::default(), flows: EnergyFlows::default(), tally: StepTally::default(), front: None, hot: HotArrays::default(), trails: TrailLog::default(), watch: None, capacity: CapacityLog::default(), migration: MigrationLog::default(), density: DensityGrid::default(), energy_audit: None, speed_grid: None,
+        }
+    }
+
+    // Advance by real elapsed seconds scaled by the time scale; no-op while paused
+    pub fn step(&mut self, real_dt: f32) {
+        if self.paused { return; }
+        let real_dt = if real_dt.is_finite() { real_dt.clamp(0.0, self.config.max_frame_dt_sec.max(0.0)) } else { 0.0 };
+        let dt = real_dt * self.time_scale;
+        let max_sub = self.config.max_substep_dt_sec;
+        let (ticks, tick_dt) = if max_sub > 0.0 {
+            let wanted = (dt / max_sub).ceil().max(1.0);
+            let budget = self.config.max_substeps.max(1) as f32;
+            // Past the tick budget the remaining time is dropped rather than lengthening every tick
+            if wanted > budget { log::debug!("step: dropped {:.3}s of simulated time (tick budget {})", dt - budget * max_sub, budget); }
+            (wanted.min(budget) as u32, (dt / wanted).min(max_sub))
+        } else {
+            (1, dt)
+        };
+        // One tally for the whole frame (step_with_summary)
+        let mut frame = StepTally::default();
+        for _ in 0..ticks {
+            self.advance(tick_dt);
+            frame.add(&self.tally);
+        }
+        self.tally = frame;
+        self.publish_frame();
+        self.refresh_hot_arrays();
+    }
+
+    pub fn set_time_scale(&mut self, scale: f32) {
+        let s = if scale.is_finite() { scale.clamp(0.0, 100.0) } else { 1.0 };
+        if s != scale { log::warn!("set_time_scale: {} clamped to {}", scale, s); }
+        self.time_scale = s;
+    }
+
+    pub fn time_scale(&self) -> f32 { self.time_scale }
+
+    pub fn set_paused(&mut self, paused: bool) { self.paused = paused; }
+
+    pub fn is_paused(&self) -> bool { self.paused }
+
+    // Entity getters serve the last completed frame when Config::double_buffered_exports is set
+    pub fn creatures(&self) -> &[Creature] { self.front.as_ref().map_or(&self.creatures, |f| &f.creatures) }
+
+    pub fn plants(&self) -> &[Plant] { self.front.as_ref().map_or(&self.plants, |f| &f.plants) }
+
+    // Elite archive, fittest first (living elites included; empty unless config.elitism.topK > 0)
+    pub fn elites(&self) -> &[Creature] { self.front.as_ref().map_or(&self.elites, |f| &f.elites) }
+
+    pub fn corpses(&self) -> &[Corpse] { self.front.as_ref().map_or(&self.corpses, |f| &f.corpses) }
+
+    pub fn burrows(&self) -> &[Burrow] { self.front.as_ref().map_or(&self.burrows, |f| &f.burrows) }
+
+    // Bindings time their own entity exports and report them here (perf serializationMs)
+    pub fn record_serialization_ms(&self, ms: f64) {
+        self.perf.serialization_ms.set(self.perf.serialization_ms.get() + ms);
+    }
+
+    // MessagePack entity dumps (same field names as the JSON exports); Err if encoding fails
+    pub fn creatures_msgpack(&self) -> Result<Vec<u8>, String> { self.timed_msgpack(&self.export_entities(self.creatures())) }
+
+    pub fn plants_msgpack(&self) -> Result<Vec<u8>, String> { self.timed_msgpack(&self.export_entities(self.plants())) }
+
+    pub fn corpses_msgpack(&self) -> Result<Vec<u8>, String> { self.timed_msgpack(&self.export_entities(self.corpses())) }
+
+    // Full simulation state as MessagePack, restorable with load_snapshot_msgpack
+    pub fn snapshot_msgpack(&self) -> Result<Vec<u8>, String> { self.timed_msgpack(&self.snapshot()) }
+
+    // Replace the world with a snapshot_msgpack payload (older formats are migrated);
+    // clears rewind/lockstep history. Err (world unchanged) describes why the payload was rejected.
+    pub fn load_snapshot_msgpack(&mut self, bytes: &[u8]) -> Result<(), String> {
+        let snap = decode_snapshot(bytes).map_err(|e| format!("invalid snapshot: {}", e))?;
+        self.load_snapshot(snap);
+        Ok(())
+    }
+
+    // Replace the world with `snap`; clears rewind/lockstep history. Brain parameters are
+    // sanitized like imported brains.
+    pub fn load_snapshot(&mut self, mut snap: Snapshot) {
+        let fixed: u32 = snap.creatures.iter_mut().map(|c| c.brain.sanitize(snap.config.brain_weight_limit)).sum();
+        if fixed > 0 { log::warn!("load_snapshot: sanitized {} brain parameter(s)", fixed); }
+        self.clear_history();
+        self.restore(snap);
+    }
+
+    // Population/vitals summary for the current tick
+    pub fn stats(&self) -> WorldStats { self.stats_summary() }
+
+    // Advance `ticks` fixed 1/60s ticks (ignores pause/time scale).
+    // Returns a stats summary every `report_every` ticks plus one for the final tick.
+    pub fn run_headless(&mut self, ticks: u32, report_every: u32) -> Vec<WorldStats> {
+        self.run_headless_reports(ticks, report_every)
+    }
+
+    // Jump back `ticks` ticks via the snapshot ring; returns false if no snapshot is old enough
+    pub fn rewind(&mut self, ticks: u64) -> bool {
+        let ok = self.rewind_by(ticks);
+        if !ok { log::warn!("rewind: no snapshot covers {} ticks back from tick {}", ticks, self.tick); }
+        ok
+    }
+
+    // Fork a ghost world from the current state with `overrides` (partial camelCase config) applied
+    // on top of the current config. It then steps in lockstep with this world, and queued commands,
+    // spawns, custom inputs, and brain outputs reach it too, so only the overrides set it apart.
+    pub fn start_ghost(&mut self, overrides: &serde_json::Value) -> Result<(), String> {
+        let mut cfg = merge_config(&self.config, overrides)?;
+        // The ghost never rewinds, so it keeps no history of its own
+        cfg.snapshot_capacity = 0;
+        let mut ghost = World::from_snapshot(self.snapshot());
+        ghost.apply_config(cfg);
+        // Host-side state the snapshot leaves out; without it the ghost's brain draws and inputs
+        // would drift from the main world's even with no overrides
+        ghost.bad_brain_hashes = self.bad_brain_hashes.clone();
+        ghost.custom_inputs = self.custom_inputs.clone();
+        ghost.external_outputs = self.external_outputs.clone();
+        for sc in &self.pending_commands { ghost.enqueue_command(sc.tick, sc.seq, sc.command.clone()); }
+        self.ghost = Some(Box::new(ghost));
+        Ok(())
+    }
+
+    pub fn stop_ghost(&mut self) { self.ghost = None; }
+
+    // Ghost minus main differences plus both stat summaries; None when no ghost is running
+    pub fn ghost_divergence(&self) -> Option<GhostDivergence> {
+        let g = self.ghost.as_ref()?;
+        let (m, gs) = (self.stats(), g.stats());
+        let d = |a: u32, b: u32| b as i64 - a as i64;
+        Some(GhostDivergence {
+            tick: self.tick,
+            creatures_delta: d(m.creatures, gs.creatures),
+            herbivores_delta: d(m.herbivores, gs.herbivores),
+            carnivores_delta: d(m.carnivores, gs.carnivores),
+            plants_delta: d(m.plants, gs.plants),
+            corpses_delta: d(m.corpses, gs.corpses),
+            births_delta: gs.births_total as i64 - m.births_total as i64,
+            deaths_delta: gs.deaths_total as i64 - m.deaths_total as i64,
+            mean_energy_delta: gs.mean_energy - m.mean_energy,
+            mean_health_delta: gs.mean_health - m.mean_health,
+            main: m,
+            ghost: gs,
+        })
+    }
+
+    // Schedule `command` for `tick`; it runs before that tick advances.
+    // Past ticks are rejected. Returns the sequence id.
+    pub fn queue_command(&mut self, tick: u64, command: Command) -> Option<u64> {
+        if tick < self.tick {
+            log::warn!("queue_command: tick {} is in the past (current {})", tick, self.tick);
+            return None;
+        }
+        let seq = self.next_command_seq;
+        self.enqueue_command(tick, seq, command);
+        Some(seq)
+    }
+
+    // Applied commands in application order (feed to load_command_log on a peer)
+    pub fn command_log(&self) -> &[ScheduledCommand] { &self.command_log }
+
+    // Queue every entry of a command log that is not yet in the past; returns how many were queued
+    pub fn load_command_log(&mut self, entries: Vec<ScheduledCommand>) -> u32 {
+        let mut n = 0;
+        for sc in entries {
+            if sc.tick < self.tick { log::warn!("load_command_log: skipping command for past tick {}", sc.tick); continue; }
+            self.enqueue_command(sc.tick, sc.seq, sc.command);
+            n += 1;
+        }
+        n
+    }
+
+    // Hash of the current simulation state
+    pub fn state_hash(&self) -> u64 { self.compute_state_hash() }
+
+    // Recent per-tick (tick, hash) pairs (requires config.state_hash_history > 0)
+    pub fn state_hashes(&self) -> impl Iterator<Item = (u64, u64)> + '_ { self.state_hashes.iter().copied() }
+
+    // Oldest tick reachable by rewind (or the current tick when history is empty)
+    pub fn rewind_horizon(&self) -> u64 {
+        self.snapshots.front().map(|s| s.tick).unwrap_or(self.tick)
+    }
+
+    // Accumulated step-phase timings (ms) since construction or the last reset_perf()
+    pub fn perf(&self) -> PerfReport {
+        let p = &self.perf;
+        PerfReport {
+            steps: p.steps,
+            sensing_ms: p.sensing_ms,
+            brain_ms: p.brain_ms,
+            movement_ms: p.movement_ms,
+            environment_ms: p.environment_ms,
+            reproduction_ms: p.reproduction_ms,
+            corpse_decay_ms: p.corpse_decay_ms,
+            serialization_ms: p.serialization_ms.get(),
+            total_step_ms: p.sensing_ms + p.brain_ms + p.movement_ms + p.environment_ms + p.reproduction_ms + p.corpse_decay_ms,
+        }
+    }
+
+    pub fn reset_perf(&mut self) {
+        self.perf = PerfCounters::default();
+    }
+
+    // Cheap metadata getters
+    pub fn tick(&self) -> u64 { self.tick }
+
+    pub fn width(&self) -> f32 { self.width }
+
+    pub fn height(&self) -> f32 { self.height }
+
+    pub fn brain_mode(&self) -> BrainMode { self.brain_mode }
+
+    pub fn config(&self) -> &Config { &self.config }
+
+    // Replace one creature's brain (e.g. imported from an external trainer). The first layer must
+    // match the current input size (brain mode plus custom channels). Parameters are sanitized
+    // (see Config::brain_weight_limit); returns how many were changed.
+    pub fn set_creature_brain(&mut self, id: &str, mut brain: Brain) -> Result<u32, BrainError> {
+        brain.validate()?;
+        if brain.layer_sizes[0] != self.input_len() {
+            return Err(BrainError::InputSize { expected: self.input_len(), actual: brain.layer_sizes[0] });
+        }
+        let Some(c) = self.creatures.iter_mut().find(|c| c.id == id) else {
+            return Err(BrainError::UnknownCreature { id: id.to_string() });
+        };
+        let fixed = brain.sanitize(self.config.brain_weight_limit);
+        if fixed > 0 { log::warn!("set_creature_brain: sanitized {} parameter(s) of '{}'", fixed, id); }
+        brain.activations = None;
+        c.brain = brain;
+        c.learned_weights.clear();
+        Ok(fixed)
+    }
+
+    // Pack every usable brain into per-topology batches (contiguous weights/biases plus the input
+    // rows for the upcoming tick) so they can be evaluated outside the engine, e.g. on the GPU.
+    // Inputs see every neighbour's pre-tick position, unlike the in-engine sequential pass.
+    pub fn brain_batches(&self) -> Vec<BrainBatch> {
+        let n_in = self.input_len();
+        let env = SenseEnv { width: self.width, height: self.height, tick: self.tick + 1, layout: self.io_layout(), plants: &self.plants, food: &self.food, corpses: &self.corpses, scent: &self.scent, speed_grid: self.speed_grid.as_ref(), senescence: self.config.senescence, max_life: self.config.max_life, seasons: self.config.seasons };
+        let mut batches: Vec<BrainBatch> = Vec::new();
+        for (i, c) in self.creatures.iter().enumerate() {
+            if !c.brain.is_well_formed() || c.brain.layer_sizes[0] != n_in { continue; }
+            let mut inputs = build_inputs(&env, c, &self.creatures[..i], &self.creatures[i + 1..]);
+            self.custom_inputs.append(&c.id, &mut inputs);
+            let idx = match batches.iter().position(|b| b.layer_sizes == c.brain.layer_sizes) {
+                Some(idx) => idx,
+                None => { batches.push(BrainBatch::new(c.brain.layer_sizes.clone())); batches.len() - 1 }
+            };
+            batches[idx].push(&c.id, &c.brain, &inputs);
+        }
+        batches
+    }
+
+    // Feed back externally computed outputs (row-major, one row per id) for the next tick only;
+    // rows whose width doesn't match the creature's output layer are ignored. Returns rows accepted.
+    pub fn set_brain_outputs(&mut self, ids: &[String], outputs: &[f32]) -> u32 {
+        if ids.is_empty() { return 0; }
+        if !outputs.len().is_multiple_of(ids.len()) {
+            log::warn!("set_brain_outputs: {} values do not divide into {} rows", outputs.len(), ids.len());
+            return 0;
+        }
+        let stride = outputs.len() / ids.len();
+        let mut accepted = 0;
+        for (id, row) in ids.iter().zip(outputs.chunks(stride.max(1))) {
+            let fits = self.creatures.iter()
+                .any(|c| c.id == *id && c.brain.layer_sizes.last().is_some_and(|&n| n as usize == stride));
+            if fits {
+                self.external_outputs.insert(id.clone(), row.to_vec());
+                accepted += 1;
+            }
+        }
+        if let Some(g) = self.ghost.as_deref_mut() { g.set_brain_outputs(ids, outputs); }
+        accepted
+    }
+
+    // Approximate heap + inline bytes held by each entity collection (capacity-based)
+    pub fn memory_report(&self) -> MemoryReport {
+        let creatures = self.creatures.capacity() * std::mem::size_of::<Creature>();
+        let creature_heap = self.creatures.iter().map(|c| c.heap_bytes()).sum::<usize>();
+        let brains = self.creatures.iter().map(|c| brain_heap_bytes(&c.brain)).sum::<usize>();
+        let plants = self.plants.capacity() * std::mem::size_of::<Plant>();
+        let corpses = self.corpses.capacity() * std::mem::size_of::<Corpse>();
+        let burrows = self.burrows.capacity() * std::mem::size_of::<Burrow>();
+        let food = self.food.capacity() * std::mem::size_of::<FoodItem>() + self.decomposers.capacity() * std::mem::size_of::<Decomposer>();
+        let grids = self.scent.heap_bytes() + self.soil.heap_bytes() + self.density.heap_bytes() + self.speed_grid.as_ref().map_or(0, |g| g.heap_bytes())
+            + self.climate.elevation_grid.as_ref().map_or(0, |g| g.heap_bytes());
+        let history = self.snapshots.iter().map(snapshot_heap_bytes).sum::<usize>() + self.dt_log.capacity() * std::mem::size_of::<f32>();
+        let commands = (self.pending_commands.capacity() + self.command_log.capacity()) * std::mem::size_of::<ScheduledCommand>()
+            + self.state_hashes.capacity() * std::mem::size_of::<(u64, u64)>();
+        let events = self.events.capacity() * std::mem::size_of::<WorldEvent>();
+        let elites = creature_vec_bytes(&self.elites);
+        let archives = self.highlights.heap_bytes()
+            + self.novelty_archive.capacity() * std::mem::size_of::<[f32; 3]>()
+            + self.transitions.iter().map(|(id, t)| std::mem::size_of::<(String, TransitionRecorder)>() + id.capacity() + t.heap_bytes()).sum::<usize>();
+        let logs = self.capacity.heap_bytes() + self.migration.heap_bytes();
+        let bad_brain_hashes = self.bad_brain_hashes.capacity() * std::mem::size_of::<String>()
+            + self.bad_brain_hashes.iter().map(|h| h.capacity()).sum::<usize>();
+        let front_buffer = self.front.as_ref().map_or(0, |f| f.heap_bytes());
+        let trails = self.trails.heap_bytes();
+        let ghost = self.ghost.as_ref().map_or(0, |g| std::mem::size_of::<World>() + g.memory_report().total);
+        let total = creatures + creature_heap + brains + plants + corpses + burrows + food + grids + history + commands + events + elites
+            + archives + logs + bad_brain_hashes + front_buffer + trails + ghost;
+        MemoryReport {
+            creatures, creature_heap, brains, plants, corpses, burrows, food, grids, history, commands, events, elites, archives, logs,
+            bad_brain_hashes, front_buffer, trails, ghost, total,
+        }
+    }
+
+    // Sample one environment field on a resolution x resolution grid (row-major, cell centers)
+    // field: "temperature" | "humidity" | "rain" | "wetness" | "wind" | "elevation" | "noise" | "scent" | "speed" |
+    // "currentX" | "currentY" | "plantGrowth" | "litter" | "fertility". Unknown fields yield an empty grid.
+    pub fn env_grid(&self, field: &str, resolution: u32) -> Vec<f32> {
+        let n = resolution.clamp(1, 512) as usize;
+        if n != resolution as usize { log::warn!("env_grid_json: resolution {} clamped to {}", resolution, n); }
+        let climate = &self.climate;
+        let sampler: Box<dyn Fn(f32, f32, u64) -> f32 + '_> = match field {
+            "temperature" => Box::new(|x, y, t| climate.temperature_c(x, y, t)),
+            "humidity" => Box::new(|x, y, t| climate.humidity01(x, y, t)),
+            "rain" => Box::new(sample_rain01),
+            "wetness" => Box::new(|x, y, t| climate.wetness01(x, y, t)),
+            "wind" => Box::new(sample_wind_speed),
+            "elevation" => Box::new(|x, y, _| climate.elevation01(x, y)),
+            "noise" => Box::new(sample_noise01),
+            "scent" => Box::new(|x, y, _| self.scent.sample(x, y)),
+            "litter" => Box::new(|x, y, _| self.soil.litter_at(x, y)),
+            "fertility" => Box::new(|x, y, _| self.soil.fertility_at(x, y)),
+            "currentX" => Box::new(|_, y, _| self.config.water_currents.at(y, self.height).0),
+            "currentY" => Box::new(|_, y, _| self.config.water_currents.at(y, self.height).1),
+            "plantGrowth" => Box::new(|x, y, _| self.plant_growth_at(x, y)),
+            "speed" => Box::new(|x, y, t| ground_speed(self.speed_grid.as_ref(), x, y, (self.width, self.height), t)),
+            _ => {
+                log::warn!("env_grid_json: unknown field '{}'", field);
+                return Vec::new();
+            }
+        };
+        let cw = self.width / n as f32;
+        let ch = self.height / n as f32;
+        let mut grid = Vec::with_capacity(n * n);
+        for gy in 0..n {
+            for gx in 0..n {
+                grid.push(sampler((gx as f32 + 0.5) * cw, (gy as f32 + 0.5) * ch, self.tick));
+            }
+        }
+        grid
+    }
+
+    // All environment values at a point for the current tick (tooltips / JS parity checks)
+    pub fn sample_env(&self, x: f32, y: f32) -> EnvSample { self.env_at(x, y) }
+
+    // Spawn a single creature at a specific location (diet randomized)
+    pub fn spawn_creature(&mut self, x: f32, y: f32) {
+        if let Some(g) = self.ghost.as_deref_mut() { g.spawn_creature(x, y); }
+        let genes = Genes::founder(self.seed, self.next_creature_id, &self.config);
+        let id = creature::take_creature_id(&mut self.next_creature_id);
+        let diet = if self.rng.next_f32_01() > 0.8 { Diet::Carnivore } else { Diet::Herbivore };
+        let brain = init_brain_avoiding_bad(self.brain_layer_sizes(), &mut self.rng, &self.bad_brain_hashes, self.config.legacy_brain_hash);
+        let vx = self.rng.uniform(-1.0, 1.0) * 2.0;
+        let vy = self.rng.uniform(-1.0, 1.0) * 2.0;
+        self.creatures.push(Creature { vx, vy, genes, ..Creature::new(id, x, y, diet, brain) });
+    }
+
+    // Spawn a plant at a location with optional radius (defaults to 3.0)
+    pub fn spawn_plant(&mut self, x: f32, y: f32, radius: Option<f32>) {
+        if let Some(g) = self.ghost.as_deref_mut() { g.spawn_plant(x, y, radius); }
+        let r = radius.unwrap_or(3.0).max(0.5);
+        if let Some(req) = radius { if req < 0.5 { log::warn!("spawn_plant: radius {} clamped to {}", req, r); } }
+        self.plants.push(Plant{ x, y, radius: r, biomass: self.config.plant_max_biomass, bloom_until: None });
+    }
+
+    // Reset the world entities using current dimensions, RNG, and brain mode
+    pub fn reset_world(&mut self) {
+        self.clear_entities();
+        // Recreate a default population similar to constructor
+        self.populate(50, 150);
+    }
+
+    // Empty world at tick 0: entities, counters, archives, and history cleared
+    fn clear_entities(&mut self) {
+        self.front = None;
+        self.trails = TrailLog::default();
+        self.watch = None;
+        self.capacity = CapacityLog::default();
+        self.migration = MigrationLog::default();
+        self.density = DensityGrid::default();
+        self.tick = 0;
+        self.creatures.clear();
+        self.plants.clear();
+        self.corpses.clear();
+        self.burrows.clear();
+        self.next_creature_id = 0;
+        self.decomposers.clear();
+        self.food.clear();
+        self.soil = SoilGrid::default();
+        self.births_total = 0;
+        self.deaths_total = 0;
+        self.attack_attempts_total = 0;
+        self.kills_total = 0;
+        self.elites.clear();
+        self.novelty_archive.clear();
+        self.transitions.clear();
+        self.scent = ScentField::default();
+        self.highlights = HighlightDigest::default();
+        self.clear_history();
+    }
+
+    // Synthetic dense world (fixed seed, ~2500 square units per creature, 3 plants each, no history)
+    // stepped for `ticks` fixed 1/60s ticks. Deterministic, so reports are comparable across devices.
+    pub fn benchmark(ticks: u32, population: u32) -> BenchmarkReport {
+        let population = population.max(1);
+        let side = (population as f32 * 2500.0).sqrt();
+        let mut w = World::new(side, side, 0xBE7C_4A11);
+        w.config.snapshot_capacity = 0;
+        w.creatures.clear();
+        w.plants.clear();
+        w.populate(population as usize, population as usize * 3);
+        let t0 = now_ms();
+        for _ in 0..ticks { w.advance(1.0 / 60.0); }
+        let elapsed_ms = now_ms() - t0;
+        BenchmarkReport {
+            ticks,
+            population,
+            final_population: w.creatures.len() as u32,
+            elapsed_ms,
+            ticks_per_sec: if elapsed_ms > 0.0 { ticks as f64 * 1000.0 / elapsed_ms } else { 0.0 },
+            perf: w.perf(),
+        }
+    }
+
+    // Append `creatures` random creatures (current brain mode) and `plants` default plants
+    fn populate(&mut self, creatures: usize, plants: usize) {
+        for _ in 0..creatures {
+            let diet = if self.rng.next_f32_01() > 0.8 { Diet::Carnivore } else { Diet::Herbivore };
+            let brain = init_brain_avoiding_bad(self.brain_layer_sizes(), &mut self.rng, &self.bad_brain_hashes, self.config.legacy_brain_hash);
+            let x = self.rng.uniform(0.0, self.width);
+            let y = self.rng.uniform(0.0, self.height);
+            let vx = self.rng.uniform(-1.0, 1.0) * 2.0;
+            let vy = self.rng.uniform(-1.0, 1.0) * 2.0;
+            let genes = Genes::founder(self.seed, self.next_creature_id, &self.config);
+            let id = creature::take_creature_id(&mut self.next_creature_id);
+            self.creatures.push(Creature { vx, vy, genes, ..Creature::new(id, x, y, diet, brain) });
+        }
+        for _ in 0..plants {
+            self.plants.push(Plant{ x: self.rng.uniform(0.0, self.width), y: self.rng.uniform(0.0, self.height), radius: 3.0, biomass: self.config.plant_max_biomass, bloom_until: None });
+        }
+    }
+
+    // Switch to "OG" or "Zegion" (case-insensitive), re-rolling every brain when the mode changes;
+    // Err (and unchanged) for any other name
+    pub fn set_brain_mode(&mut self, mode: &str) -> Result<(), String> {
+        let new_mode = BrainMode::parse(mode).ok_or_else(|| format!("unknown brain mode '{}' (expected OG or Zegion)", mode))?;
+        if new_mode == self.brain_mode { return Ok(()); }
+        self.brain_mode = new_mode;
+        let layer_sizes = self.brain_layer_sizes();
+        for c in &mut self.creatures { c.brain = init_brain_avoiding_bad(layer_sizes.clone(), &mut self.rng, &self.bad_brain_hashes, self.config.legacy_brain_hash); }
+        Ok(())
+    }
+
+    // Reseed the simulation RNG and regenerate the seeded terrain/climate (an uploaded elevation grid
+    // is kept). Existing entities stay where they are.
+    pub fn set_seed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.rng = RngLCG::new(seed);
+        let elevation_grid = self.climate.elevation_grid.take();
+        self.climate = Climate::new(seed, (self.width, self.height), &self.config);
+        self.climate.elevation_grid = elevation_grid;
+    }
+
+    // Reseed only the simulation RNG (brain draws, spawns, births); the world seed and the terrain
+    // and climate it generated are left alone
+    pub fn set_brain_seed(&mut self, seed: u32) { self.rng = RngLCG::new(seed); }
+
+    pub fn set_bad_brain_hashes(&mut self, hashes: Vec<String>) {
+        let new_set: HashSet<String> = hashes.into_iter().collect();
+        log::debug!("set_bad_brain_hashes: {} hashes loaded", new_set.len());
+        self.bad_brain_hashes = new_set;
+    }
+
+    // Brain maintenance pass (runs automatically every config.pruneEveryTicks): dead hidden neurons
+    // are re-initialized or removed per config.pruneMode, then small weights are sparsified
+    pub fn prune_brains(&mut self) -> PruneReport {
+        let mut report = PruneReport::default();
+        for c in &mut self.creatures {
+            let (neurons, weights) = prune_brain(&mut c.brain, &mut c.neuron_activity, self.config.prune_mode, self.config.sparsify_weight_below, &mut self.rng);
+            if neurons > 0 { report.brains_touched += 1; }
+            report.neurons_pruned += neurons;
+            report.weights_zeroed += weights;
+        }
+        log::debug!("prune_brains: {} neurons in {} brains, {} weights zeroed", report.neurons_pruned, report.brains_touched, report.weights_zeroed);
+        report
+    }
+
+    // Child of two same-topology brains under config.crossover (None if off or incompatible);
+    // draws from the world RNG
+    pub fn crossover_brains(&mut self, a: &Brain, b: &Brain) -> Option<Brain> {
+        crossover(a, b, &self.config.crossover, &mut self.rng)
+    }
+
+    // Copy of `brain` put through the configured weight mutation (annealed for `generation`), for
+    // hosts running their own epoch-style selection; draws from the world RNG
+    pub fn mutate_brain(&mut self, brain: &Brain, generation: u32) -> Brain {
+        let mut child = Brain { activations: None, ..brain.clone() };
+        mutate_weights(&mut child, &self.config.mutation, generation, &mut self.rng);
+        child
+    }
+
+    // Events since the last call, oldest first
+    pub fn drain_events(&mut self) -> Vec<WorldEvent> { self.events.drain(..).collect() }
+
+    // Recent notable moments, oldest first (not drained; the digest keeps the latest 64)
+    pub fn highlights(&self) -> &VecDeque<Highlight> { self.highlights.entries() }
+
+    // Hash in the format the bad-brain set expects (see Config::legacy_brain_hash)
+    pub fn brain_hash(&self, brain: &Brain) -> String { brain_hash(brain, self.config.legacy_brain_hash) }
+
+    pub fn set_config(&mut self, config: Config) { self.apply_config(config); }
+
+    // Merge a partial camelCase config object into the current config (nested objects merge key by
+    // key, unmentioned fields keep their values); Err (and unchanged) for an unknown top-level key
+    // or an invalid value
+    pub fn update_config(&mut self, patch: &serde_json::Value) -> Result<(), String> {
+        check_config_keys(patch)?;
+        let cfg = merge_config(&self.config, patch)?;
+        self.apply_config(cfg);
+        Ok(())
+    }
+
+    // Swap in `config`: brains are re-fitted to any I/O layout change and fitness is re-scored.
+    // Every config change (set_config, setConfig commands, ghosts, scenarios) goes through here.
+    pub(crate) fn apply_config(&mut self, config: Config) {
+        let layout = self.io_layout();
+        self.config = config;
+        self.climate.params = NoiseParams::from_config(&self.config);
+        self.refresh_fitness();
+        self.reconcile_io(layout);
+    }
+}
+
+impl World {
+    // One simulation tick of dt seconds (already scaled); shared by step() and headless runners
+    pub fn advance(&mut self, dt: f32) {
+        self.record_history(dt);
+        self.apply_due_commands();
+        self.tick += 1;
+        self.perf.steps += 1;
+        self.tally = StepTally::default();
+        self.begin_energy_audit();
+        self.pair_mates();
+        let mut t_mark = now_ms();
+        // Simple behavior: herbivores drift, carnivores chase nearest herbivore
+        // Collect offspring to append after the main iteration to avoid borrow conflicts
+        let mut newborns: Vec<Creature> = Vec::new();
+        let debt_floor = self.config.energy_debt_floor.min(0.0);
+        // Energy at or below this is starvation (0 unless creatures may live in debt)
+        let starve_at = if self.config.survive_energy_debt { debt_floor } else { 0.0 };
+        // Shared energy (recipient index, amount), credited once every creature has acted
+        let mut gifts: Vec<(usize, f32)> = Vec::new();
+        // Predation damage (victim index, health), dealt once every creature has acted so the bite
+        // outweighs the victim's own harm this tick when naming the cause
+        let mut bitten: Vec<(usize, f32)> = Vec::new();
+        // Carnivores eating (indices) and ids signalling CARRY, resolved against corpses after the pass
+        let mut meat_eaters: Vec<usize> = Vec::new();
+        // Plant bites (creature index, plant index)
+        let mut bites: Vec<(usize, usize)> = Vec::new();
+        // Food item bites (creature index, item index)
+        let mut food_bites: Vec<(usize, usize)> = Vec::new();
+        let mut carriers: Vec<String> = Vec::new();
+        let input_len = self.input_len();
+        let layer_sizes = self.brain_layer_sizes();
+        let noise = self.sensor_noise();
+        if self.config.scent.enabled {
+            self.scent.update(self.width, self.height, &self.plants, &self.corpses, &self.config.scent, dt * 60.0);
+        } else if self.scent.heap_bytes() > 0 {
+            self.scent = ScentField::default();
+        }
+        let env = SenseEnv { width: self.width, height: self.height, tick: self.tick, layout: self.io_layout(), plants: &self.plants, food: &self.food, corpses: &self.corpses, scent: &self.scent, speed_grid: self.speed_grid.as_ref(), senescence: self.config.senescence, max_life: self.config.max_life, seasons: self.config.seasons };
+        for i in 0..self.creatures.len() {
+            let (left, right) = self.creatures.split_at_mut(i);
+            // Split again to keep current creature disjoint from the rest to satisfy the borrow checker
+            let (cur_slice, rest) = right.split_at_mut(1);
+            let c = &mut cur_slice[0];
+            // Terrain influence reduces effective speed on rough terrain
+            // Flyers aloft ignore terrain, slope, and water
+            let aloft = c.altitude > 0.0;
+            let speed_mult = if aloft { 1.0 } else { env.terrain_speed(c.x, c.y) };
+            // Build inputs and run brain forward pass to steer
+            let mut inputs = build_inputs(&env, c, left, rest);
+            self.custom_inputs.append(&c.id, &mut inputs);
+            if let Some(noise) = &noise { noise.apply(&mut inputs, &mut self.rng); }
+            let recorder = self.transitions.get_mut(&c.id);
+            let baseline = recorder.as_ref().map(|_| RewardBaseline::of(c));
+            if let Some(rec) = recorder { rec.complete(&inputs); }
+            t_mark = lap(&mut self.perf.sensing_ms, t_mark);
+            let (out, acts) = match self.external_outputs.remove(&c.id) {
+                // Externally evaluated: keep the last in-engine activations for visualization
+                Some(out) => (out, c.brain.activations.take().unwrap_or_default()),
+                None => match brain_forward(&mut c.brain, &inputs, self.brain_mode) {
+                    Ok((out, acts)) => {
+                        if self.config.prune_every_ticks > 0 { c.neuron_activity.observe(&acts); }
+                        if self.config.plasticity.enabled { apply_plasticity(c, &acts, &self.config.plasticity, dt * 60.0); }
+                        (out, acts)
+                    }
+                    // A brain that can't run leaves the creature idle; report it once
+                    Err(e) => {
+                        if !c.degenerate_brain { log::warn!("brain of {} cannot run: {}", c.id, e); }
+                        c.degenerate_brain = true;
+                        (Vec::new(), Vec::new())
+                    }
+                },
+            };
+            if !c.degenerate_brain {
+                if let Some(reason) = c.output_watch.observe(&c.brain, &out, self.config.degenerate_window_ticks) {
+                    let action = self.config.degenerate_action;
+                    let hash = handle_degenerate(c, action, self.config.degenerate_mutation_sigma, &mut self.rng, &mut self.bad_brain_hashes, self.config.legacy_brain_hash);
+                    log::debug!("degenerate brain {} on {} ({:?}, {:?})", hash, c.id, reason, action);
+                    push_event(&mut self.events, WorldEvent::DegenerateBrain { tick: self.tick, id: c.id.clone(), hash, reason, action });
+                }
+            }
+            t_mark = lap(&mut self.perf.brain_ms, t_mark);
+            // Use outputs
+            let ax = out.first().cloned().unwrap_or(0.0).tanh();
+            let ay = out.get(1).cloned().unwrap_or(0.0).tanh();
+            let a_scale = (out.get(2).cloned().unwrap_or(0.0)).tanh().abs();
+            let eat_sig = out.get(3).cloned().unwrap_or(0.0).tanh();
+            let rest_sig = out.get(4).cloned().unwrap_or(0.0).tanh();
+            let boost_sig = out.get(5).cloned().unwrap_or(0.0).tanh();
+            // Only topologies with a 7th output (OG) can drive burrowing
+            let mode_out = &out[..out.len().min(env.layout.mode.output_len() as usize)];
+            let burrow_sig = mode_out.get(6).cloned().unwrap_or(0.0).tanh();
+            let carry_sig = env.layout.carry_output().and_then(|k| out.get(k)).cloned().unwrap_or(0.0).tanh();
+            let torpor_sig = env.layout.torpor_output().and_then(|k| out.get(k)).cloned().unwrap_or(0.0).tanh();
+            // Torpor: a torpid creature lies still and pays a fraction of its metabolic costs
+            let metab = if self.config.torpor.enabled {
+                let can_enter = c.altitude <= 0.0 && !in_water_at(c.y, self.height);
+                self.config.torpor.update(c, torpor_sig > 0.5, can_enter, self.config.seasons.season_at(self.tick), dt * 60.0)
+            } else {
+                c.torpor_sec = 0.0;
+                c.wake_timer = 0.0;
+                1.0
+            };
+            let torpid = c.torpor_sec > 0.0;
+            // Senescence: the old are slower, recover stamina more slowly, and sense less
+            let vigor = self.config.senescence.vigor(c.age_norm(self.config.max_life));
+            let mut accel = 0.35 * speed_mult * (0.5 + a_scale);
+            let wants_boost = boost_sig > 0.5 && !torpid;
+            let wants_rest = rest_sig > 0.5 && !torpid;
+            let wants_eat = eat_sig > 0.5 && !torpid;
+            if wants_boost { accel *= 1.5; }
+            let (mut dvx, mut dvy) = (ax * accel, ay * accel);
+            let max_accel = self.config.max_accel * c.genes.agility;
+            if max_accel > 0.0 { (dvx, dvy) = clamp_magnitude(dvx, dvy, max_accel); }
+            c.vx += dvx;
+            c.vy += dvy;
+            let max_speed = self.config.max_speed * c.genes.agility * vigor.speed;
+            if max_speed > 0.0 { (c.vx, c.vy) = clamp_magnitude(c.vx, c.vy, max_speed); }
+            // Flight: boosting climbs, wanting to eat/rest descends; must be landed to eat, drink, or rest
+            if c.genes.can_fly {
+                let climb = self.config.flight_climb_rate_per_sec * dt * 60.0;
+                if wants_eat || wants_rest { c.altitude = (c.altitude - climb).max(0.0); }
+                else if wants_boost { c.altitude = (c.altitude + climb).min(1.0); }
+            } else {
+                c.altitude = 0.0;
+            }
+            let grounded = c.altitude <= 0.0;
+            // Water slows poor swimmers; strong swimmers cross at near-land speed
+            let in_water = !aloft && in_water_at(c.y, self.height);
+            let water_mult = if in_water {
+                let min = self.config.swim_speed_min_mult.clamp(0.0, 1.0);
+                min + (1.0 - min) * c.genes.swim_skill
+            } else { 1.0 };
+            // Slope along the heading: uphill slows, downhill gives a small boost
+            let grade = if aloft { 0.0 } else { self.climate.grade_along(c.x, c.y, c.vx, c.vy) };
+            let slope_mult = if grade > 0.0 {
+                (1.0 - self.config.slope_speed_penalty * grade).max(0.2)
+            } else {
+                1.0 + self.config.slope_downhill_boost * (-grade).min(1.0)
+            };
+            // Burrowed creatures stay put
+            if c.burrow_id.is_some() || torpid { c.vx = 0.0; c.vy = 0.0; }
+            // Dragging a corpse slows the carrier in proportion to its size; waking from torpor and late pregnancy are slow
+            let drag_mult = self.config.corpse_carry.drag_mult(c.carry_load) * self.config.torpor.speed_mult(c)
+                * self.config.pregnancy.speed_mult(c, self.config.gestation_period);
+            let step_x = c.vx * dt * 60.0 * speed_mult * slope_mult * water_mult * drag_mult;
+            let step_y = c.vy * dt * 60.0 * speed_mult * slope_mult * water_mult * drag_mult;
+            c.x += step_x;
+            c.y += step_y;
+            // Water currents carry swimmers downstream
+            if in_water && c.burrow_id.is_none() {
+                let (cx, cy) = self.config.water_currents.at(c.y, self.height);
+                let push = self.config.water_currents.swimmer_push(c.genes.swim_skill) * dt * 60.0;
+                c.x += cx * push;
+                c.y += cy * push;
+            }
+            c.metrics.distance_traveled += (step_x * step_x + step_y * step_y).sqrt();
+            c.vx *= 0.99;
+            c.vy *= 0.99;
+            if self.config.spatial_memory.enabled {
+                let spots = self.plants.iter().map(|p| (p.x, p.y))
+                    .chain(self.food.iter().map(|f| (f.x, f.y)))
+                    .chain(self.corpses.iter().map(|co| (co.x, co.y)));
+                c.memory.update(c.x, c.y, spots, &self.config.spatial_memory, dt * 60.0);
+            }
+            if self.config.novelty.enabled {
+                c.behavior.observe_motion(c.x / self.width.max(1.0), c.y / self.height.max(1.0), c.vx, c.vy, self.config.movement_threshold);
+            }
+            // Reset telemetry masks (a chase already under way last tick is not a new attempt)
+            let was_attacking = c.actions_mask & ActionFlag::Attacking.mask() != 0;
+            c.actions_mask = 0;
+            c.feelings_mask = 0;
+            c.ledger = EnergyLedger::default();
+            c.harm_peak = 0.0;
+            // Burrowing: enter a nearby burrow (digging one if needed) and leave when the signal drops
+            let wants_burrow = burrow_sig > 0.5;
+            if wants_burrow && grounded && !in_water {
+                if c.burrow_id.is_none() {
+                    let reach = c.radius + self.config.burrow_radius;
+                    let existing = self.burrows.iter()
+                        .find(|b| { let dx = b.x - c.x; let dy = b.y - c.y; dx * dx + dy * dy <= reach * reach })
+                        .map(|b| b.id);
+                    let id = match existing {
+                        Some(id) => id,
+                        None => {
+                            c.apply_energy(LedgerCat::Burrow, -self.config.burrow_dig_cost_energy, 0.0);
+                            c.actions_mask |= ActionFlag::Digging.mask();
+                            let id = self.next_burrow_id;
+                            self.next_burrow_id = self.next_burrow_id.wrapping_add(1);
+                            self.burrows.push(Burrow { id, x: c.x, y: c.y, radius: self.config.burrow_radius, dug_tick: self.tick });
+                            // Oldest burrows collapse once the cap is exceeded
+                            let cap = self.config.max_burrows as usize;
+                            if self.burrows.len() > cap { let excess = self.burrows.len() - cap; self.burrows.drain(..excess); }
+                            id
+                        }
+                    };
+                    c.burrow_id = Some(id);
+                }
+            } else {
+                c.burrow_id = None;
+            }
+            let burrowed = c.burrow_id.is_some();
+            if burrowed { c.actions_mask |= ActionFlag::Burrowed.mask(); }
+            if torpid { c.actions_mask |= ActionFlag::Torpid.mask(); }
+            // Rest behavior: damp and regen small amounts
+            if wants_rest && grounded {
+                c.vx *= 0.9;
+                c.vy *= 0.9;
+                // rest regen (scaled by config)
+                c.stamina = (c.stamina + self.config.rest_stamina_regen_per_sec * vigor.stamina_regen * dt * 60.0).min(c.max_stamina);
+                if c.health < 100.0 { c.health = (c.health + self.config.rest_health_regen_per_sec * dt * 60.0).min(100.0); }
+                c.actions_mask |= ActionFlag::Resting.mask();
+            }
+            // Eat behavior: bite a food item in reach, else the nearest plant with biomass left; the
+            // yield is resolved after the pass so creatures sharing a plant or item split it
+            if wants_eat && grounded && !burrowed {
+                let reach = c.radius + 5.0;
+                let target = match food_target(&self.food, c.x, c.y, reach) {
+                    Some(f) => Some((&mut food_bites, f)),
+                    None => harvest_target(&self.plants, c.x, c.y, reach).map(|p| (&mut bites, p)),
+                };
+                if let Some((list, k)) = target {
+                    list.push((i, k));
+                    c.apply_energy(LedgerCat::Harvest, -self.config.harvest_plant_action_cost_per_second * dt * 60.0, 0.0);
+                }
+            }
+            // Sprint energy drain
+            if wants_boost {
+                c.apply_energy(LedgerCat::Sprint, -0.1, 0.0);
+                c.actions_mask |= ActionFlag::Sprinting.mask();
+            }
+            // Sprint overflow: if moving fast while boosting, extra cost
+            let speed_mag = (c.vx * c.vx + c.vy * c.vy).sqrt();
+            if wants_boost && speed_mag > 2.5 { c.apply_energy(LedgerCat::SprintOverflow, -self.config.sprint_overflow_cost_per_sec * dt * 60.0, 0.0); }
+            // Posture maintenance when nearly idle and not explicitly resting
+            if !wants_rest && speed_mag < 0.05 { c.apply_energy(LedgerCat::Posture, -self.config.posture_cost_per_sec * metab * dt * 60.0, 0.0); }
+            // Predation: a boosting carnivore bites the nearest herbivore in reach (damage lands after the pass)
+            if c.diet == Diet::Carnivore && wants_boost {
+                if let Some(j) = prey_in_reach(c, left, rest, self.config.attack_reach) {
+                    let (to, victim) = if j < left.len() { (j, &mut left[j]) } else { (i + 1 + j - left.len(), &mut rest[j - left.len()]) };
+                    c.apply_energy(LedgerCat::Attack, -self.config.attack_cost_per_hit_energy * dt * 60.0, 0.0);
+                    c.stamina = (c.stamina - self.config.attack_cost_per_hit_stamina * dt * 60.0).max(0.0);
+                    c.actions_mask |= ActionFlag::Attacking.mask();
+                    if !was_attacking {
+                        c.metrics.attack_attempts += 1;
+                        self.attack_attempts_total += 1;
+                        self.tally.attacks += 1;
+                    }
+                    c.recent_actions.push(self.tick, CreatureAction::Attacked { target: victim.id.clone() }, 0.0);
+                    // The first biter this tick keeps the credit for it
+                    if victim.last_bite.as_ref().is_none_or(|b| b.tick != self.tick) {
+                        victim.last_bite = Some(Bite { by: c.id.clone(), tick: self.tick });
+                    }
+                    if self.config.spatial_memory.enabled {
+                        c.memory.remember_attack(victim.x, victim.y);
+                        victim.memory.remember_attack(c.x, c.y);
+                    }
+                    bitten.push((to, self.config.attack_damage_per_sec.max(0.0) * dt * 60.0));
+                }
+            }
+            // Drinking at the shore or from rain puddles: recover thirst, pay drink cost
+            if grounded && !burrowed && !torpid && can_drink_at(c.x, c.y, self.height, self.tick, c.radius + self.config.drink_reach, self.config.puddle_rain_threshold) {
+                let thirst_thresh = self.config.thirst_threshold;
+                if c.thirst < thirst_thresh {
+                    let before = c.thirst;
+                    c.thirst = (c.thirst + self.config.thirst_recovery_per_sec * dt * 60.0).min(100.0);
+                    c.apply_energy(LedgerCat::Drink, -self.config.drink_cost_per_second * dt * 60.0, 0.0);
+                    c.actions_mask |= ActionFlag::Drinking.mask();
+                    c.recent_actions.push(self.tick, CreatureAction::Drank, c.thirst - before);
+                }
+            }
+            // Carnivores eat from and carry corpses (applied after the pass)
+            if c.diet == Diet::Carnivore && grounded && !burrowed {
+                if wants_eat { meat_eaters.push(i); }
+                if carry_sig > 0.5 && !torpid { carriers.push(c.id.clone()); c.actions_mask |= ActionFlag::Carrying.mask(); }
+            }
+            // Share: only topologies with an 8th output (OG) can give energy away
+            let share_sig = mode_out.get(7).cloned().unwrap_or(0.0).tanh();
+            c.share_cooldown = (c.share_cooldown - dt * 60.0).max(0.0);
+            let sharing = &self.config.energy_sharing;
+            if sharing.enabled && share_sig > 0.5 && !torpid && c.share_cooldown <= 0.0 && c.energy - sharing.amount >= sharing.min_donor_energy {
+                if let Some((j, kin)) = pick_recipient(c, left, rest, sharing) {
+                    let (to, to_id) = if j < left.len() { (j, left[j].id.clone()) } else { (i + 1 + j - left.len(), rest[j - left.len()].id.clone()) };
+                    let received = sharing.amount * sharing.efficiency.clamp(0.0, 1.0);
+                    c.apply_energy(LedgerCat::Share, -sharing.amount, 0.0);
+                    c.share_cooldown = sharing.cooldown_sec;
+                    c.actions_mask |= ActionFlag::Sharing.mask();
+                    c.recent_actions.push(self.tick, CreatureAction::SharedEnergy { to: to_id.clone() }, sharing.amount);
+                    gifts.push((to, received));
+                    push_event(&mut self.events, WorldEvent::EnergyShared { tick: self.tick, from: c.id.clone(), to: to_id, amount: sharing.amount, received, kin });
+                }
+            }
+            // Baseline movement energy (locomotion cost proportional to speed)
+            let locomotion = self.config.move_cost_coeff_per_speed_per_sec * speed_mag;
+            c.apply_energy(LedgerCat::Locomotion, -locomotion * metab * dt * 60.0, 0.0);
+            // Nervous-system upkeep scales with brain size
+            if self.config.brain_cost_per_param_per_sec > 0.0 {
+                let brain_cost = self.config.brain_cost_per_param_per_sec * brain_param_count(&c.brain) as f32;
+                c.apply_energy(LedgerCat::Brain, -brain_cost * metab * dt * 60.0, 0.0);
+            }
+            // Males pay to display their ornaments (Config::sexes)
+            let display = self.config.sexes.display_cost(c);
+            if display > 0.0 { c.apply_energy(LedgerCat::Display, -display * metab * dt * 60.0, 0.0); }
+            // Staying aloft is expensive
+            if c.altitude > 0.0 {
+                c.apply_energy(LedgerCat::Flight, -self.config.flight_energy_cost_per_sec * dt * 60.0, 0.0);
+                c.actions_mask |= ActionFlag::Flying.mask();
+            }
+            // Environmental energy costs (simple samplers for parity scaffolding)
+            // NOTE: Keep these formulas 1:1 with the JS validator in useSimulationStore.ts.
+            // Units: all costs are per-second rates; we multiply by t_sec = dt*60 to apply.
+            // Components:
+            //  - Swim: penalty inside heuristic water bands, reduced by swim skill.
+            //  - Wind: proportional to wind speed and creature ground speed.
+            //  - Cold/Heat: linear penalties outside comfort range (degC scaled by 1/10 factor), reduced in burrows.
+            //  - Humidity: dehydration above threshold.
+            //  - Oxygen: thin air penalty above elevation cutoff, eased by acclimatization.
+            //  - Noise: proportional to ambient noise.
+            //  - Disease: flat drain.
+            //  - Slope: proportional to uphill grade and ground speed.
+            t_mark = lap(&mut self.perf.movement_ms, t_mark);
+            let t_sec = dt * 60.0;
+            let temp_c = self.climate.temperature_c(c.x, c.y, self.tick);
+            let humid01 = self.climate.humidity01(c.x, c.y, self.tick);
+            let wind = sample_wind_speed(c.x, c.y, self.tick);
+            let elev01 = self.climate.elevation01(c.x, c.y);
+            let noise01 = sample_noise01(c.x, c.y, self.tick);
+            let env_swim = if in_water {
+                self.config.swim_energy_cost_per_sec * (1.0 - self.config.swim_skill_cost_reduction.clamp(0.0, 1.0) * c.genes.swim_skill)
+            } else { 0.0 };
+            // Poor swimmers tire quickly in water and drown once stamina is gone
+            if in_water {
+                let drain = self.config.swim_stamina_drain_per_sec * (1.0 - c.genes.swim_skill);
+                c.stamina = (c.stamina - drain * t_sec).max(0.0);
+                if c.stamina <= 0.0 {
+                    c.harm(DeathCause::Drowning, self.config.drowning_damage_per_sec * t_sec);
+                    c.feelings_mask |= FeelingFlag::Drowning.mask();
+                }
+            }
+            let env_wind = self.config.wind_drag_coeff * wind * speed_mag;
+            // Burrows buffer temperature extremes
+            let shelter = if burrowed { 1.0 - self.config.burrow_temp_shelter.clamp(0.0, 1.0) } else { 1.0 };
+            let env_cold = if temp_c < self.config.comfort_low_c {
+                let d = (self.config.comfort_low_c - temp_c).max(0.0);
+                self.config.temp_cold_penalty_per_sec * d / 10.0 * shelter
+            } else { 0.0 };
+            let env_heat = if temp_c > self.config.comfort_high_c {
+                let d = (temp_c - self.config.comfort_high_c).max(0.0);
+                self.config.temp_heat_penalty_per_sec * d / 10.0 * shelter
+            } else { 0.0 };
+            let env_humid = if humid01 > self.config.humidity_threshold {
+                let ex = (humid01 - self.config.humidity_threshold).max(0.0);
+                self.config.humidity_dehydration_coeff_per_sec * ex
+            } else { 0.0 };
+            // Acclimatization drifts toward the excess altitude (normalized so the peak reads 1)
+            let cutoff = self.config.thin_air_elevation_cutoff01.clamp(0.0, 0.99);
+            let excess = ((elev01 - cutoff) / (1.0 - cutoff)).clamp(0.0, 1.0);
+            let adapt = (self.config.altitude_acclimatization_per_sec.max(0.0) * t_sec).min(1.0);
+            c.acclimatization += (excess - c.acclimatization) * adapt;
+            let env_oxy = if elev01 > self.config.thin_air_elevation_cutoff01 {
+                let ex = (elev01 - self.config.thin_air_elevation_cutoff01).max(0.0);
+                let relief = 1.0 - self.config.altitude_acclimatization_relief.clamp(0.0, 1.0) * c.acclimatization;
+                self.config.oxygen_thin_air_penalty_per_sec * ex * relief
+            } else { 0.0 };
+            let env_noise = self.config.noise_stress_penalty_per_sec * noise01;
+            let sick = !self.config.disease.enabled || c.infected;
+            let env_disease = if sick { self.config.disease_energy_drain_per_sec * self.config.inbreeding.disease_mult(c.inbreeding) } else { 0.0 };
+            let env_slope = self.config.slope_uphill_cost_per_sec * grade.max(0.0) * speed_mag;
+            let env_total = env_swim + env_wind + env_cold + env_heat + env_humid + env_oxy + env_noise + env_disease + env_slope;
+            // Record telemetry
+            c.last_locomotion = locomotion;
+            c.last_env_total = env_total;
+            c.last_env_swim = env_swim;
+            c.last_env_wind = env_wind;
+            c.last_env_cold = env_cold;
+            c.last_env_heat = env_heat;
+            c.last_env_humid = env_humid;
+            c.last_env_oxy = env_oxy;
+            c.last_env_noise = env_noise;
+            c.last_env_disease = env_disease;
+            c.last_env_slope = env_slope;
+            if env_total != 0.0 { c.apply_energy(LedgerCat::Environment, -env_total * metab * t_sec, 0.0); }
+            // Ambient health decay with aging
+            let age_norm = c.age_norm(self.config.max_life);
+            let ambient = self.config.ambient_health_decay_per_sec * (1.0 + self.config.aging_health_decay_coeff * age_norm);
+            if !wants_rest { c.harm(DeathCause::OldAge, ambient * dt * 60.0); }
+            // Thirst: base loss plus heat above the comfort band and exertion; damage once dry
+            let heat = (temp_c - self.config.comfort_high_c).max(0.0) / 10.0;
+            let thirst_loss = self.config.thirst_decay_per_sec + self.config.thirst_heat_coeff * heat + self.config.thirst_exertion_coeff * speed_mag;
+            c.thirst = (c.thirst - thirst_loss * metab * t_sec).max(0.0);
+            if c.thirst <= 0.0 { c.harm(DeathCause::Dehydration, self.config.dehydration_damage_per_sec * t_sec); }
+            // Energy debt (energy at or below 0) eats into health until repaid
+            if self.config.survive_energy_debt && c.energy <= 0.0 { c.harm(DeathCause::Starvation, self.config.energy_debt_health_penalty_per_sec.max(0.0) * t_sec); }
+            // Well-fed creatures convert surplus energy (above the threshold) into health
+            let surplus = c.energy - self.config.regen_energy_threshold;
+            if c.health < 100.0 && surplus > 0.0 {
+                let cost = self.config.regen_energy_per_health.max(0.0);
+                let mut heal = (self.config.regen_health_per_sec.max(0.0) * t_sec).min(100.0 - c.health);
+                if cost > 0.0 { heal = heal.min(surplus / cost); }
+                c.health += heal;
+                c.apply_energy(LedgerCat::Regen, -heal * cost, 0.0);
+            }
+            t_mark = lap(&mut self.perf.environment_ms, t_mark);
+            // Gestation per-second cost and birth handling
+            c.postpartum_timer = (c.postpartum_timer - dt * 60.0).max(0.0);
+            if c.is_pregnant {
+                // Litter size is fixed by the genes when gestation starts
+                if c.gestation_timer <= 0.0 { c.offspring_count = c.genes.litter(&self.config); }
+                let oc = c.offspring_count.max(1) as f32;
+                let gest_e = self.config.gestation_base_cost_per_sec + self.config.gestation_cost_per_offspring_per_sec * oc;
+                let ramp = self.config.pregnancy.cost_mult(c, self.config.gestation_period);
+                c.apply_energy(LedgerCat::Gestation, -gest_e * ramp * metab * dt * 60.0, debt_floor);
+                c.gestation_timer += dt * 60.0;
+                if self.config.pregnancy.miscarries(c) {
+                    // A mother run down below the threshold loses the litter
+                    let progress = (c.gestation_timer / self.config.gestation_period.max(1.0)).min(1.0);
+                    push_event(&mut self.events, WorldEvent::Miscarriage { tick: self.tick, id: c.id.clone(), offspring: c.offspring_count.max(1), progress });
+                    c.recent_actions.push(self.tick, CreatureAction::Miscarried, oc);
+                    c.is_pregnant = false;
+                    c.gestation_timer = 0.0;
+                    c.sire_id = None;
+                    c.postpartum_timer = self.config.postpartum_cooldown.max(0.0);
+                } else if c.gestation_timer >= self.config.gestation_period {
+                    // Birth energy cost
+                    c.apply_energy(LedgerCat::Birth, -self.config.birth_event_cost_energy, debt_floor);
+                    // Spawn offspring near parent with small jitter; bigger litters mean smaller newborns
+                    let share = oc.powf(-self.config.litter_size_tradeoff.max(0.0));
+                    let newborn_energy = self.config.newborn_energy * share;
+                    let newborn_radius = (self.config.newborn_radius * share.sqrt()).max(1.0);
+                    let mut mutation = 0.0;
+                    // The sire (two-sex mode) or else the nearest same-diet partner is the mate for crossover and inbreeding
+                    let sire = c.sire_id.take().and_then(|id| left.iter().chain(rest.iter()).find(|o| o.id == id));
+                    let mate = if self.config.crossover.mode != CrossoverMode::None || self.config.inbreeding.enabled { sire.or_else(|| nearest_mate(c, left, rest)) } else { None };
+                    let related = mate.map_or(0.0, |m| relatedness(c, m, &self.config.inbreeding));
+                    let vitals = self.config.inbreeding.vitals_mult(related);
+                    for k in 0..c.offspring_count.max(1) {
+                        let aquatic = c.genes.swim_skill >= self.config.aquatic_swim_skill;
+                        let (nx, ny) = birth_spot(&mut self.rng, (c.x, c.y), (self.width, self.height), k, aquatic, self.config.birth_placement_attempts);
+                        let id = creature::take_creature_id(&mut self.next_creature_id);
+                        let diet = c.diet; // inherit diet
+                        let (mut genes, gene_change) = c.genes.inherit(&mut self.rng, &self.config);
+                        if self.config.sexes.enabled {
+                            let sire_ornament = sire.map_or(c.genes.ornament, |s| s.genes.ornament);
+                            genes.ornament = self.config.sexes.inherit_ornament(&mut self.rng, c.genes.ornament, sire_ornament);
+                        }
+                        if self.config.growth.enabled { genes.adult_size = self.config.growth.inherit_size(&mut self.rng, c.genes.adult_size); }
+                        let inherited = if self.config.inherit_brains && c.brain.layer_sizes.first() == Some(&input_len) {
+                            let mate = if self.config.crossover.mode != CrossoverMode::None { mate } else { None };
+                            let mate_brain = mate.map(|m| heritable_brain(m, &self.config.plasticity));
+                            let parent_brain = heritable_brain(c, &self.config.plasticity);
+                            inherit_brain(&parent_brain, mate_brain.as_deref(), c.generation + 1, &self.config, &mut self.rng, &self.bad_brain_hashes)
+                        } else { None };
+                        let (brain, brain_change) = inherited.unwrap_or_else(|| {
+                            (init_brain_avoiding_bad(layer_sizes.clone(), &mut self.rng, &self.bad_brain_hashes, self.config.legacy_brain_hash), 0.0)
+                        });
+                        mutation += gene_change + brain_change;
+                        let vx = self.rng.uniform(-0.5, 0.5);
+                        let vy = self.rng.uniform(-0.5, 0.5);
+                        newborns.push(Creature {
+                            vx, vy, radius: newborn_radius, energy: newborn_energy * vitals, health: 100.0 * vitals, genes,
+                            generation: c.generation + 1, lineage: c.lineage.clone(), parent_id: Some(c.id.clone()), inbreeding: related,
+                            plant_share: c.plant_share, // weaned on the parent's diet
+                            ..Creature::new(id, nx, ny, diet, brain)
+                        });
+                        c.metrics.offspring += 1;
+                    }
+                    c.recent_actions.push(self.tick, CreatureAction::GaveBirth, c.offspring_count.max(1) as f32);
+                    // Mutation energy cost from the litter's realized mutations
+                    let mut_cost = self.config.mutation_cost_energy_base + self.config.mutation_cost_per_std_change * mutation;
+                    c.apply_energy(LedgerCat::Mutation, -mut_cost, debt_floor);
+                    // Reset pregnancy
+                    c.is_pregnant = false;
+                    c.gestation_timer = 0.0;
+                    c.postpartum_timer = self.config.postpartum_cooldown.max(0.0);
+                }
+            }
+            wrap(&mut c.x, self.width);
+            wrap(&mut c.y, self.height);
+            // Clamp vital ranges
+            c.energy = c.energy.clamp(debt_floor, 100.0);
+            c.health = c.health.clamp(0.0, 100.0);
+            // Age increment (ticks)
+            c.lifespan = c.lifespan.saturating_add(1);
+            c.fitness = fitness(c, &self.config);
+            if let (Some(base), Some(rec)) = (baseline, self.transitions.get_mut(&c.id)) {
+                rec.record(self.tick, &inputs, &out, base.reward(c, self.reward_source));
+            }
+            // Only the watched creature keeps activations for visualization (see World::watch_creature)
+            if let Some(w) = self.watch.as_mut().filter(|w| w.follows(&c.id)) {
+                w.begin(self.tick, c, &inputs, &out, &acts);
+                c.brain.activations = Some(acts);
+            }
+            // Feelings telemetry based on thresholds
+            if c.thirst < self.config.thirst_threshold { c.feelings_mask |= FeelingFlag::Thirsty.mask(); }
+            if c.energy < self.config.hunger_energy_threshold { c.feelings_mask |= FeelingFlag::Hungry.mask(); }
+            if c.stamina < self.config.fatigue_stamina_threshold { c.feelings_mask |= FeelingFlag::Fatigued.mask(); }
+            // Restless: track stagnant ticks based on speed
+            let speed = (c.vx * c.vx + c.vy * c.vy).sqrt();
+            if speed < self.config.movement_threshold { c.stagnant_ticks = c.stagnant_ticks.saturating_add(1); } else { c.stagnant_ticks = 0; }
+            if c.stagnant_ticks >= self.config.stagnant_ticks_limit { c.feelings_mask |= FeelingFlag::Restless.mask(); }
+            t_mark = lap(&mut self.perf.reproduction_ms, t_mark);
+        }
+        for (to, received) in gifts { self.creatures[to].apply_energy(LedgerCat::Share, received, 0.0); }
+        for (to, damage) in bitten { self.creatures[to].harm(DeathCause::Predation, damage); }
+        // Hand feeding landed before the pass (and before the audit's opening balance); only its
+        // ledger credit is still owed
+        for (id, fed) in std::mem::take(&mut self.hand_fed) {
+            if let Some(c) = self.creatures.iter_mut().find(|c| c.id == id) { c.ledger.0[LedgerCat::Intake as usize] += fed; }
+        }
+        self.resolve_harvest(&bites, dt * 60.0);
+        self.resolve_food_bites(&food_bites, dt * 60.0);
+        self.update_food(dt * 60.0);
+        self.germinate(dt * 60.0);
+        self.update_blooms();
+        self.feed_on_corpses(&meat_eaters, dt * 60.0);
+        self.grow_juveniles(dt * 60.0);
+        self.spread_disease(dt * 60.0);
+        // Append any newborn creatures after processing all current ones
+        if !newborns.is_empty() {
+            for c in &newborns { self.highlights.on_birth(self.tick, c); }
+            self.flows.births += newborns.iter().map(|c| c.energy).sum::<f32>();
+            self.births_total += newborns.len() as u64;
+            self.tally.births += newborns.len() as u32;
+            self.creatures.extend(newborns);
+        }
+        self.credit_kills(starve_at);
+        // Remove dead into corpses
+        let mut alive = Vec::with_capacity(self.creatures.len());
+        for mut c in std::mem::take(&mut self.creatures) {
+            if (c.health <= 0.0 || c.energy <= starve_at) && self.is_protected_elite(&c.id) {
+                c.health = c.health.max(1.0);
+                // Energy from outside the ledger: an explicit audit inflow
+                self.flows.elite_rescue += (1.0 - c.energy).max(0.0);
+                c.energy = c.energy.max(1.0);
+            }
+            if c.health <= 0.0 || c.energy <= starve_at {
+                if let Some(rec) = self.transitions.get_mut(&c.id) { rec.finish(); }
+                self.custom_inputs.forget(&c.id);
+                self.archive_novelty(&c);
+                self.offer_elite(&c);
+                self.deaths_total += 1;
+                self.highlights.on_death(self.tick, &c);
+                let cause = if c.energy <= starve_at { DeathCause::Starvation } else { c.harm_cause };
+                self.tally.deaths.record(cause);
+                push_event(&mut self.events, WorldEvent::Death { tick: self.tick, id: c.id.clone(), cause, generation: c.generation, lifespan: c.lifespan });
+                let decay_time = (self.config.corpse_decay_time_per_radius * c.radius).max(1.0);
+                let body = self.config.corpse_energy_per_radius.max(0.0) * c.radius;
+                self.flows.record_death(&c, body);
+                self.corpses.push(Corpse{
+                    x: c.x, y: c.y, radius: c.radius,
+                    energy_remaining: body + c.energy.max(0.0),
+                    initial_decay_time: decay_time,
+                    decay_timer: decay_time,
+                    last_decay_total: 0.0,
+                    last_decay_base: 0.0,
+                    last_decay_temp: 0.0,
+                    last_decay_humid: 0.0,
+                    last_decay_rain: 0.0,
+                    last_decay_wet: 0.0,
+                    carried_by: None,
+                });
+            } else {
+                alive.push(c);
+            }
+        }
+        self.creatures = alive;
+        self.highlights.after_step(self.tick, &self.creatures, self.config.extinction_warning_threshold);
+        self.record_trails();
+        self.accumulate_density(dt * 60.0);
+        self.sample_capacity(dt * 60.0);
+        self.track_migration(dt * 60.0);
+        self.update_carrying(&carriers);
+        t_mark = lap(&mut self.perf.reproduction_ms, t_mark);
+        self.update_decomposers(dt * 60.0);
+        // Decay corpses
+        // NOTE: Rate = base + sum(component contributions). Each component is a fraction of base
+        // controlled by its coefficient and an environmental scalar.
+        for co in &mut self.corpses {
+            // Loose corpses drift with the current until they strand on a bank
+            if co.carried_by.is_none() {
+                let (cx, cy) = self.config.water_currents.at(co.y, self.height);
+                co.x += cx * dt * 60.0;
+                co.y = (co.y + cy * dt * 60.0).clamp(0.0, self.height);
+                wrap(&mut co.x, self.width);
+            }
+            let temp_c = self.climate.temperature_c(co.x, co.y, self.tick);
+            let humid01 = self.climate.humidity01(co.x, co.y, self.tick);
+            let rain01 = sample_rain01(co.x, co.y, self.tick);
+            let wet01 = self.climate.wetness01(co.x, co.y, self.tick);
+            // Componentized decay rate contributions (per second)
+            let base = self.config.corpse_base_decay_per_sec.max(0.0);
+            let temp_term = (temp_c - 20.0) / 15.0; // >0 when warmer than ~room temp
+            // Warmer-than-room temperature accelerates decay; clamp 0..2 for stability
+            let contrib_temp = base * self.config.corpse_temp_decay_coeff * temp_term.clamp(0.0, 2.0);
+            let contrib_humid = base * self.config.corpse_humidity_decay_coeff * humid01.max(0.0);
+            let contrib_rain = base * self.config.corpse_rain_decay_coeff * rain01.max(0.0);
+            let contrib_wet = base * self.config.corpse_wetness_decay_coeff * wet01.max(0.0);
+            let rate = (base + contrib_temp + contrib_humid + contrib_rain + contrib_wet).max(0.0);
+            // Telemetry record
+            co.last_decay_base = base;
+            co.last_decay_temp = contrib_temp.max(0.0);
+            co.last_decay_humid = contrib_humid.max(0.0);
+            co.last_decay_rain = contrib_rain.max(0.0);
+            co.last_decay_wet = contrib_wet.max(0.0);
+            co.last_decay_total = rate;
+            co.decay_timer -= dt * 60.0 * rate;
+            if co.decay_timer < 0.0 { co.decay_timer = 0.0; }
+        }
+        self.flows.corpse_decay += self.corpses.iter().filter(|c| c.decay_timer <= 0.0).map(|c| c.energy_remaining).sum::<f32>();
+        // What rots away untouched is left as litter for decomposers
+        for co in self.corpses.iter().filter(|c| c.decay_timer <= 0.0) { self.soil.add_litter(co.x, co.y, co.energy_remaining.max(0.0)); }
+        self.corpses.retain(|c| c.decay_timer > 0.0);
+        lap(&mut self.perf.corpse_decay_ms, t_mark);
+        let novelty_every = self.config.novelty.period_ticks as u64;
+        if self.config.novelty.enabled && novelty_every > 0 && self.tick.is_multiple_of(novelty_every) { self.update_novelty(); }
+        let elite_every = self.config.elitism.period_ticks as u64;
+        if elite_every > 0 && self.tick.is_multiple_of(elite_every) { self.run_elitism(); }
+        let balance_every = self.config.auto_balance.interval_ticks as u64;
+        if self.config.auto_balance.enabled && balance_every > 0 && self.tick.is_multiple_of(balance_every) { self.auto_balance(); }
+        let prune_every = self.config.prune_every_ticks as u64;
+        if prune_every > 0 && self.tick.is_multiple_of(prune_every) { self.prune_brains(); }
+        let every = self.config.snapshot_every_ticks as u64;
+        if every > 0 && self.tick.is_multiple_of(every) { self.push_snapshot(); }
+        if self.config.state_hash_history > 0 {
+            let h = self.compute_state_hash();
+            self.state_hashes.push_back((self.tick, h));
+            while self.state_hashes.len() > self.config.state_hash_history as usize { self.state_hashes.pop_front(); }
+        }
+        self.finish_energy_audit();
+        if cfg!(feature = "invariant-checks") { self.check_invariants(); }
+        self.finish_watch();
+        self.external_outputs.clear();
+        if let Some(g) = self.ghost.as_mut() { g.advance(dt); }
+    }
+
+    fn timed_msgpack<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, String> {
+        let t0 = now_ms();
+        let bytes = rmp_serde::to_vec_named(value).map_err(|e| format!("could not encode MessagePack: {}", e));
+        self.perf.serialization_ms.set(self.perf.serialization_ms.get() + (now_ms() - t0));
+        bytes
+    }
+}
+
+// Ghost minus main differences (see World::ghost_divergence)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GhostDivergence {
+    pub tick: u64,
+    pub creatures_delta: i64,
+    pub herbivores_delta: i64,
+    pub carnivores_delta: i64,
+    pub plants_delta: i64,
+    pub corpses_delta: i64,
+    pub births_delta: i64,
+    pub deaths_delta: i64,
+    pub mean_energy_delta: f32,
+    pub mean_health_delta: f32,
+    pub main: WorldStats,
+    pub ghost: WorldStats,
+}
+
+// Step-phase timing totals (see World::perf)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PerfReport {
+    pub steps: u64,
+    pub sensing_ms: f64,
+    pub brain_ms: f64,
+    pub movement_ms: f64,
+    pub environment_ms: f64,
+    pub reproduction_ms: f64,
+    pub corpse_decay_ms: f64,
+    pub serialization_ms: f64,
+    pub total_step_ms: f64,
+}
+
+// Result of World::prune_brains
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneReport {
+    pub brains_touched: u32,
+    pub neurons_pruned: u32,
+    pub weights_zeroed: u32,
+}
+
+// Result of World::benchmark
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkReport {
+    pub ticks: u32,
+    pub population: u32,
+    pub final_population: u32,
+    pub elapsed_ms: f64,
+    pub ticks_per_sec: f64,
+    pub perf: PerfReport,
+}
+
+// Approximate bytes per collection (see World::memory_report)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryReport {
+    pub creatures: usize, // inline structs only; see creature_heap and brains
+    #[serde(default)]
+    pub creature_heap: usize, // ids, learned weights, action logs, degenerate-brain windows
+    pub brains: usize,
+    pub plants: usize,
+    pub corpses: usize,
+    pub burrows: usize,
+    #[serde(default)]
+    pub food: usize, // loose food items and decomposers
+    pub grids: usize,
+    pub history: usize, // rewind snapshots and dt log
+    #[serde(default)]
+    pub commands: usize, // pending and applied commands, per-tick state hashes
+    #[serde(default)]
+    pub events: usize,
+    #[serde(default)]
+    pub elites: usize,
+    #[serde(default)]
+    pub archives: usize, // highlights, novelty archive, RL transitions
+    #[serde(default)]
+    pub logs: usize, // carrying-capacity and migration logs
+    pub bad_brain_hashes: usize,
+    #[serde(default)]
+    pub front_buffer: usize, // Config::double_buffered_exports copy
+    #[serde(default)]
+    pub trails: usize,
+    #[serde(default)]
+    pub ghost: usize, // the whole ghost world, when one is running
+    pub total: usize,
+}
+
+// Periodic population summary (stats_json / run_headless)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldStats {
+    pub tick: u64,
+    pub creatures: u32,
+    pub herbivores: u32,
+    pub carnivores: u32,
+    pub plants: u32,
+    pub corpses: u32,
+    pub burrows: u32,
+    pub mean_energy: f32,
+    pub mean_health: f32,
+    pub mean_age_ticks: f32,
+    pub births_total: u64,
+    pub deaths_total: u64,
+    // Novelty search (zero unless config.novelty.enabled)
+    #[serde(default)]
+    pub mean_novelty: f32,
+    #[serde(default)]
+    pub novelty_archive_size: u32,
+    // Mean relatedness of living creatures' parents (founder effects, Config::inbreeding)
+    #[serde(default)]
+    pub mean_inbreeding: f32,
+    // Predation: chases started, kills (in-engine credits plus record_kill), and kills per attempt (lifetime totals)
+    #[serde(default)]
+    pub attack_attempts_total: u64,
+    #[serde(default)]
+    pub kills_total: u64,
+    #[serde(default)]
+    pub predation_success_rate: f32,
+    // Currently infected creatures (Config::disease)
+    #[serde(default)]
+    pub infected: u32,
+    // Realized diet (Config::diet_specialization): carnivores' mean plant share of intake, and the mean
+    // specialization of all creatures (0 = even plant/meat mix, 1 = a single food)
+    #[serde(default)]
+    pub carnivore_plant_share: f32,
+    #[serde(default)]
+    pub mean_diet_specialization: f32,
+    // Decomposer colonies (Config::decomposers)
+    #[serde(default)]
+    pub decomposers: u32,
+    // Food items lying around and the energy they hold (Config::food_items)
+    #[serde(default)]
+    pub food_items: u32,
+    #[serde(default)]
+    pub food_energy: f32,
+}
+
+impl World {
+    fn stats_summary(&self) -> WorldStats {
+        let n = self.creatures.len();
+        let herbivores = self.creatures.iter().filter(|c| c.diet == Diet::Herbivore).count();
+        let mean = |f: &dyn Fn(&Creature) -> f32| if n == 0 { 0.0 } else { self.creatures.iter().map(f).sum::<f32>() / n as f32 };
+        WorldStats {
+            tick: self.tick,
+            creatures: n as u32,
+            herbivores: herbivores as u32,
+            carnivores: (n - herbivores) as u32,
+            plants: self.plants.len() as u32,
+            corpses: self.corpses.len() as u32,
+            burrows: self.burrows.len() as u32,
+            mean_energy: mean(&|c| c.energy),
+            mean_health: mean(&|c| c.health),
+            mean_age_ticks: mean(&|c| c.lifespan as f32),
+            births_total: self.births_total,
+            deaths_total: self.deaths_total,
+            mean_novelty: mean(&|c| c.novelty),
+            novelty_archive_size: self.novelty_archive.len() as u32,
+            mean_inbreeding: mean(&|c| c.inbreeding),
+            attack_attempts_total: self.attack_attempts_total,
+            kills_total: self.kills_total,
+            predation_success_rate: if self.attack_attempts_total == 0 { 0.0 } else { self.kills_total as f32 / self.attack_attempts_total as f32 },
+            infected: self.creatures.iter().filter(|c| c.infected).count() as u32,
+            carnivore_plant_share: if n == herbivores { 0.0 } else {
+                self.creatures.iter().filter(|c| c.diet == Diet::Carnivore).map(|c| c.plant_share).sum::<f32>() / (n - herbivores) as f32
+            },
+            mean_diet_specialization: mean(&|c| specialization(c.plant_share)),
+            decomposers: self.decomposers.len() as u32,
+            food_items: self.food.len() as u32,
+            food_energy: self.food.iter().map(|f| f.energy).sum(),
+        }
+    }
+
+    fn run_headless_reports(&mut self, ticks: u32, report_every: u32) -> Vec<WorldStats> {
+        let every = report_every.max(1);
+        let mut reports = Vec::with_capacity((ticks / every) as usize + 1);
+        for i in 1..=ticks {
+            self.advance(1.0 / 60.0);
+            if i % every == 0 || i == ticks { reports.push(self.stats_summary()); }
+        }
+        reports
+    }
+}
+
+fn wrap(v: &mut f32, max: f32) {
+    if *v > max { *v = 0.0; } else if *v < 0.0 { *v = max; }
+}
+
+// Scale (x, y) down to length `max` if it is longer
+fn clamp_magnitude(x: f32, y: f32, max: f32) -> (f32, f32) {
+    let len = (x * x + y * y).sqrt();
+    if len > max { (x * max / len, y * max / len) } else { (x, y) }
+}
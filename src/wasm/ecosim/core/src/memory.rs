@@ -0,0 +1,66 @@
+// Short-term spatial memory: engine-maintained recollections of where a creature last saw food and
+// last fought, fed to the brain with a fading strength so feed-forward brains can return to them
+
+use serde::{Deserialize, Serialize};
+
+// Memory settings (serialized as the `spatialMemory` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SpatialMemoryConfig {
+    pub enabled: bool,
+    pub sight_radius: f32,  // plants and corpses within this distance are remembered as food
+    pub half_life_sec: f32, // strength halves every this many seconds without a refresh
+}
+
+impl Default for SpatialMemoryConfig {
+    fn default() -> Self { Self { enabled: false, sight_radius: 60.0, half_life_sec: 10.0 } }
+}
+
+// One remembered location; strength is 1 when recorded and fades toward 0
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MemorySlot {
+    pub x: f32,
+    pub y: f32,
+    pub strength: f32,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SpatialMemory {
+    pub food: Option<MemorySlot>,
+    pub attack: Option<MemorySlot>, // target when attacking, attacker when attacked
+}
+
+// Slots fainter than this are forgotten
+const FORGET_BELOW: f32 = 0.01;
+
+impl SpatialMemory {
+    pub fn is_empty(&self) -> bool { self.food.is_none() && self.attack.is_none() }
+
+    pub(crate) fn slots(&self) -> [Option<MemorySlot>; 2] { [self.food, self.attack] }
+
+    // Fade both slots by `t_sec` seconds, then remember the nearest food spot (plant, food item or
+    // corpse) within sight
+    pub(crate) fn update(&mut self, x: f32, y: f32, spots: impl Iterator<Item = (f32, f32)>, cfg: &SpatialMemoryConfig, t_sec: f32) {
+        let fade = if cfg.half_life_sec > 0.0 { 0.5f32.powf(t_sec / cfg.half_life_sec) } else { 0.0 };
+        for slot in [&mut self.food, &mut self.attack] {
+            if let Some(m) = slot {
+                m.strength *= fade;
+                if m.strength < FORGET_BELOW { *slot = None; }
+            }
+        }
+        let sight2 = cfg.sight_radius * cfg.sight_radius;
+        let seen = spots.map(|(px, py)| (px, py, (px - x) * (px - x) + (py - y) * (py - y)))
+            .filter(|&(_, _, d2)| d2 <= sight2)
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+        if let Some((fx, fy, _)) = seen { self.food = Some(MemorySlot { x: fx, y: fy, strength: 1.0 }); }
+    }
+
+    pub(crate) fn remember_attack(&mut self, x: f32, y: f32) {
+        self.attack = Some(MemorySlot { x, y, strength: 1.0 });
+    }
+}
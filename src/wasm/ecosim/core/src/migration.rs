@@ -0,0 +1,163 @@
+// Seasonal migration: on top of the global yearly cycle, the two halves of the map swing in
+// opposition (the north is warmer and greener in its summer while the south sits in a lean winter,
+// and the other way round half a year later), so the best place to live moves through the year.
+// Each creature remembers the best region it has foraged in; that memory and the time of year are
+// fed to the brain so migration routes can evolve. Net movement between the map quadrants is
+// tallied per season. Needs seasons; the tallies are not part of snapshots.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::{Creature, Diet, LedgerCat};
+use crate::memory::MemorySlot;
+use crate::seasons::{Season, SeasonConfig};
+use crate::World;
+
+// Quadrant order used by QuadrantMovement arrays (north is the top of the map)
+pub const QUADRANTS: [&str; 4] = ["northWest", "northEast", "southWest", "southEast"];
+
+// Remembered regions fainter than this are forgotten
+const FORGET_BELOW: f32 = 0.01;
+
+// Migration settings (serialized as the `migration` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MigrationConfig {
+    pub enabled: bool,
+    pub temp_swing_c: f32,         // extra seasonal temperature swing at the map edges, opposite in each half
+    pub growth_swing: f32,         // plant growth multiplier runs 1 - swing .. 1 + swing from winter to summer half
+    pub region_size: f32,          // remembered regions are square cells this wide
+    pub forage_window_sec: f32,    // smoothing of the feeding rate that rates a region
+    pub region_half_life_sec: f32, // a remembered region's rating halves every this many seconds away from it
+    pub history_len: u32,          // seasons of quadrant movement kept
+}
+
+impl Default for MigrationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, temp_swing_c: 8.0, growth_swing: 0.6, region_size: 150.0, forage_window_sec: 30.0,
+            region_half_life_sec: 600.0, history_len: 16,
+        }
+    }
+}
+
+// Per-creature migration state (Creature::migration)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MigrationState {
+    pub forage_rate: f32,                 // smoothed intake as a fraction of the diet's top feeding rate
+    pub best_region: Option<MemorySlot>,  // centre of the best region remembered; strength is its rating
+    pub quadrant: Option<u8>,             // quadrant at the last tally (index into QUADRANTS)
+}
+
+impl MigrationState {
+    pub fn is_empty(&self) -> bool { self.forage_rate == 0.0 && self.best_region.is_none() && self.quadrant.is_none() }
+}
+
+// Quadrant crossings during one season (or the one under way); arrays follow QUADRANTS
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QuadrantMovement {
+    pub season: Option<Season>,
+    pub start_tick: u64,
+    pub end_tick: u64,
+    pub crossings: [[u32; 4]; 4], // [from][to]
+    pub net: [i32; 4],            // arrivals minus departures
+    pub population: [u32; 4],     // at end_tick
+}
+
+#[derive(Default)]
+pub(crate) struct MigrationLog {
+    current: Option<QuadrantMovement>,
+    history: VecDeque<QuadrantMovement>,
+}
+
+impl MigrationLog {
+    pub(crate) fn heap_bytes(&self) -> usize { self.history.capacity() * std::mem::size_of::<QuadrantMovement>() }
+}
+
+impl MigrationConfig {
+    // How far toward the top edge (1) or bottom edge (-1) `y` lies, times the yearly warmth cycle
+    fn swing(&self, seasons: &SeasonConfig, y: f32, height: f32, tick: u64) -> f32 {
+        if !self.enabled { return 0.0; }
+        let north = (1.0 - 2.0 * y / height.max(1.0)).clamp(-1.0, 1.0);
+        north * seasons.warmth(tick)
+    }
+
+    pub(crate) fn temp_offset_c(&self, seasons: &SeasonConfig, y: f32, height: f32, tick: u64) -> f32 {
+        self.temp_swing_c * self.swing(seasons, y, height, tick)
+    }
+
+    pub(crate) fn growth_mult(&self, seasons: &SeasonConfig, y: f32, height: f32, tick: u64) -> f32 {
+        (1.0 + self.growth_swing.clamp(0.0, 1.0) * self.swing(seasons, y, height, tick)).max(0.0)
+    }
+
+    // Fold this tick's intake into the forage rate, then remember the current region if it rates
+    // better than the remembered one (revisiting the remembered region re-rates it)
+    fn remember(&self, c: &mut Creature, top_rate: f32, t_sec: f32) {
+        let rate = if t_sec > 0.0 { c.ledger.0[LedgerCat::Intake as usize] / t_sec / top_rate.max(1e-6) } else { 0.0 };
+        let m = &mut c.migration;
+        m.forage_rate += (rate.clamp(0.0, 1.0) - m.forage_rate) * (t_sec / self.forage_window_sec.max(1e-3)).min(1.0);
+        let cell = self.region_size.max(1.0);
+        let (rx, ry) = (((c.x / cell).floor() + 0.5) * cell, ((c.y / cell).floor() + 0.5) * cell);
+        let fade = if self.region_half_life_sec > 0.0 { 0.5f32.powf(t_sec / self.region_half_life_sec) } else { 0.0 };
+        match &mut m.best_region {
+            Some(r) if r.x == rx && r.y == ry => r.strength = m.forage_rate,
+            Some(r) if r.strength * fade >= m.forage_rate => r.strength *= fade,
+            _ => m.best_region = Some(MemorySlot { x: rx, y: ry, strength: m.forage_rate }),
+        }
+        if m.best_region.is_some_and(|r| r.strength < FORGET_BELOW) { m.best_region = None; }
+    }
+}
+
+fn quadrant(x: f32, y: f32, width: f32, height: f32) -> u8 {
+    (y >= height * 0.5) as u8 * 2 + (x >= width * 0.5) as u8
+}
+
+impl World {
+    // Finished seasons of quadrant movement, oldest first, then the season under way
+    pub fn migration_history(&self) -> Vec<QuadrantMovement> {
+        self.migration.history.iter().chain(self.migration.current.as_ref()).cloned().collect()
+    }
+
+    // Update every creature's remembered region and tally quadrant crossings, closing the season's
+    // record when the season turns
+    pub(crate) fn track_migration(&mut self, t_sec: f32) {
+        let cfg = self.config.migration;
+        if !cfg.enabled {
+            if self.migration.current.is_some() || !self.migration.history.is_empty() {
+                self.migration = MigrationLog::default();
+                for c in &mut self.creatures { c.migration = MigrationState::default(); }
+            }
+            return;
+        }
+        let (tick, season) = (self.tick, self.config.seasons.season_at(self.tick));
+        let log = &mut self.migration;
+        if log.current.as_ref().is_some_and(|m| m.season != season) {
+            let done = log.current.take().expect("checked above");
+            while !log.history.is_empty() && log.history.len() >= cfg.history_len as usize { log.history.pop_front(); }
+            if cfg.history_len > 0 { log.history.push_back(done); }
+        }
+        let m = log.current.get_or_insert(QuadrantMovement {
+            season, start_tick: tick, end_tick: tick, crossings: [[0; 4]; 4], net: [0; 4], population: [0; 4],
+        });
+        m.end_tick = tick;
+        m.population = [0; 4];
+        for c in &mut self.creatures {
+            let top_rate = if c.diet == Diet::Herbivore { self.config.harvest_rate_per_sec } else { self.config.meat_intake_per_sec };
+            cfg.remember(c, top_rate, t_sec);
+            let q = quadrant(c.x, c.y, self.width, self.height);
+            if let Some(from) = c.migration.quadrant.filter(|&from| from != q) {
+                m.crossings[from as usize][q as usize] += 1;
+                m.net[from as usize] -= 1;
+                m.net[q as usize] += 1;
+            }
+            c.migration.quadrant = Some(q);
+            m.population[q as usize] += 1;
+        }
+    }
+}
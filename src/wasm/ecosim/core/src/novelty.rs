@@ -0,0 +1,127 @@
+// Novelty search: per-creature behaviour descriptors scored against a descriptor archive
+
+use serde::{Serialize, Deserialize};
+
+use crate::creature::Creature;
+use crate::World;
+
+const COVERAGE_GRID: f32 = 8.0; // 8x8 cells tracked in a u64 bitmask
+const HEADING_SECTORS: usize = 8;
+
+// Raw behaviour observations accumulated over a creature's life
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BehaviorTrace {
+    visited: u64,
+    headings: [u32; HEADING_SECTORS],
+    pub(crate) plant_intake: f32,
+    pub(crate) meat_intake: f32,
+}
+
+impl BehaviorTrace {
+    // `nx`/`ny` are the position as fractions of the world size
+    pub(crate) fn observe_motion(&mut self, nx: f32, ny: f32, vx: f32, vy: f32, min_speed: f32) {
+        let gx = (nx * COVERAGE_GRID).clamp(0.0, COVERAGE_GRID - 1.0) as u32;
+        let gy = (ny * COVERAGE_GRID).clamp(0.0, COVERAGE_GRID - 1.0) as u32;
+        self.visited |= 1u64 << (gy * COVERAGE_GRID as u32 + gx);
+        if (vx * vx + vy * vy).sqrt() > min_speed {
+            let turn = (vy.atan2(vx) + std::f32::consts::PI) / std::f32::consts::TAU;
+            self.headings[((turn * HEADING_SECTORS as f32) as usize).min(HEADING_SECTORS - 1)] += 1;
+        }
+    }
+
+    // [position coverage, plant share of intake, normalized heading entropy], each in 0..1
+    pub fn descriptor(&self) -> [f32; 3] {
+        let coverage = self.visited.count_ones() as f32 / (COVERAGE_GRID * COVERAGE_GRID);
+        let intake = self.plant_intake + self.meat_intake;
+        let plant_share = if intake > 0.0 { self.plant_intake / intake } else { 0.5 };
+        let moves: u32 = self.headings.iter().sum();
+        let entropy = if moves == 0 { 0.0 } else {
+            -self.headings.iter().filter(|&&n| n > 0)
+                .map(|&n| { let p = n as f32 / moves as f32; p * p.ln() })
+                .sum::<f32>() / (HEADING_SECTORS as f32).ln()
+        };
+        [coverage, plant_share, entropy]
+    }
+}
+
+// Novelty search settings (serialized as the `novelty` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoveltyConfig {
+    pub enabled: bool,
+    pub k: u32,                // nearest neighbours averaged for the score
+    pub period_ticks: u32,     // how often living creatures are re-scored
+    pub archive_threshold: f32, // dying creatures at least this novel join the archive
+    pub archive_cap: u32,      // oldest entries drop first
+    pub fitness_weight: f32,   // novelty added to selection fitness (fitness units per descriptor unit)
+}
+
+impl Default for NoveltyConfig {
+    fn default() -> Self {
+        Self { enabled: false, k: 10, period_ticks: 600, archive_threshold: 0.15, archive_cap: 500, fitness_weight: 0.0 }
+    }
+}
+
+// Per-creature behaviour descriptor and latest novelty score (World::behavior_descriptors)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BehaviorDescriptor {
+    pub id: String,
+    pub coverage: f32,
+    pub plant_share: f32,
+    pub movement_entropy: f32,
+    pub novelty: f32,
+}
+
+fn dist(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum::<f32>().sqrt()
+}
+
+// Mean distance to the k nearest descriptors in `others` (excluding `skip`, the descriptor's own index)
+fn knn_score(d: &[f32; 3], others: &[[f32; 3]], skip: Option<usize>, k: usize) -> f32 {
+    let mut ds: Vec<f32> = others.iter().enumerate()
+        .filter(|(i, _)| Some(*i) != skip)
+        .map(|(_, o)| dist(d, o))
+        .collect();
+    if ds.is_empty() { return 0.0; }
+    ds.sort_by(f32::total_cmp);
+    let k = k.clamp(1, ds.len());
+    ds[..k].iter().sum::<f32>() / k as f32
+}
+
+impl World {
+    // Re-score every living creature against the population plus the archive
+    pub(crate) fn update_novelty(&mut self) {
+        let k = self.config.novelty.k as usize;
+        let n = self.creatures.len();
+        let mut pool: Vec<[f32; 3]> = self.creatures.iter().map(|c| c.behavior.descriptor()).collect();
+        pool.extend(self.novelty_archive.iter().copied());
+        for (i, c) in self.creatures.iter_mut().enumerate() {
+            c.novelty = knn_score(&pool[i], &pool, Some(i), k);
+        }
+        log::debug!("novelty: scored {} creatures against {} archived", n, self.novelty_archive.len());
+    }
+
+    // Archive a dying creature's descriptor when it is novel enough
+    pub(crate) fn archive_novelty(&mut self, c: &Creature) {
+        let nc = &self.config.novelty;
+        if !nc.enabled || nc.archive_cap == 0 { return; }
+        let d = c.behavior.descriptor();
+        let score = if self.novelty_archive.is_empty() { f32::INFINITY } else { knn_score(&d, &self.novelty_archive, None, nc.k as usize) };
+        if score < nc.archive_threshold { return; }
+        self.novelty_archive.push(d);
+        let cap = nc.archive_cap as usize;
+        if self.novelty_archive.len() > cap { let excess = self.novelty_archive.len() - cap; self.novelty_archive.drain(..excess); }
+    }
+
+    pub fn behavior_descriptors(&self) -> Vec<BehaviorDescriptor> {
+        self.creatures.iter().map(|c| {
+            let [coverage, plant_share, movement_entropy] = c.behavior.descriptor();
+            BehaviorDescriptor { id: c.id.clone(), coverage, plant_share, movement_entropy, novelty: c.novelty }
+        }).collect()
+    }
+}
@@ -0,0 +1,49 @@
+use std::cell::Cell;
+use std::sync::OnceLock;
+
+// Accumulated wall-clock time per step phase (ms), exposed via perf_json()
+#[derive(Default)]
+pub(crate) struct PerfCounters {
+    pub(crate) steps: u64,
+    pub(crate) sensing_ms: f64,
+    pub(crate) brain_ms: f64,
+    pub(crate) movement_ms: f64,
+    pub(crate) environment_ms: f64,
+    pub(crate) reproduction_ms: f64,
+    pub(crate) corpse_decay_ms: f64,
+    // Export getters take &self, so serialization time accumulates through a Cell
+    pub(crate) serialization_ms: Cell<f64>,
+}
+
+// Millisecond clock used for perf timings. Hosts without std::time (the browser) install
+// their own via set_clock; otherwise an Instant-based clock is used natively.
+static CLOCK: OnceLock<fn() -> f64> = OnceLock::new();
+
+// Install the host clock once; later calls are ignored
+pub fn set_clock(clock: fn() -> f64) {
+    let _ = CLOCK.set(clock);
+}
+
+pub fn now_ms() -> f64 {
+    match CLOCK.get() {
+        Some(clock) => clock(),
+        None => default_now_ms(),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn default_now_ms() -> f64 { 0.0 }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_now_ms() -> f64 {
+    use std::time::Instant;
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
+// Add elapsed time since `since` to an accumulator and return the new mark
+pub(crate) fn lap(acc: &mut f64, since: f64) -> f64 {
+    let now = now_ms();
+    *acc += now - since;
+    now
+}
@@ -0,0 +1,83 @@
+// Within-lifetime Hebbian/Oja plasticity. Learned changes are tracked per creature
+// (Creature::learned_weights) on top of the birth weights so they can decay back or be withheld
+// from offspring.
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::brain::Brain;
+use crate::creature::Creature;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum PlasticityRule {
+    Hebbian, // dw = lr * post * pre
+    #[default]
+    Oja,     // dw = lr * post * (pre - post * w); self-normalizing
+}
+
+// Plasticity settings (serialized as the `plasticity` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PlasticityConfig {
+    pub enabled: bool,
+    pub rule: PlasticityRule,
+    pub learning_rate: f32, // per second
+    pub decay: f32,         // fraction of the learned change lost per second (pulls back to birth weights)
+    pub heritable: bool,    // offspring inherit learned weights instead of the parent's birth weights
+    pub max_weight: f32,    // |w| cap after each update
+}
+
+impl Default for PlasticityConfig {
+    fn default() -> Self {
+        Self { enabled: false, rule: PlasticityRule::Oja, learning_rate: 0.01, decay: 0.0, heritable: false, max_weight: 4.0 }
+    }
+}
+
+// One plasticity update from this tick's activations (input layer first), scaled by t_sec
+pub(crate) fn apply_plasticity(c: &mut Creature, acts: &[Vec<f32>], cfg: &PlasticityConfig, t_sec: f32) {
+    let Some(weights) = c.brain.weights.as_mut() else { return; };
+    // Topology changed (pruning, growth, import): treat the current weights as the new birth weights
+    let shape_ok = c.learned_weights.len() == weights.len()
+        && c.learned_weights.iter().zip(weights.iter()).all(|(d, w)| d.len() == w.len());
+    if !shape_ok { c.learned_weights = weights.iter().map(|w| vec![0.0; w.len()]).collect(); }
+    let lr = cfg.learning_rate * t_sec;
+    let keep = (1.0 - cfg.decay * t_sec).clamp(0.0, 1.0);
+    let cap = cfg.max_weight.abs();
+    for (li, (w, d)) in weights.iter_mut().zip(c.learned_weights.iter_mut()).enumerate() {
+        let (Some(pre), Some(post)) = (acts.get(li), acts.get(li + 1)) else { break; };
+        let n_in = pre.len();
+        if n_in == 0 || w.len() != n_in * post.len() { continue; }
+        for (o, &y) in post.iter().enumerate() {
+            for (i, &x) in pre.iter().enumerate() {
+                let k = o * n_in + i;
+                let innate = w[k] - d[k];
+                let dw = match cfg.rule {
+                    PlasticityRule::Hebbian => y * x,
+                    PlasticityRule::Oja => y * (x - y * w[k]),
+                };
+                let learned = d[k] * keep + lr * dw;
+                if !learned.is_finite() { continue; }
+                w[k] = (innate + learned).clamp(-cap, cap);
+                d[k] = w[k] - innate;
+            }
+        }
+    }
+}
+
+// Brain passed to offspring: as lived when learning is heritable, otherwise the birth weights
+pub(crate) fn heritable_brain<'a>(c: &'a Creature, cfg: &PlasticityConfig) -> Cow<'a, Brain> {
+    if cfg.heritable || c.learned_weights.is_empty() { return Cow::Borrowed(&c.brain); }
+    let mut brain = Brain { activations: None, ..c.brain.clone() };
+    if let Some(weights) = brain.weights.as_mut() {
+        if weights.len() != c.learned_weights.len() { return Cow::Borrowed(&c.brain); }
+        for (w, d) in weights.iter_mut().zip(&c.learned_weights) {
+            if w.len() != d.len() { continue; }
+            for (wk, dk) in w.iter_mut().zip(d) { *wk -= dk; }
+        }
+    }
+    Cow::Owned(brain)
+}
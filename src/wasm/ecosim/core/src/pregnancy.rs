@@ -0,0 +1,42 @@
+// Visible pregnancy: as gestation progresses the mother slows down and her gestation costs climb,
+// and if her energy drops below a threshold she loses the litter (WorldEvent::Miscarriage).
+// Reproduction then costs mobility and food, not just a lump of energy at birth.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Creature;
+
+// Pregnancy settings (serialized as the `pregnancy` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PregnancyConfig {
+    pub enabled: bool,
+    pub speed_loss: f32,         // fraction of movement speed lost at term, growing with progress
+    pub cost_ramp: f32,          // gestation costs rise from 1x at conception to (1 + cost_ramp)x at term
+    pub miscarriage_energy: f32, // the pregnancy ends without a birth once energy falls below this
+}
+
+impl Default for PregnancyConfig {
+    fn default() -> Self { Self { enabled: false, speed_loss: 0.4, cost_ramp: 1.0, miscarriage_energy: 10.0 } }
+}
+
+impl PregnancyConfig {
+    // Fraction of gestation_period elapsed (0 when not pregnant or when the effects are off)
+    fn progress(&self, c: &Creature, period: f32) -> f32 {
+        if !self.enabled || !c.is_pregnant { return 0.0; }
+        (c.gestation_timer / period.max(1.0)).clamp(0.0, 1.0)
+    }
+
+    pub(crate) fn speed_mult(&self, c: &Creature, period: f32) -> f32 {
+        1.0 - self.speed_loss.clamp(0.0, 1.0) * self.progress(c, period)
+    }
+
+    pub(crate) fn cost_mult(&self, c: &Creature, period: f32) -> f32 {
+        1.0 + self.cost_ramp.max(0.0) * self.progress(c, period)
+    }
+
+    pub(crate) fn miscarries(&self, c: &Creature) -> bool {
+        self.enabled && c.is_pregnant && c.energy < self.miscarriage_energy
+    }
+}
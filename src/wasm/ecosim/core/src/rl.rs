@@ -0,0 +1,140 @@
+// Reinforcement-learning hook: per-creature (inputs, outputs, reward, next inputs) transitions for
+// external learners, which push trained weights back through World::set_creature_brain
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Creature;
+use crate::World;
+
+// Completed transitions kept per creature between collect_transitions calls (oldest dropped first)
+const TRANSITION_CAP: usize = 4096;
+
+// Per-tick reward signal
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum RewardSource {
+    #[default]
+    Energy,   // energy change over the tick
+    Health,   // health change over the tick
+    Fitness,  // change in weighted fitness (Config::fitness)
+    Survival, // 1 per tick survived
+}
+
+impl RewardSource {
+    pub fn parse(mode: &str) -> Option<Self> {
+        match mode.to_ascii_lowercase().as_str() {
+            "energy" => Some(Self::Energy),
+            "health" => Some(Self::Health),
+            "fitness" => Some(Self::Fitness),
+            "survival" => Some(Self::Survival),
+            _ => None,
+        }
+    }
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Transition {
+    pub tick: u64,
+    pub inputs: Vec<f32>,
+    pub outputs: Vec<f32>,
+    pub reward: f32,
+    // Inputs at the following tick; equal to `inputs` when `done`
+    pub next_inputs: Vec<f32>,
+    pub done: bool, // the creature died during this tick
+}
+
+// Vitals at the start of a tick, for computing that tick's reward
+#[derive(Clone, Copy)]
+pub(crate) struct RewardBaseline {
+    energy: f32,
+    health: f32,
+    fitness: f32,
+}
+
+impl RewardBaseline {
+    pub(crate) fn of(c: &Creature) -> Self { Self { energy: c.energy, health: c.health, fitness: c.fitness } }
+
+    pub(crate) fn reward(&self, c: &Creature, source: RewardSource) -> f32 {
+        match source {
+            RewardSource::Energy => c.energy - self.energy,
+            RewardSource::Health => c.health - self.health,
+            RewardSource::Fitness => c.fitness - self.fitness,
+            RewardSource::Survival => 1.0,
+        }
+    }
+}
+
+// Recording state for one selected creature. The latest transition stays pending until the next
+// tick's inputs (or the creature's death) complete it.
+#[derive(Default)]
+pub(crate) struct TransitionRecorder {
+    pending: Option<Transition>,
+    ready: VecDeque<Transition>,
+}
+
+impl TransitionRecorder {
+    pub(crate) fn heap_bytes(&self) -> usize {
+        let vectors = |t: &Transition| (t.inputs.capacity() + t.outputs.capacity() + t.next_inputs.capacity()) * std::mem::size_of::<f32>();
+        self.ready.capacity() * std::mem::size_of::<Transition>() + self.ready.iter().chain(&self.pending).map(vectors).sum::<usize>()
+    }
+
+    pub(crate) fn record(&mut self, tick: u64, inputs: &[f32], outputs: &[f32], reward: f32) {
+        self.pending = Some(Transition { tick, inputs: inputs.to_vec(), outputs: outputs.to_vec(), reward, next_inputs: Vec::new(), done: false });
+    }
+
+    pub(crate) fn complete(&mut self, next_inputs: &[f32]) {
+        if let Some(mut t) = self.pending.take() {
+            t.next_inputs = next_inputs.to_vec();
+            self.push(t);
+        }
+    }
+
+    pub(crate) fn finish(&mut self) {
+        if let Some(mut t) = self.pending.take() {
+            t.next_inputs = t.inputs.clone();
+            t.done = true;
+            self.push(t);
+        }
+    }
+
+    fn push(&mut self, t: Transition) {
+        if self.ready.len() >= TRANSITION_CAP { self.ready.pop_front(); }
+        self.ready.push_back(t);
+    }
+}
+
+impl World {
+    // Reward used for recorded transitions: "energy" | "health" | "fitness" | "survival"; Err (and
+    // unchanged) for anything else
+    pub fn set_reward_source(&mut self, mode: &str) -> Result<(), String> {
+        let source = RewardSource::parse(mode).ok_or_else(|| format!("unknown mode '{}' (expected energy, health, fitness or survival)", mode))?;
+        self.reward_source = source;
+        Ok(())
+    }
+
+    pub fn reward_source(&self) -> RewardSource { self.reward_source }
+
+    // Completed transitions for `id` since the last call, oldest first. The first call selects a
+    // living creature for recording (and returns nothing); recording ends once it dies and its
+    // final transitions have been collected.
+    pub fn collect_transitions(&mut self, id: &str) -> Vec<Transition> {
+        let alive = self.creatures.iter().any(|c| c.id == id);
+        match self.transitions.get_mut(id) {
+            Some(rec) => {
+                let out: Vec<Transition> = rec.ready.drain(..).collect();
+                if !alive { self.transitions.remove(id); }
+                out
+            }
+            None => {
+                if alive { self.transitions.insert(id.to_string(), TransitionRecorder::default()); }
+                else { log::warn!("collect_transitions: no creature '{}'", id); }
+                Vec::new()
+            }
+        }
+    }
+}
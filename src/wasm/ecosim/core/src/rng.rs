@@ -0,0 +1,14 @@
+// Deterministic LCG RNG (32-bit state)
+pub(crate) struct RngLCG { pub(crate) state: u32 }
+impl RngLCG {
+    pub(crate) fn new(seed: u32) -> Self { let s = if seed == 0 { 0xDEADBEEF } else { seed }; Self { state: s } }
+    pub(crate) fn next_u32(&mut self) -> u32 { self.state = self.state.wrapping_mul(1664525).wrapping_add(1013904223); self.state }
+    pub(crate) fn next_f32_01(&mut self) -> f32 { (self.next_u32() as f32) / 4294967296.0 }
+    pub(crate) fn uniform(&mut self, min: f32, max: f32) -> f32 { min + (max - min) * self.next_f32_01() }
+    // Standard normal sample (Box-Muller)
+    pub(crate) fn normal(&mut self) -> f32 {
+        let u1 = self.next_f32_01().max(1e-7);
+        let u2 = self.next_f32_01();
+        (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+    }
+}
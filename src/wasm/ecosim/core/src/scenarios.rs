@@ -0,0 +1,108 @@
+// Built-in curriculum scenarios: config overrides, terrain, and spawn layouts that set up one
+// task coherently so brain modes can be compared on it (World::load_scenario)
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::brain::init_brain_avoiding_bad;
+use crate::config::{merge_config, Config};
+use crate::creature::{take_creature_id, Creature, Diet, Genes, Plant};
+use crate::rng::RngLCG;
+use crate::World;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenarioInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+pub const SCENARIOS: [ScenarioInfo; 4] = [
+    ScenarioInfo { name: "foodGradient", description: "Herbivores start on the barren left; plant density rises linearly to the right" },
+    ScenarioInfo { name: "predatorGauntlet", description: "Herbivores must cross a band of carnivores to reach food on the far side" },
+    ScenarioInfo { name: "seasonalScarcity", description: "Sparse food with a narrow comfort band; temperature swings cost energy unless sheltered" },
+    ScenarioInfo { name: "mazeWorld", description: "Steep, rugged terrain forms ridges between a start corner and food in the opposite corner" },
+];
+
+// Config overrides applied on top of the base config (Config::default() unless given)
+fn overrides(name: &str) -> serde_json::Value {
+    match name {
+        "predatorGauntlet" => json!({ "attackCostPerHitEnergy": 0.02, "attackDamagePerSec": 4.0 }),
+        "seasonalScarcity" => json!({
+            "tempColdPenaltyPerSec": 0.05, "tempHeatPenaltyPerSec": 0.05,
+            "comfortLowC": 16.0, "comfortHighC": 24.0,
+        }),
+        "mazeWorld" => json!({
+            "noiseScale": 0.008, "noiseOctaves": 2, "noisePersistence": 0.35,
+            "slopeSpeedPenalty": 8.0, "slopeUphillCostPerSec": 0.02, "slopeDownhillBoost": 0.5,
+        }),
+        _ => json!({}),
+    }
+}
+
+// Uniform point in the given fractions of the map (y kept off the water bands)
+fn point_in(rng: &mut RngLCG, w: f32, h: f32, x: (f32, f32), y: (f32, f32)) -> (f32, f32) {
+    let y = (y.0.max(0.15), y.1.min(0.85));
+    (rng.uniform(x.0, x.1) * w, rng.uniform(y.0, y.1) * h)
+}
+
+impl World {
+    pub fn scenarios() -> &'static [ScenarioInfo] { &SCENARIOS }
+
+    // Reset into a built-in scenario (see SCENARIOS) on the default config; Err (and unchanged)
+    // for an unknown name. Layouts come from a stream seeded by the world seed, so each brain mode
+    // sees the same map.
+    pub fn load_scenario(&mut self, name: &str) -> Result<(), String> { self.load_scenario_on(name, Config::default()) }
+
+    // load_scenario with the scenario's overrides applied to `base` instead of the default config.
+    // Never the current config: a previous scenario's overrides would leak into this one.
+    pub fn load_scenario_on(&mut self, name: &str, base: Config) -> Result<(), String> {
+        if !SCENARIOS.iter().any(|s| s.name == name) {
+            let names: Vec<&str> = SCENARIOS.iter().map(|s| s.name).collect();
+            return Err(format!("unknown scenario '{}' (expected one of: {})", name, names.join(", ")));
+        }
+        let cfg = merge_config(&base, &overrides(name))?;
+        self.set_config(cfg);
+        self.clear_entities();
+        self.rng = RngLCG::new(self.seed);
+        let mut layout = RngLCG::new(self.seed ^ 0x5CE4_A210);
+        let (w, h) = (self.width, self.height);
+        let mut spawns: Vec<(f32, f32, Diet)> = Vec::new();
+        let mut plants: Vec<(f32, f32)> = Vec::new();
+        match name {
+            "foodGradient" => {
+                for _ in 0..60 { let (x, y) = point_in(&mut layout, w, h, (0.0, 0.2), (0.0, 1.0)); spawns.push((x, y, Diet::Herbivore)); }
+                // sqrt of a uniform draw: density grows linearly with x
+                for _ in 0..240 { let x = layout.next_f32_01().sqrt() * w; let (_, y) = point_in(&mut layout, w, h, (0.0, 1.0), (0.0, 1.0)); plants.push((x, y)); }
+            }
+            "predatorGauntlet" => {
+                for _ in 0..50 { let (x, y) = point_in(&mut layout, w, h, (0.0, 0.1), (0.0, 1.0)); spawns.push((x, y, Diet::Herbivore)); }
+                for _ in 0..15 { let (x, y) = point_in(&mut layout, w, h, (0.35, 0.65), (0.0, 1.0)); spawns.push((x, y, Diet::Carnivore)); }
+                for _ in 0..120 { plants.push(point_in(&mut layout, w, h, (0.85, 1.0), (0.0, 1.0))); }
+            }
+            "seasonalScarcity" => {
+                for _ in 0..50 {
+                    let diet = if layout.next_f32_01() > 0.8 { Diet::Carnivore } else { Diet::Herbivore };
+                    let (x, y) = point_in(&mut layout, w, h, (0.0, 1.0), (0.0, 1.0));
+                    spawns.push((x, y, diet));
+                }
+                for _ in 0..50 { plants.push(point_in(&mut layout, w, h, (0.0, 1.0), (0.0, 1.0))); }
+            }
+            _ => {
+                for _ in 0..50 { let (x, y) = point_in(&mut layout, w, h, (0.0, 0.15), (0.0, 0.3)); spawns.push((x, y, Diet::Herbivore)); }
+                for _ in 0..100 { plants.push(point_in(&mut layout, w, h, (0.85, 1.0), (0.7, 1.0))); }
+            }
+        }
+        let layer_sizes = self.brain_layer_sizes();
+        for (x, y, diet) in spawns {
+            let brain = init_brain_avoiding_bad(layer_sizes.clone(), &mut self.rng, &self.bad_brain_hashes, self.config.legacy_brain_hash);
+            let genes = Genes::founder(self.seed, self.next_creature_id, &self.config);
+            let id = take_creature_id(&mut self.next_creature_id);
+            self.creatures.push(Creature { genes, ..Creature::new(id, x, y, diet, brain) });
+        }
+        self.plants.extend(plants.into_iter().map(|(x, y)| Plant { x, y, radius: 3.0, biomass: self.config.plant_max_biomass, bloom_until: None }));
+        log::info!("load_scenario: {} ({} creatures, {} plants)", name, self.creatures.len(), self.plants.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+// Scent field: plants and corpses emit smell into a coarse grid that diffuses and evaporates each
+// tick; creatures sense its local gradient, which makes food detectable from beyond sight range
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::{Corpse, Plant};
+
+// Scent settings (serialized as the `scent` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScentConfig {
+    pub enabled: bool,
+    pub cell_size: f32,       // world units per grid cell
+    pub diffusion: f32,       // fraction exchanged with neighbouring cells per second
+    pub evaporation: f32,     // fraction lost per second
+    pub plant_emission: f32,  // scent per second per plant
+    pub corpse_emission: f32, // scent per second per corpse (carcasses smell stronger)
+}
+
+impl Default for ScentConfig {
+    fn default() -> Self {
+        Self { enabled: false, cell_size: 20.0, diffusion: 0.5, evaporation: 0.1, plant_emission: 1.0, corpse_emission: 4.0 }
+    }
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScentField {
+    cols: u32,
+    rows: u32,
+    cell: f32,
+    values: Vec<f32>,
+}
+
+impl ScentField {
+    pub(crate) fn heap_bytes(&self) -> usize { self.values.capacity() * std::mem::size_of::<f32>() }
+
+    fn index(&self, x: f32, y: f32) -> usize {
+        let gx = ((x / self.cell).max(0.0) as u32).min(self.cols - 1);
+        let gy = ((y / self.cell).max(0.0) as u32).min(self.rows - 1);
+        (gy * self.cols + gx) as usize
+    }
+
+    // Scent in the cell containing (x, y); 0 before the first update
+    pub(crate) fn sample(&self, x: f32, y: f32) -> f32 {
+        if self.values.is_empty() { return 0.0; }
+        self.values[self.index(x, y)]
+    }
+
+    // Emit, diffuse, and evaporate for `t_sec` seconds. The grid is (re)allocated empty when the
+    // world size or cell size changes.
+    pub(crate) fn update(&mut self, width: f32, height: f32, plants: &[Plant], corpses: &[Corpse], cfg: &ScentConfig, t_sec: f32) {
+        let cell = cfg.cell_size.max(1.0);
+        let cols = (width / cell).ceil().max(1.0) as u32;
+        let rows = (height / cell).ceil().max(1.0) as u32;
+        if self.cols != cols || self.rows != rows || self.cell != cell {
+            *self = Self { cols, rows, cell, values: vec![0.0; (cols * rows) as usize] };
+        }
+        for p in plants { let i = self.index(p.x, p.y); self.values[i] += cfg.plant_emission * t_sec; }
+        for c in corpses { let i = self.index(c.x, c.y); self.values[i] += cfg.corpse_emission * t_sec; }
+        // Explicit 4-neighbour diffusion, rate capped for stability; edges reflect
+        let rate = (cfg.diffusion * t_sec).clamp(0.0, 0.25);
+        let keep = (1.0 - cfg.evaporation * t_sec).clamp(0.0, 1.0);
+        let (w, h) = (cols as usize, rows as usize);
+        let old = &self.values;
+        let mut next = Vec::with_capacity(old.len());
+        for y in 0..h {
+            for x in 0..w {
+                let v = old[y * w + x];
+                let l = if x > 0 { old[y * w + x - 1] } else { v };
+                let r = if x + 1 < w { old[y * w + x + 1] } else { v };
+                let u = if y > 0 { old[(y - 1) * w + x] } else { v };
+                let d = if y + 1 < h { old[(y + 1) * w + x] } else { v };
+                next.push((v + rate * (l + r + u + d - 4.0 * v)) * keep);
+            }
+        }
+        self.values = next;
+    }
+
+    // Relative scent gradient at (x, y) (change across neighbouring cells over the local level),
+    // squashed into -1..1; zero where there is no scent
+    pub(crate) fn gradient(&self, x: f32, y: f32) -> (f32, f32) {
+        if self.values.is_empty() { return (0.0, 0.0); }
+        let c = self.cell;
+        let gx = self.sample(x + c, y) - self.sample(x - c, y);
+        let gy = self.sample(x, y + c) - self.sample(x, y - c);
+        let level = self.sample(x, y) + 0.01;
+        ((gx / level).tanh(), (gy / level).tanh())
+    }
+}
@@ -0,0 +1,109 @@
+// Seasons: a yearly temperature swing on top of the climate model, and plant growth that follows
+// it. Regrowth and seed germination scale with local temperature (nothing grows on frozen ground)
+// and get a boost in spring, so vegetation rises and falls in waves the rest of the food chain
+// has to track.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Plant;
+use crate::environment::in_water_at;
+use crate::World;
+
+// Season settings (serialized as the `seasons` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SeasonConfig {
+    pub enabled: bool,
+    pub year_ticks: u32,
+    pub temp_amplitude_c: f32,    // peak seasonal temperature offset (mid-summer +, mid-winter -)
+    pub frost_c: f32,             // plants don't grow at or below this temperature
+    pub growth_optimum_c: f32,    // full growth from this temperature up (linear ramp from frost_c)
+    pub spring_growth_mult: f32,
+    pub germination_per_sec: f32, // seeding chance per second of each fully grown plant
+    pub seed_spread: f32,         // max distance of a seedling from its parent
+    pub max_plants: u32,          // germination stops at this many plants
+}
+
+impl Default for SeasonConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, year_ticks: 60 * 60 * 20, temp_amplitude_c: 10.0, frost_c: 0.0, growth_optimum_c: 18.0,
+            spring_growth_mult: 1.5, germination_per_sec: 0.002, seed_spread: 24.0, max_plants: 400,
+        }
+    }
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum Season { Spring, Summer, Autumn, Winter }
+
+impl SeasonConfig {
+    // Fraction of the year elapsed at `tick` (0 = start of spring)
+    pub(crate) fn phase(&self, tick: u64) -> f32 {
+        let year = self.year_ticks.max(1) as u64;
+        (tick % year) as f32 / year as f32
+    }
+
+    pub(crate) fn season_at(&self, tick: u64) -> Option<Season> {
+        if !self.enabled { return None; }
+        Some(match (self.phase(tick) * 4.0) as u32 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        })
+    }
+
+    // Yearly warmth cycle: 1 mid-summer, -1 mid-winter (0 while seasons are off)
+    pub(crate) fn warmth(&self, tick: u64) -> f32 {
+        if !self.enabled { return 0.0; }
+        f32::sin(std::f32::consts::TAU * (self.phase(tick) - 0.125))
+    }
+
+    // Seasonal temperature offset; warmest mid-summer, coldest mid-winter
+    pub(crate) fn temp_offset_c(&self, tick: u64) -> f32 {
+        self.temp_amplitude_c * self.warmth(tick)
+    }
+
+    // Plant regrowth/germination multiplier at `temp_c` (1 while seasons are off)
+    pub(crate) fn growth_mult(&self, temp_c: f32, tick: u64) -> f32 {
+        if !self.enabled { return 1.0; }
+        let span = (self.growth_optimum_c - self.frost_c).max(0.01);
+        let warmth = ((temp_c - self.frost_c) / span).clamp(0.0, 1.0);
+        let spring = if self.season_at(tick) == Some(Season::Spring) { self.spring_growth_mult.max(0.0) } else { 1.0 };
+        warmth * spring
+    }
+}
+
+impl World {
+    pub(crate) fn plant_growth_at(&self, x: f32, y: f32) -> f32 {
+        let seasons = &self.config.seasons;
+        let season = if seasons.enabled { seasons.growth_mult(self.climate.temperature_c(x, y, self.tick), self.tick) } else { 1.0 };
+        season * self.config.migration.growth_mult(seasons, y, self.height, self.tick) * self.soil_growth_at(x, y)
+    }
+
+    // Fully grown plants drop seeds that sprout (with no biomass yet) nearby on dry land, at a
+    // rate scaled by the growth conditions at the seedling's spot
+    pub(crate) fn germinate(&mut self, t_sec: f32) {
+        let seasons = &self.config.seasons;
+        if !seasons.enabled || seasons.germination_per_sec <= 0.0 { return; }
+        let (rate, spread, cap) = (seasons.germination_per_sec * t_sec, seasons.seed_spread.max(0.0), seasons.max_plants as usize);
+        let full = self.config.plant_max_biomass;
+        let parents: Vec<(f32, f32)> = self.plants.iter().filter(|p| p.biomass >= full && p.bloom_until.is_none()).map(|p| (p.x, p.y)).collect();
+        for (px, py) in parents {
+            if self.plants.len() >= cap { break; }
+            if self.rng.next_f32_01() >= rate { continue; }
+            let angle = self.rng.next_f32_01() * std::f32::consts::TAU;
+            let r = self.rng.next_f32_01() * spread;
+            let x = (px + angle.cos() * r).rem_euclid(self.width.max(1.0));
+            let y = (py + angle.sin() * r).clamp(0.0, self.height);
+            if in_water_at(y, self.height) || self.rng.next_f32_01() >= self.plant_growth_at(x, y) { continue; }
+            self.plants.push(Plant { x, y, radius: 3.0, biomass: 0.0, bloom_until: None });
+        }
+    }
+
+    // Current season; None while config.seasons is off
+    pub fn season(&self) -> Option<Season> { self.config.seasons.season_at(self.tick) }
+}
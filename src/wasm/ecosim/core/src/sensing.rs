@@ -0,0 +1,520 @@
+// Brain input layout: optional sense blocks and host-supplied channels (World::set_custom_inputs)
+// appended after the brain mode's built-in senses, and the named channel schema
+// (World::brain_io_schema)
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::aging::SenescenceConfig;
+use crate::brain::{Brain, BrainMode};
+use crate::creature::{Corpse, Creature, Diet, Plant};
+use crate::environment::{ground_speed, in_water_at, HostGrid};
+use crate::food::FoodItem;
+use crate::rng::RngLCG;
+use crate::scent::ScentField;
+use crate::seasons::SeasonConfig;
+use crate::World;
+
+// Nearest targets reported per category by multi-target sensing, and values per target
+const TARGETS_PER_CATEGORY: usize = 3;
+const TARGET_CATEGORIES: [&str; 4] = ["herbivore", "carnivore", "plant", "corpse"];
+const TARGET_FIELDS: [&str; 4] = ["DirX", "DirY", "Dist", "Size"];
+const MULTI_TARGET_LEN: u32 = (TARGET_CATEGORIES.len() * TARGETS_PER_CATEGORY * TARGET_FIELDS.len()) as u32;
+// Nearest plant, corpse, and water: direction and distance each; then the thirst level
+const RESOURCE_TARGETS: [&str; 3] = ["plant", "corpse", "water"];
+const RESOURCE_LEN: u32 = (RESOURCE_TARGETS.len() * 3 + 1) as u32;
+// Spatial memory slots (in SpatialMemory::slots order) and values per slot
+const MEMORY_SLOTS: [&str; 2] = ["memFood", "memAttack"];
+const MEMORY_FIELDS: [&str; 4] = ["DirX", "DirY", "Dist", "Strength"];
+const MEMORY_LEN: u32 = (MEMORY_SLOTS.len() * MEMORY_FIELDS.len()) as u32;
+// Size reported for food items by multi-target sensing (a small plant)
+const FOOD_ITEM_SIZE: f32 = 2.0;
+// Scent gradient x and y
+const SCENT_LEN: u32 = 2;
+// Remembered best region (direction, distance, rating), then the time of year as sin/cos
+const MIGRATION_LEN: u32 = 6;
+
+// Brain I/O layout. Inputs: the brain mode's senses, then each enabled optional block, then custom
+// channels. Outputs: the brain mode's actions, then each enabled optional action.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct IoLayout {
+    pub(crate) mode: BrainMode,
+    pub(crate) resources: bool,
+    pub(crate) memory: bool,
+    pub(crate) scent: bool,
+    pub(crate) multi_target: bool,
+    pub(crate) migration: bool,
+    pub(crate) custom: u32,
+    pub(crate) carry: bool,
+    pub(crate) torpor: bool,
+}
+
+impl IoLayout {
+    // Block lengths in input order
+    fn blocks(&self) -> [u32; 7] {
+        [
+            self.mode.input_len(),
+            if self.resources { RESOURCE_LEN } else { 0 },
+            if self.memory { MEMORY_LEN } else { 0 },
+            if self.scent { SCENT_LEN } else { 0 },
+            if self.multi_target { MULTI_TARGET_LEN } else { 0 },
+            if self.migration { MIGRATION_LEN } else { 0 },
+            self.custom,
+        ]
+    }
+
+    pub(crate) fn len(&self) -> u32 { self.blocks().iter().sum() }
+
+    // Block lengths in output order
+    fn output_blocks(&self) -> [u32; 3] { [self.mode.output_len(), self.carry as u32, self.torpor as u32] }
+
+    pub(crate) fn output_len(&self) -> u32 { self.output_blocks().iter().sum() }
+
+    // Output index of the CARRY action, when enabled
+    pub(crate) fn carry_output(&self) -> Option<usize> { self.carry.then_some(self.mode.output_len() as usize) }
+
+    // Output index of the TORPOR action, when enabled
+    pub(crate) fn torpor_output(&self) -> Option<usize> { self.torpor.then_some((self.mode.output_len() + self.carry as u32) as usize) }
+}
+
+// Read-only world context for building one tick's inputs
+pub(crate) struct SenseEnv<'a> {
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) tick: u64,
+    pub(crate) layout: IoLayout,
+    pub(crate) plants: &'a [Plant],
+    pub(crate) food: &'a [FoodItem], // sensed as plants
+    pub(crate) corpses: &'a [Corpse],
+    pub(crate) scent: &'a ScentField,
+    pub(crate) speed_grid: Option<&'a HostGrid>,
+    pub(crate) senescence: SenescenceConfig,
+    pub(crate) max_life: f32,
+    pub(crate) seasons: SeasonConfig,
+}
+
+impl SenseEnv<'_> {
+    pub(crate) fn terrain_speed(&self, x: f32, y: f32) -> f32 {
+        ground_speed(self.speed_grid, x, y, (self.width, self.height), self.tick)
+    }
+
+    // Plants and food items
+    fn plant_spots(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.plants.iter().map(|p| (p.x, p.y)).chain(self.food.iter().map(|f| (f.x, f.y)))
+    }
+
+    // How far `c` senses creatures and food (shrinks with age under Config::senescence)
+    pub(crate) fn sight(&self, c: &Creature) -> f32 {
+        self.senescence.vigor(c.age_norm(self.max_life)).perception_range
+    }
+}
+
+// Unit direction and normalized distance to the nearest point within `range`; (0, 0, 1) when there is none
+fn nearest_vector(env: &SenseEnv, c: &Creature, range: f32, points: impl Iterator<Item = (f32, f32)>) -> [f32; 3] {
+    let nearest = points.map(|(x, y)| (x - c.x, y - c.y)).min_by(|p, q| (p.0 * p.0 + p.1 * p.1).total_cmp(&(q.0 * q.0 + q.1 * q.1)));
+    let Some((dx, dy)) = nearest else { return [0.0, 0.0, 1.0]; };
+    let d = (dx * dx + dy * dy).sqrt().max(0.0001);
+    if d > range { return [0.0, 0.0, 1.0]; }
+    [dx / d, dy / d, (d / env.width.max(env.height).max(1.0)).clamp(0.0, 1.0)]
+}
+
+// Direction and distance to the nearest plant, corpse, and water (the shore of the nearer water
+// band; (0, 0, 0) while swimming), then hydration (thirst / 100) to drive water seeking
+pub(crate) fn resource_inputs(env: &SenseEnv, c: &Creature, out: &mut Vec<f32>) {
+    let sight = env.sight(c);
+    out.extend(nearest_vector(env, c, sight, env.plant_spots()));
+    out.extend(nearest_vector(env, c, sight, env.corpses.iter().map(|p| (p.x, p.y))));
+    if in_water_at(c.y, env.height) {
+        out.extend([0.0, 0.0, 0.0]);
+    } else {
+        let shores = [(c.x, env.height * 0.12), (c.x, env.height * 0.88)];
+        out.extend(nearest_vector(env, c, f32::INFINITY, shores.into_iter()));
+    }
+    out.push((c.thirst / 100.0).clamp(0.0, 1.0));
+}
+
+// Direction, distance, and strength of each remembered location; empty slots read (0, 0, 1, 0)
+pub(crate) fn memory_inputs(env: &SenseEnv, c: &Creature, out: &mut Vec<f32>) {
+    for slot in c.memory.slots() {
+        match slot {
+            Some(m) => {
+                out.extend(nearest_vector(env, c, f32::INFINITY, std::iter::once((m.x, m.y))));
+                out.push(m.strength);
+            }
+            None => out.extend([0.0, 0.0, 1.0, 0.0]),
+        }
+    }
+}
+
+// Direction, distance, and rating of the best remembered region ((0, 0, 1, 0) when none), then the
+// time of year ((0, 0) while seasons are off)
+pub(crate) fn migration_inputs(env: &SenseEnv, c: &Creature, out: &mut Vec<f32>) {
+    match c.migration.best_region {
+        Some(r) => {
+            out.extend(nearest_vector(env, c, f32::INFINITY, std::iter::once((r.x, r.y))));
+            out.push(r.strength);
+        }
+        None => out.extend([0.0, 0.0, 1.0, 0.0]),
+    }
+    let year = env.seasons.enabled.then(|| env.seasons.phase(env.tick) * std::f32::consts::TAU);
+    out.extend(year.map_or([0.0, 0.0], |a| [a.sin(), a.cos()]));
+}
+
+// Direction, distance, and size of the three nearest visible herbivores, carnivores, plants, and
+// corpses; empty slots read (0, 0, 1, 0)
+pub(crate) fn multi_target_inputs(env: &SenseEnv, c: &Creature, a: &[Creature], b: &[Creature], out: &mut Vec<f32>) {
+    let visible = |diet: Diet| a.iter().chain(b.iter())
+        .filter(move |o| o.diet == diet && o.burrow_id.is_none())
+        .map(|o| (o.x, o.y, o.radius));
+    let scale = env.width.max(env.height).max(1.0);
+    let sight2 = env.sight(c).powi(2);
+    let mut push_nearest = |targets: &mut dyn Iterator<Item = (f32, f32, f32)>| {
+        let mut best: [(f32, f32, f32, f32); TARGETS_PER_CATEGORY] = [(0.0, 0.0, f32::INFINITY, 0.0); TARGETS_PER_CATEGORY];
+        for (x, y, r) in targets {
+            let (dx, dy) = (x - c.x, y - c.y);
+            let d2 = dx * dx + dy * dy;
+            if d2 > sight2 || d2 >= best[TARGETS_PER_CATEGORY - 1].2 { continue; }
+            let at = best.iter().position(|t| d2 < t.2).unwrap_or(TARGETS_PER_CATEGORY - 1);
+            best.copy_within(at..TARGETS_PER_CATEGORY - 1, at + 1);
+            best[at] = (dx, dy, d2, r);
+        }
+        for (dx, dy, d2, r) in best {
+            if d2.is_finite() {
+                let d = d2.sqrt().max(0.0001);
+                out.extend([dx / d, dy / d, (d / scale).clamp(0.0, 1.0), (r / 10.0).clamp(0.0, 1.0)]);
+            } else {
+                out.extend([0.0, 0.0, 1.0, 0.0]);
+            }
+        }
+    };
+    push_nearest(&mut visible(Diet::Herbivore));
+    push_nearest(&mut visible(Diet::Carnivore));
+    push_nearest(&mut env.plants.iter().map(|p| (p.x, p.y, p.radius)).chain(env.food.iter().map(|f| (f.x, f.y, FOOD_ITEM_SIZE))));
+    push_nearest(&mut env.corpses.iter().map(|p| (p.x, p.y, p.radius)));
+}
+
+#[derive(Default, Clone)]
+pub(crate) struct CustomInputs {
+    pub(crate) count: u32,
+    all: Vec<f32>,
+    by_id: HashMap<String, Vec<f32>>,
+}
+
+impl CustomInputs {
+    pub(crate) fn with_count(count: u32) -> Self { Self { count, ..Self::default() } }
+
+    // Append this creature's channels (its own values, else the shared ones, else zeros)
+    pub(crate) fn append(&self, id: &str, inputs: &mut Vec<f32>) {
+        if self.count == 0 { return; }
+        let n = self.count as usize;
+        let end = inputs.len() + n;
+        inputs.extend(self.by_id.get(id).unwrap_or(&self.all).iter().take(n));
+        inputs.resize(end, 0.0);
+    }
+
+    pub(crate) fn forget(&mut self, id: &str) { self.by_id.remove(id); }
+
+    // Shared values (id ""), then per-creature values in id order (for state hashing)
+    pub(crate) fn for_each_sorted(&self, mut f: impl FnMut(&str, &[f32])) {
+        if self.count == 0 { return; }
+        f("", &self.all);
+        let mut ids: Vec<&String> = self.by_id.keys().collect();
+        ids.sort();
+        for id in ids { f(id, &self.by_id[id]); }
+    }
+}
+
+// Perception error per input index, resolved from Config::sensor_noise for the current layout
+pub(crate) struct SensorNoise {
+    channels: Vec<NoisyChannel>,
+}
+
+struct NoisyChannel {
+    sigma: f32,
+    dropout: f32,
+    min: Option<f32>,
+    max: Option<f32>,
+}
+
+impl SensorNoise {
+    pub(crate) fn apply(&self, inputs: &mut [f32], rng: &mut RngLCG) {
+        for (v, ch) in inputs.iter_mut().zip(&self.channels) {
+            if ch.dropout > 0.0 && rng.next_f32_01() < ch.dropout { *v = 0.0; continue; }
+            if ch.sigma > 0.0 {
+                *v += ch.sigma * rng.normal();
+                if let Some(min) = ch.min { *v = v.max(min); }
+                if let Some(max) = ch.max { *v = v.min(max); }
+            }
+        }
+    }
+}
+
+// Replace `remove` input columns starting at `at` with `insert` new ones. New inputs start at zero
+// weight so existing behaviour is unchanged until mutation or learning picks them up.
+pub(crate) fn splice_inputs(brain: &mut Brain, at: u32, remove: u32, insert: u32) {
+    let Some(&old) = brain.layer_sizes.first() else { return; };
+    if brain.layer_sizes.len() < 2 || at + remove > old { return; }
+    let n_out = brain.layer_sizes[1] as usize;
+    let new = old - remove + insert;
+    if let Some(w) = brain.weights.as_mut().and_then(|w| w.first_mut()) {
+        let (old, at, remove, insert) = (old as usize, at as usize, remove as usize, insert as usize);
+        let mut resized = Vec::with_capacity(n_out * new as usize);
+        for row in w.chunks(old.max(1)).take(n_out) {
+            resized.extend_from_slice(&row[..at]);
+            resized.extend(std::iter::repeat_n(0.0, insert));
+            resized.extend_from_slice(&row[at + remove..]);
+        }
+        *w = resized;
+    }
+    brain.layer_sizes[0] = new;
+    brain.activations = None;
+}
+
+// Replace `remove` output rows starting at `at` with `insert` new ones (zero weights and bias, so
+// new actions stay below their firing threshold until evolved)
+pub(crate) fn splice_outputs(brain: &mut Brain, at: u32, remove: u32, insert: u32) {
+    let n = brain.layer_sizes.len();
+    if n < 2 || at + remove > brain.layer_sizes[n - 1] { return; }
+    let n_in = brain.layer_sizes[n - 2] as usize;
+    let (at, remove, insert) = (at as usize, remove as usize, insert as usize);
+    if let Some(w) = brain.weights.as_mut().and_then(|w| w.last_mut()) {
+        if w.len() >= (at + remove) * n_in {
+            w.splice(at * n_in..(at + remove) * n_in, std::iter::repeat_n(0.0, insert * n_in));
+        }
+    }
+    if let Some(b) = brain.biases.as_mut().and_then(|b| b.last_mut()) {
+        if b.len() >= at + remove { b.splice(at..at + remove, std::iter::repeat_n(0.0, insert)); }
+    }
+    brain.layer_sizes[n - 1] = (brain.layer_sizes[n - 1] as usize - remove + insert) as u32;
+    brain.activations = None;
+}
+
+impl World {
+    pub(crate) fn io_layout(&self) -> IoLayout {
+        IoLayout {
+            mode: self.brain_mode,
+            resources: self.config.resource_sensing,
+            memory: self.config.spatial_memory.enabled,
+            scent: self.config.scent.enabled,
+            multi_target: self.config.multi_target_sensing,
+            migration: self.config.migration.enabled,
+            custom: self.custom_inputs.count,
+            carry: self.config.corpse_carry.enabled,
+            torpor: self.config.torpor.enabled,
+        }
+    }
+
+    // Brain input width: the brain mode's senses, enabled optional senses, and custom channels
+    pub fn input_len(&self) -> u32 { self.io_layout().len() }
+
+    // Re-fit every brain sized for layout `old` to the current one, block by block, so weights on
+    // unchanged blocks survive (e.g. custom channels when a sense block is toggled)
+    pub(crate) fn reconcile_io(&mut self, old: IoLayout) {
+        let new = self.io_layout();
+        if old == new || old.mode != new.mode { return; }
+        let (ob, nb) = (old.blocks(), new.blocks());
+        let (oo, no) = (old.output_blocks(), new.output_blocks());
+        for c in self.creatures.iter_mut().chain(self.elites.iter_mut()) {
+            if c.brain.layer_sizes.first() == Some(&old.len()) {
+                // Last block first so earlier offsets stay valid
+                let mut offset = old.len();
+                for i in (0..ob.len()).rev() {
+                    offset -= ob[i];
+                    if ob[i] != nb[i] { splice_inputs(&mut c.brain, offset, ob[i], nb[i]); }
+                }
+            }
+            if oo != no && c.brain.layer_sizes.last() == Some(&old.output_len()) {
+                let mut offset = old.output_len();
+                for i in (0..oo.len()).rev() {
+                    offset -= oo[i];
+                    if oo[i] != no[i] { splice_outputs(&mut c.brain, offset, oo[i], no[i]); }
+                }
+            }
+        }
+        log::info!("brain I/O re-fitted from {}x{} to {}x{}", old.len(), old.output_len(), new.len(), new.output_len());
+    }
+
+    // Ordered names, ranges, and kinds of every input/output channel for `mode` (the current mode
+    // when None), including this world's custom channels
+    pub fn brain_io_schema(&self, mode: Option<BrainMode>) -> BrainIoSchema {
+        io_schema(IoLayout { mode: mode.unwrap_or(self.brain_mode), ..self.io_layout() })
+    }
+
+    // Noise to apply to this tick's inputs; None when disabled or every channel is noiseless
+    pub(crate) fn sensor_noise(&self) -> Option<SensorNoise> {
+        let cfg = &self.config.sensor_noise;
+        if !cfg.enabled { return None; }
+        let channels: Vec<NoisyChannel> = self.brain_io_schema(None).inputs.into_iter().map(|ch| {
+            if ch.kind == ChannelKind::Constant { return NoisyChannel { sigma: 0.0, dropout: 0.0, min: None, max: None }; }
+            let n = cfg.channels.get(ch.name.as_ref());
+            NoisyChannel {
+                sigma: n.map_or(cfg.sigma, |n| n.sigma).max(0.0),
+                dropout: n.map_or(cfg.dropout, |n| n.dropout).clamp(0.0, 1.0),
+                min: ch.min,
+                max: ch.max,
+            }
+        }).collect();
+        if channels.iter().all(|c| c.sigma == 0.0 && c.dropout == 0.0) { return None; }
+        Some(SensorNoise { channels })
+    }
+
+    // Layer sizes for freshly initialized brains
+    pub(crate) fn brain_layer_sizes(&self) -> Vec<u32> {
+        let hidden = match self.brain_mode { BrainMode::OG => 8, BrainMode::Zegion => 16 };
+        let layout = self.io_layout();
+        vec![layout.len(), hidden, layout.output_len()]
+    }
+
+    // Feed extra input values to one creature (`target` = its id) or to everyone ("*" / "all").
+    // The value count sets the number of custom channels; changing it resizes every brain's input
+    // layer and drops previously supplied values. An empty array for "*" removes the channels.
+    // Err (and unchanged) for an unknown creature id.
+    pub fn set_custom_inputs(&mut self, target: &str, values: &[f32]) -> Result<(), String> {
+        let all = target == "*" || target.eq_ignore_ascii_case("all");
+        if !all && !self.creatures.iter().any(|c| c.id == target) { return Err(format!("no creature '{}'", target)); }
+        if let Some(g) = self.ghost.as_deref_mut() { let _ = g.set_custom_inputs(target, values); }
+        let count = values.len() as u32;
+        if count != self.custom_inputs.count {
+            let old = self.io_layout();
+            self.custom_inputs = CustomInputs::with_count(count);
+            self.reconcile_io(old);
+        }
+        if all { self.custom_inputs.all = values.to_vec(); } else { self.custom_inputs.by_id.insert(target.to_string(), values.to_vec()); }
+        Ok(())
+    }
+}
+
+// What a channel reflects: the creature's own state, its surroundings, a constant, or host data
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum ChannelKind { Internal, External, Constant, Custom }
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IoChannel {
+    pub name: Cow<'static, str>,
+    pub min: Option<f32>, // None: unbounded (custom channels)
+    pub max: Option<f32>,
+    pub kind: ChannelKind,
+}
+
+// Ordered input/output channel layout for a brain mode (World::brain_io_schema)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrainIoSchema {
+    pub mode: BrainMode,
+    pub inputs: Vec<IoChannel>,
+    pub outputs: Vec<IoChannel>,
+}
+
+const fn ch(name: &'static str, min: f32, max: f32, kind: ChannelKind) -> IoChannel {
+    IoChannel { name: Cow::Borrowed(name), min: Some(min), max: Some(max), kind }
+}
+
+// Must match the order built by brain::build_inputs
+const COMMON_INPUTS: [IoChannel; 11] = [
+    ch("posX", 0.0, 1.0, ChannelKind::External),
+    ch("posY", 0.0, 1.0, ChannelKind::External),
+    ch("velX", -1.0, 1.0, ChannelKind::Internal),
+    ch("velY", -1.0, 1.0, ChannelKind::Internal),
+    ch("energy", 0.0, 1.0, ChannelKind::Internal),
+    ch("health", 0.0, 1.0, ChannelKind::Internal),
+    ch("clockSin", -1.0, 1.0, ChannelKind::External),
+    ch("clockCos", -1.0, 1.0, ChannelKind::External),
+    ch("herbivoreDirX", -1.0, 1.0, ChannelKind::External),
+    ch("herbivoreDirY", -1.0, 1.0, ChannelKind::External),
+    ch("herbivoreDist", 0.0, 1.0, ChannelKind::External),
+];
+
+const OG_INPUTS: [IoChannel; 3] = [
+    ch("bias", 1.0, 1.0, ChannelKind::Constant),
+    ch("pad12", 0.0, 0.0, ChannelKind::Constant),
+    ch("pad13", 0.0, 0.0, ChannelKind::Constant),
+];
+
+const ZEGION_INPUTS: [IoChannel; 13] = [
+    ch("carnivoreDirX", -1.0, 1.0, ChannelKind::External),
+    ch("carnivoreDirY", -1.0, 1.0, ChannelKind::External),
+    ch("carnivoreDist", 0.0, 1.0, ChannelKind::External),
+    ch("terrainSpeed", 0.6, 1.0, ChannelKind::External),
+    ch("terrainSpeedNorm", 0.0, 1.0, ChannelKind::External),
+    ch("speed", 0.0, 1.0, ChannelKind::Internal),
+    ch("headingToHerbivore", -std::f32::consts::SQRT_2, std::f32::consts::SQRT_2, ChannelKind::External),
+    ch("headingToCarnivore", -std::f32::consts::SQRT_2, std::f32::consts::SQRT_2, ChannelKind::External),
+    ch("localClockSin", -1.0, 1.0, ChannelKind::External),
+    ch("localClockCos", -1.0, 1.0, ChannelKind::External),
+    ch("isCarnivore", 0.0, 1.0, ChannelKind::Internal),
+    ch("hunger", 0.0, 1.0, ChannelKind::Internal),
+    ch("bias", 1.0, 1.0, ChannelKind::Constant),
+];
+
+// Outputs pass through tanh; the action channels fire above 0.5
+const OUTPUTS: [IoChannel; 8] = [
+    ch("moveX", -1.0, 1.0, ChannelKind::Internal),
+    ch("moveY", -1.0, 1.0, ChannelKind::Internal),
+    ch("thrust", -1.0, 1.0, ChannelKind::Internal),
+    ch("eat", -1.0, 1.0, ChannelKind::Internal),
+    ch("rest", -1.0, 1.0, ChannelKind::Internal),
+    ch("boost", -1.0, 1.0, ChannelKind::Internal),
+    ch("burrow", -1.0, 1.0, ChannelKind::Internal),
+    ch("share", -1.0, 1.0, ChannelKind::Internal),
+];
+
+pub(crate) fn io_schema(layout: IoLayout) -> BrainIoSchema {
+    let mode = layout.mode;
+    let mut inputs = COMMON_INPUTS.to_vec();
+    match mode {
+        BrainMode::OG => inputs.extend_from_slice(&OG_INPUTS),
+        BrainMode::Zegion => inputs.extend_from_slice(&ZEGION_INPUTS),
+    }
+    if layout.resources {
+        for target in RESOURCE_TARGETS {
+            inputs.push(IoChannel { name: Cow::Owned(format!("{}DirX", target)), min: Some(-1.0), max: Some(1.0), kind: ChannelKind::External });
+            inputs.push(IoChannel { name: Cow::Owned(format!("{}DirY", target)), min: Some(-1.0), max: Some(1.0), kind: ChannelKind::External });
+            inputs.push(IoChannel { name: Cow::Owned(format!("{}Dist", target)), min: Some(0.0), max: Some(1.0), kind: ChannelKind::External });
+        }
+        inputs.push(ch("thirst", 0.0, 1.0, ChannelKind::Internal));
+    }
+    if layout.memory {
+        for slot in MEMORY_SLOTS {
+            for field in MEMORY_FIELDS {
+                let min = if field.starts_with("Dir") { -1.0 } else { 0.0 };
+                inputs.push(IoChannel { name: Cow::Owned(format!("{}{}", slot, field)), min: Some(min), max: Some(1.0), kind: ChannelKind::Internal });
+            }
+        }
+    }
+    if layout.scent {
+        inputs.push(ch("scentGradX", -1.0, 1.0, ChannelKind::External));
+        inputs.push(ch("scentGradY", -1.0, 1.0, ChannelKind::External));
+    }
+    if layout.multi_target {
+        for cat in TARGET_CATEGORIES {
+            for k in 1..=TARGETS_PER_CATEGORY {
+                for field in TARGET_FIELDS {
+                    let min = if field.starts_with("Dir") { -1.0 } else { 0.0 };
+                    inputs.push(IoChannel { name: Cow::Owned(format!("{}{}{}", cat, k, field)), min: Some(min), max: Some(1.0), kind: ChannelKind::External });
+                }
+            }
+        }
+    }
+    if layout.migration {
+        inputs.push(ch("bestRegionDirX", -1.0, 1.0, ChannelKind::Internal));
+        inputs.push(ch("bestRegionDirY", -1.0, 1.0, ChannelKind::Internal));
+        inputs.push(ch("bestRegionDist", 0.0, 1.0, ChannelKind::Internal));
+        inputs.push(ch("bestRegionRating", 0.0, 1.0, ChannelKind::Internal));
+        inputs.push(ch("yearSin", -1.0, 1.0, ChannelKind::External));
+        inputs.push(ch("yearCos", -1.0, 1.0, ChannelKind::External));
+    }
+    for i in 0..layout.custom as usize {
+        inputs.push(IoChannel { name: Cow::Owned(format!("custom{}", i)), min: None, max: None, kind: ChannelKind::Custom });
+    }
+    let mut outputs = OUTPUTS[..mode.output_len() as usize].to_vec();
+    if layout.carry { outputs.push(ch("carry", -1.0, 1.0, ChannelKind::Internal)); }
+    if layout.torpor { outputs.push(ch("torpor", -1.0, 1.0, ChannelKind::Internal)); }
+    BrainIoSchema { mode, inputs, outputs }
+}
@@ -0,0 +1,102 @@
+// Two-sex mode: every creature is female or male. Only females gestate; a female ready to conceive
+// mates with the most attractive male of her diet within reach, judged by his health and his
+// heritable ornament. Ornaments cost males energy to display, so sexual selection trades survival
+// against mating success. Off by default: reproduction stays asexual.
+
+use serde::{Deserialize, Serialize};
+
+use crate::action_log::CreatureAction;
+use crate::creature::{mutate_trait, Creature};
+use crate::rng::RngLCG;
+use crate::World;
+
+const ORNAMENT_RANGE: (f32, f32) = (0.0, 5.0);
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum Sex {
+    Female,
+    Male,
+}
+
+// Two-sex settings (serialized as the `sexes` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SexConfig {
+    pub enabled: bool,
+    pub male_fraction: f32,          // chance a creature is male
+    pub mate_reach: f32,             // how far a female looks for a mate
+    pub choosiness: f32,             // attractiveness = health01 * exp(choosiness * ornament)
+    pub display_cost_per_sec: f32,   // male energy cost per unit of ornament
+    pub refractory_period: f32,      // male wait between matings, in gestation_period units
+    pub ornament_mutation_prob: f32, // the ornament gene starts at 0 and evolves from the midparent value
+    pub ornament_mutation_sigma: f32,
+}
+
+impl Default for SexConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, male_fraction: 0.5, mate_reach: 40.0, choosiness: 1.0, display_cost_per_sec: 0.02,
+            refractory_period: 60.0 * 5.0, ornament_mutation_prob: 0.5, ornament_mutation_sigma: 0.1,
+        }
+    }
+}
+
+impl SexConfig {
+    fn attractiveness(&self, male: &Creature) -> f32 {
+        (male.health / 100.0).max(0.0) * (self.choosiness * male.genes.ornament).exp()
+    }
+
+    // Energy per second `c` spends on display (males only)
+    pub(crate) fn display_cost(&self, c: &Creature) -> f32 {
+        if !self.enabled || c.sex != Some(Sex::Male) { return 0.0; }
+        self.display_cost_per_sec.max(0.0) * c.genes.ornament
+    }
+
+    // Offspring ornament: the parents' midpoint with a Gaussian step
+    pub(crate) fn inherit_ornament(&self, rng: &mut RngLCG, mother: f32, sire: f32) -> f32 {
+        mutate_trait(rng, 0.5 * (mother + sire), self.ornament_mutation_prob, self.ornament_mutation_sigma, ORNAMENT_RANGE)
+    }
+}
+
+impl World {
+    // Give sexless creatures (founders, spawns, newborns, restored asexual worlds) a sex, then pair
+    // each female ready to conceive with the most attractive available male in reach
+    pub(crate) fn pair_mates(&mut self) {
+        let cfg = self.config.sexes;
+        if !cfg.enabled { return; }
+        for c in &mut self.creatures {
+            if c.sex.is_none() {
+                c.sex = Some(if self.rng.next_f32_01() < cfg.male_fraction { Sex::Male } else { Sex::Female });
+            }
+            if c.sex == Some(Sex::Male) && c.is_pregnant {
+                c.is_pregnant = false;
+                c.gestation_timer = 0.0;
+            }
+        }
+        let reach2 = cfg.mate_reach.max(0.0).powi(2);
+        let min_age = self.config.gestation_min_age;
+        for i in 0..self.creatures.len() {
+            let f = &self.creatures[i];
+            if f.sex != Some(Sex::Female) || f.torpor_sec > 0.0 || !f.can_conceive(&self.config) { continue; }
+            let sire = self.creatures.iter().enumerate()
+                .filter(|(_, m)| m.sex == Some(Sex::Male) && m.diet == f.diet && m.torpor_sec <= 0.0 && m.postpartum_timer <= 0.0 && m.lifespan >= min_age)
+                .filter(|(_, m)| { let (dx, dy) = (m.x - f.x, m.y - f.y); dx * dx + dy * dy <= reach2 })
+                .map(|(j, m)| (j, cfg.attractiveness(m)))
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(j, _)| j);
+            let Some(j) = sire else { continue };
+            let (female_id, sire_id) = (f.id.clone(), self.creatures[j].id.clone());
+            let m = &mut self.creatures[j];
+            m.postpartum_timer = cfg.refractory_period.max(0.0);
+            m.recent_actions.push(self.tick, CreatureAction::Mated { partner: female_id }, 1.0);
+            let f = &mut self.creatures[i];
+            f.is_pregnant = true;
+            f.gestation_timer = 0.0;
+            f.recent_actions.push(self.tick, CreatureAction::Mated { partner: sire_id.clone() }, 1.0);
+            f.sire_id = Some(sire_id);
+        }
+    }
+}
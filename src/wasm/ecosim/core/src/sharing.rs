@@ -0,0 +1,47 @@
+// Energy sharing: the SHARE output hands a parcel of energy to an adjacent low-energy creature,
+// preferring kin (same founder lineage), for altruism and kin-selection experiments
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Creature;
+
+// Sharing settings (serialized as the `energySharing` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EnergySharingConfig {
+    pub enabled: bool,
+    pub amount: f32,               // energy the donor gives per transfer
+    pub efficiency: f32,           // fraction of `amount` the recipient receives (0..1)
+    pub range: f32,                // max gap between the two bodies
+    pub recipient_max_energy: f32, // only creatures below this energy are helped
+    pub min_donor_energy: f32,     // donors keep at least this much after giving
+    pub kin_bias: f32,             // need multiplier for same-lineage recipients
+    pub cooldown_sec: f32,         // minimum time between a donor's transfers
+}
+
+impl Default for EnergySharingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, amount: 10.0, efficiency: 0.8, range: 6.0, recipient_max_energy: 50.0,
+            min_donor_energy: 40.0, kin_bias: 3.0, cooldown_sec: 1.0,
+        }
+    }
+}
+
+// Neediest eligible neighbour of `donor` across `a` then `b` (index into the concatenation) and
+// whether it is kin. Need is the energy shortfall below `recipient_max_energy`, scaled by
+// `kin_bias` for kin.
+pub(crate) fn pick_recipient(donor: &Creature, a: &[Creature], b: &[Creature], cfg: &EnergySharingConfig) -> Option<(usize, bool)> {
+    let mut best: Option<(usize, bool, f32)> = None;
+    for (i, o) in a.iter().chain(b.iter()).enumerate() {
+        if o.health <= 0.0 || o.energy >= cfg.recipient_max_energy || o.burrow_id.is_some() { continue; }
+        let reach = donor.radius + o.radius + cfg.range;
+        let (dx, dy) = (o.x - donor.x, o.y - donor.y);
+        if dx * dx + dy * dy > reach * reach { continue; }
+        let kin = !donor.lineage.is_empty() && donor.lineage == o.lineage;
+        let need = (cfg.recipient_max_energy - o.energy) * if kin { cfg.kin_bias.max(0.0) } else { 1.0 };
+        if best.is_none_or(|(_, _, n)| need > n) { best = Some((i, kin, need)); }
+    }
+    best.map(|(i, kin, _)| (i, kin))
+}
@@ -0,0 +1,146 @@
+// Per-step summary: the counts, births, deaths, and phase timings a host needs every frame, in one
+// small struct instead of entity dumps. The tally is reset at the start of every tick.
+
+use serde::Serialize;
+
+use crate::creature::{DeathCause, Diet};
+use crate::perf::{now_ms, PerfCounters};
+use crate::World;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone, Copy, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeathCounts {
+    pub total: u32,
+    pub old_age: u32,
+    pub starvation: u32,
+    pub dehydration: u32,
+    pub drowning: u32,
+    pub predation: u32,
+}
+
+impl DeathCounts {
+    pub(crate) fn record(&mut self, cause: DeathCause) {
+        self.total += 1;
+        match cause {
+            DeathCause::OldAge => self.old_age += 1,
+            DeathCause::Starvation => self.starvation += 1,
+            DeathCause::Dehydration => self.dehydration += 1,
+            DeathCause::Drowning => self.drowning += 1,
+            DeathCause::Predation => self.predation += 1,
+        }
+    }
+}
+
+// What happened during the last tick
+#[derive(Clone, Copy, Default)]
+pub(crate) struct StepTally {
+    pub(crate) births: u32,
+    pub(crate) deaths: DeathCounts,
+    pub(crate) attacks: u32,
+    pub(crate) plants_eaten: u32,
+    pub(crate) biomass_eaten: f32,
+}
+
+impl StepTally {
+    pub(crate) fn add(&mut self, tick: &StepTally) {
+        self.births += tick.births;
+        self.deaths.total += tick.deaths.total;
+        self.deaths.old_age += tick.deaths.old_age;
+        self.deaths.starvation += tick.deaths.starvation;
+        self.deaths.dehydration += tick.deaths.dehydration;
+        self.deaths.drowning += tick.deaths.drowning;
+        self.deaths.predation += tick.deaths.predation;
+        self.attacks += tick.attacks;
+        self.plants_eaten += tick.plants_eaten;
+        self.biomass_eaten += tick.biomass_eaten;
+    }
+}
+
+// Wall-clock time spent in each phase of the step (ms)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone, Copy, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseTimings {
+    pub sensing_ms: f64,
+    pub brain_ms: f64,
+    pub movement_ms: f64,
+    pub environment_ms: f64,
+    pub reproduction_ms: f64,
+    pub corpse_decay_ms: f64,
+    pub total_ms: f64,
+}
+
+impl PhaseTimings {
+    fn of(perf: &PerfCounters) -> PhaseTimings {
+        PhaseTimings {
+            sensing_ms: perf.sensing_ms,
+            brain_ms: perf.brain_ms,
+            movement_ms: perf.movement_ms,
+            environment_ms: perf.environment_ms,
+            reproduction_ms: perf.reproduction_ms,
+            corpse_decay_ms: perf.corpse_decay_ms,
+            total_ms: 0.0,
+        }
+    }
+
+    fn since(self, before: PhaseTimings, total_ms: f64) -> PhaseTimings {
+        PhaseTimings {
+            sensing_ms: self.sensing_ms - before.sensing_ms,
+            brain_ms: self.brain_ms - before.brain_ms,
+            movement_ms: self.movement_ms - before.movement_ms,
+            environment_ms: self.environment_ms - before.environment_ms,
+            reproduction_ms: self.reproduction_ms - before.reproduction_ms,
+            corpse_decay_ms: self.corpse_decay_ms - before.corpse_decay_ms,
+            total_ms,
+        }
+    }
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StepSummary {
+    pub tick: u64,
+    pub ticks: u32, // ticks this step ran (sub-steps; 0 while paused, when only the counts are set)
+    pub births: u32,
+    pub deaths: DeathCounts,
+    pub attacks: u32, // attack attempts started
+    pub plants_eaten: u32, // plants grazed by at least one creature, summed over the ticks
+    pub biomass_eaten: f32,
+    pub creatures: u32,
+    pub herbivores: u32,
+    pub carnivores: u32,
+    pub plants: u32,
+    pub corpses: u32,
+    pub timings: PhaseTimings,
+}
+
+impl World {
+    // step(real_dt), then report what its ticks did and the current population counts
+    pub fn step_with_summary(&mut self, real_dt: f32) -> StepSummary {
+        let before = PhaseTimings::of(&self.perf);
+        let t0 = now_ms();
+        let tick = self.tick;
+        self.step(real_dt);
+        let ticks = (self.tick - tick) as u32;
+        let tally = if ticks > 0 { self.tally } else { StepTally::default() };
+        let timings = if ticks > 0 { PhaseTimings::of(&self.perf).since(before, now_ms() - t0) } else { PhaseTimings::default() };
+        let herbivores = self.creatures.iter().filter(|c| c.diet == Diet::Herbivore).count() as u32;
+        StepSummary {
+            tick: self.tick,
+            ticks,
+            births: tally.births,
+            deaths: tally.deaths,
+            attacks: tally.attacks,
+            plants_eaten: tally.plants_eaten,
+            biomass_eaten: tally.biomass_eaten,
+            creatures: self.creatures.len() as u32,
+            herbivores,
+            carnivores: self.creatures.len() as u32 - herbivores,
+            plants: self.plants.len() as u32,
+            corpses: self.corpses.len() as u32,
+            timings,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// Torpor (hibernation): a TORPOR action that shuts a creature down for an extended bout. While
+// torpid it doesn't move, eat, drink, or fight, and its metabolic costs and thirst drop sharply,
+// more so for big bodies and in winter. Waking leaves it sluggish for a while, so sleeping through
+// a famine or a winter is a commitment.
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Creature;
+use crate::seasons::Season;
+
+// Torpor settings (serialized as the `torpor` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TorporConfig {
+    pub enabled: bool,          // adds the TORPOR output to every brain
+    pub metabolic_mult: f32,    // cost multiplier while torpid, for a body of reference_radius
+    pub reference_radius: f32,  // bigger bodies save more: the multiplier scales with sqrt(reference / radius)
+    pub winter_mult: f32,       // extra multiplier on the savings in winter (seasons enabled)
+    pub min_duration_sec: f32,  // a bout lasts at least this long once started
+    pub wake_up_sec: f32,       // sluggish period after waking
+    pub wake_speed_mult: f32,   // speed multiplier while waking up
+}
+
+impl Default for TorporConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, metabolic_mult: 0.15, reference_radius: 5.0, winter_mult: 0.5,
+            min_duration_sec: 600.0, wake_up_sec: 120.0, wake_speed_mult: 0.3,
+        }
+    }
+}
+
+impl TorporConfig {
+    // Cost multiplier for `c` while torpid (0..1)
+    fn metabolic_mult_for(&self, c: &Creature, season: Option<Season>) -> f32 {
+        let size = (self.reference_radius.max(0.1) / c.radius.max(0.1)).sqrt();
+        let winter = if season == Some(Season::Winter) { self.winter_mult.max(0.0) } else { 1.0 };
+        (self.metabolic_mult.max(0.0) * size * winter).min(1.0)
+    }
+
+    // Advance `c`'s torpor state by t_sec given this tick's TORPOR signal; `can_enter` when the
+    // creature is on dry ground. Returns the metabolic multiplier for this tick (1 when awake).
+    pub(crate) fn update(&self, c: &mut Creature, wants: bool, can_enter: bool, season: Option<Season>, t_sec: f32) -> f32 {
+        c.wake_timer = (c.wake_timer - t_sec).max(0.0);
+        if c.torpor_sec > 0.0 {
+            if !wants && c.torpor_sec >= self.min_duration_sec {
+                c.torpor_sec = 0.0;
+                c.wake_timer = self.wake_up_sec.max(0.0);
+                return 1.0;
+            }
+            c.torpor_sec += t_sec;
+        } else if wants && can_enter && c.wake_timer <= 0.0 {
+            c.torpor_sec = t_sec.max(f32::EPSILON);
+        } else {
+            return 1.0;
+        }
+        self.metabolic_mult_for(c, season)
+    }
+
+    // Speed multiplier (0 while torpid, reduced while waking up)
+    pub(crate) fn speed_mult(&self, c: &Creature) -> f32 {
+        if c.torpor_sec > 0.0 { 0.0 } else if c.wake_timer > 0.0 { self.wake_speed_mult.clamp(0.0, 1.0) } else { 1.0 }
+    }
+}
@@ -0,0 +1,78 @@
+// Movement trails: the last few positions of every creature, sampled every few ticks, so hosts can
+// draw trails for selected creatures without tracking positions across frames themselves.
+// Not part of snapshots; trails restart empty after a restore.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::World;
+
+// Trail settings (serialized as the `trails` block of Config)
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TrailConfig {
+    pub enabled: bool,
+    pub length: u32,       // positions kept per creature
+    pub stride_ticks: u32, // ticks between samples
+}
+
+impl Default for TrailConfig {
+    fn default() -> Self { Self { enabled: false, length: 60, stride_ticks: 10 } }
+}
+
+// Oldest position first; the newest sample is the creature's position at `tick`
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trail {
+    pub id: String,
+    pub tick: u64,
+    pub points: Vec<[f32; 2]>,
+}
+
+#[derive(Default)]
+pub(crate) struct TrailLog {
+    tick: u64,
+    trails: HashMap<String, VecDeque<[f32; 2]>>,
+}
+
+impl TrailLog {
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.trails.iter().map(|(id, t)| id.capacity() + t.capacity() * std::mem::size_of::<[f32; 2]>()).sum()
+    }
+}
+
+impl World {
+    // Sample every living creature when the stride comes round; trails of the dead are dropped
+    pub(crate) fn record_trails(&mut self) {
+        let cfg = &self.config.trails;
+        if !cfg.enabled || cfg.length == 0 {
+            if !self.trails.trails.is_empty() { self.trails = TrailLog::default(); }
+            return;
+        }
+        if !self.tick.is_multiple_of(cfg.stride_ticks.max(1) as u64) { return; }
+        let len = cfg.length as usize;
+        let living: HashSet<&str> = self.creatures.iter().map(|c| c.id.as_str()).collect();
+        self.trails.trails.retain(|id, _| living.contains(id.as_str()));
+        for c in &self.creatures {
+            let trail = self.trails.trails.entry(c.id.clone()).or_default();
+            while trail.len() >= len { trail.pop_front(); }
+            trail.push_back([c.x, c.y]);
+        }
+        self.trails.tick = self.tick;
+    }
+
+    // Trails for `ids` (every recorded creature when empty); unknown ids are skipped
+    pub fn trails(&self, ids: &[String]) -> Vec<Trail> {
+        let trail = |id: &String, points: &VecDeque<[f32; 2]>| Trail { id: id.clone(), tick: self.trails.tick, points: points.iter().copied().collect() };
+        if ids.is_empty() {
+            let mut all: Vec<Trail> = self.trails.trails.iter().map(|(id, p)| trail(id, p)).collect();
+            all.sort_by(|a, b| a.id.cmp(&b.id));
+            all
+        } else {
+            ids.iter().filter_map(|id| self.trails.trails.get_key_value(id).map(|(id, p)| trail(id, p))).collect()
+        }
+    }
+}
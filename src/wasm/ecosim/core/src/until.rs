@@ -0,0 +1,146 @@
+// Run-until stepping for scripted experiments: advance fixed ticks until any of a set of stop
+// conditions holds (or a tick budget runs out) and report which one stopped the run.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::creature::Diet;
+use crate::{World, WorldStats};
+
+// Checked after every tick; each compares against the world as it was when the run started.
+// Unknown fields are rejected so a misspelled filter can't silently widen a condition.
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
+pub enum StopCondition {
+    // Living creatures (of `diet` only, when given) fewer than `count`
+    PopulationBelow { count: u32, #[serde(default)] diet: Option<Diet> },
+    TickReached { tick: u64 },
+    // A diet that was present at the start died out
+    FirstExtinction,
+    // The number of distinct brain topologies among the living (see Highlight::NewSpecies) changed
+    SpeciesCountChanges,
+}
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StepUntilOutcome {
+    // Type of the condition that stopped the run, or "maxTicks"
+    pub reason: &'static str,
+    pub condition: Option<StopCondition>,
+    pub ticks: u32, // ticks advanced by this call
+    pub stats: WorldStats,
+}
+
+impl StopCondition {
+    // Serialized `type` tag
+    pub fn name(&self) -> &'static str {
+        match self {
+            StopCondition::PopulationBelow { .. } => "populationBelow",
+            StopCondition::TickReached { .. } => "tickReached",
+            StopCondition::FirstExtinction => "firstExtinction",
+            StopCondition::SpeciesCountChanges => "speciesCountChanges",
+        }
+    }
+}
+
+// Accept a single condition object or an array of them (any-of)
+pub fn parse_stop_conditions(value: serde_json::Value) -> Result<Vec<StopCondition>, String> {
+    if value.is_array() {
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_value(value).map(|c| vec![c]).map_err(|e| e.to_string())
+    }
+}
+
+impl World {
+    // Advance fixed 1/60s ticks (ignoring pause and time scale) until a condition holds or
+    // `max_ticks` ticks have run
+    pub fn step_until(&mut self, conditions: &[StopCondition], max_ticks: u32) -> StepUntilOutcome {
+        let diets_at_start = self.diets_present();
+        let species_at_start = self.species_count();
+        let mut met = None;
+        let mut ticks = 0;
+        while ticks < max_ticks && met.is_none() {
+            self.advance(1.0 / 60.0);
+            ticks += 1;
+            met = conditions.iter().find(|c| self.condition_met(c, diets_at_start, species_at_start)).cloned();
+        }
+        let reason = met.as_ref().map_or("maxTicks", StopCondition::name);
+        StepUntilOutcome { reason, condition: met, ticks, stats: self.stats_summary() }
+    }
+
+    fn condition_met(&self, condition: &StopCondition, diets_at_start: [bool; 2], species_at_start: usize) -> bool {
+        match *condition {
+            StopCondition::PopulationBelow { count, diet } => {
+                let n = self.creatures.iter().filter(|c| diet.is_none_or(|d| c.diet == d)).count();
+                n < count as usize
+            }
+            StopCondition::TickReached { tick } => self.tick >= tick,
+            StopCondition::FirstExtinction => {
+                let now = self.diets_present();
+                (diets_at_start[0] && !now[0]) || (diets_at_start[1] && !now[1])
+            }
+            StopCondition::SpeciesCountChanges => self.species_count() != species_at_start,
+        }
+    }
+
+    // (herbivores present, carnivores present)
+    fn diets_present(&self) -> [bool; 2] {
+        [Diet::Herbivore, Diet::Carnivore].map(|d| self.creatures.iter().any(|c| c.diet == d))
+    }
+
+    fn species_count(&self) -> usize {
+        self.creatures.iter().map(|c| &c.brain.layer_sizes).collect::<HashSet<_>>().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_one_condition_or_a_list() {
+        let one = parse_stop_conditions(json!({ "type": "tickReached", "tick": 500 })).unwrap();
+        assert!(matches!(one[..], [StopCondition::TickReached { tick: 500 }]));
+        let list = parse_stop_conditions(json!([
+            { "type": "populationBelow", "count": 10, "diet": "Carnivore" },
+            { "type": "populationBelow", "count": 3 },
+            { "type": "firstExtinction" },
+            { "type": "speciesCountChanges" },
+        ])).unwrap();
+        assert!(matches!(list[..], [
+            StopCondition::PopulationBelow { count: 10, diet: Some(Diet::Carnivore) },
+            StopCondition::PopulationBelow { count: 3, diet: None },
+            StopCondition::FirstExtinction,
+            StopCondition::SpeciesCountChanges,
+        ]));
+    }
+
+    // Every malformed condition is an error naming the problem, never a panic or a dropped condition
+    #[test]
+    fn rejects_malformed_conditions() {
+        let cases = [
+            (json!({ "type": "populationAbove", "count": 10 }), "unknown variant"),
+            (json!({ "count": 10 }), "missing field `type`"),
+            (json!({ "type": "populationBelow" }), "missing field `count`"),
+            (json!({ "type": "tickReached" }), "missing field `tick`"),
+            (json!({ "type": "populationBelow", "count": "ten" }), "invalid type"),
+            (json!({ "type": "tickReached", "tick": -5 }), "invalid value"),
+            (json!({ "type": "populationBelow", "count": 10, "diet": "Omnivore" }), "unknown variant"),
+            (json!({ "type": "populationBelow", "count": 10, "deit": "Carnivore" }), "unknown field"),
+            (json!([{ "type": "firstExtinction" }, { "type": "tickReached", "tick": "soon" }]), "invalid type"),
+            (json!("firstExtinction"), "invalid type"),
+        ];
+        for (value, expected) in cases {
+            let err = match parse_stop_conditions(value.clone()) {
+                Ok(_) => panic!("{} parsed", value),
+                Err(e) => e,
+            };
+            assert!(err.contains(expected), "{}: {:?} lacks {:?}", value, err, expected);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Follow-creature telemetry: a detailed per-tick record (inputs, outputs, activations, energy
+// ledger, events) for one watched creature. Only the watched creature keeps its activations on
+// its brain; everyone else drops them after the forward pass.
+// Not part of snapshots; the watch ends on restore.
+
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+use crate::action_log::ActionLogEntry;
+use crate::creature::{Creature, EnergyLedger};
+use crate::events::WorldEvent;
+use crate::World;
+
+// Records kept for the watched creature (about two seconds at 60 ticks/s)
+const WATCH_HISTORY_TICKS: usize = 120;
+
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchRecord {
+    pub tick: u64,
+    pub inputs: Vec<f32>,
+    pub outputs: Vec<f32>,
+    // Input layer first, as brain_forward produced them
+    pub activations: Vec<Vec<f32>>,
+    pub ledger: EnergyLedger,
+    pub x: f32,
+    pub y: f32,
+    pub energy: f32,
+    pub health: f32,
+    pub stamina: f32,
+    pub thirst: f32,
+    pub actions_mask: u32,
+    pub feelings_mask: u32,
+    // Events this tick that name the creature (death, miscarriage, degenerate brain, energy sharing)
+    pub events: Vec<WorldEvent>,
+}
+
+impl WatchRecord {
+    fn capture(&mut self, c: &Creature) {
+        self.ledger = c.ledger;
+        self.x = c.x;
+        self.y = c.y;
+        self.energy = c.energy;
+        self.health = c.health;
+        self.stamina = c.stamina;
+        self.thirst = c.thirst;
+        self.actions_mask = c.actions_mask;
+        self.feelings_mask = c.feelings_mask;
+    }
+}
+
+// Oldest record first; `alive` turns false on the tick the creature dies and recording stops
+#[cfg_attr(feature = "ts", derive(tsify::Tsify))]
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchedCreature {
+    pub id: String,
+    pub alive: bool,
+    pub records: Vec<WatchRecord>,
+    // Recent discrete actions (oldest first), as of the last record
+    pub recent_actions: Vec<ActionLogEntry>,
+}
+
+pub(crate) struct Watch {
+    id: String,
+    alive: bool,
+    // Filled during the creature pass, completed once the whole tick has run
+    pending: Option<WatchRecord>,
+    records: VecDeque<WatchRecord>,
+    actions: Vec<ActionLogEntry>,
+}
+
+impl Watch {
+    pub(crate) fn follows(&self, id: &str) -> bool { self.alive && self.id == id }
+
+    // Called from the creature pass with this tick's brain I/O
+    pub(crate) fn begin(&mut self, tick: u64, c: &Creature, inputs: &[f32], outputs: &[f32], activations: &[Vec<f32>]) {
+        let mut rec = WatchRecord { tick, inputs: inputs.to_vec(), outputs: outputs.to_vec(), activations: activations.to_vec(), ..WatchRecord::default() };
+        rec.capture(c);
+        self.pending = Some(rec);
+        self.actions = c.recent_actions.entries();
+    }
+}
+
+fn names(event: &WorldEvent, id: &str) -> bool {
+    match event {
+        WorldEvent::DegenerateBrain { id: who, .. } | WorldEvent::Death { id: who, .. } | WorldEvent::Miscarriage { id: who, .. }
+        | WorldEvent::HandFed { id: who, .. } => who == id,
+        WorldEvent::EnergyShared { from, to, .. } => from == id || to == id,
+        _ => false,
+    }
+}
+
+impl World {
+    // Start following `id` (replacing any previous watch), or stop with None
+    pub fn watch_creature(&mut self, id: Option<String>) {
+        if let Some(w) = &self.watch {
+            if let Some(c) = self.creatures.iter_mut().find(|c| c.id == w.id) { c.brain.activations = None; }
+        }
+        self.watch = id.map(|id| {
+            let found = self.creatures.iter().find(|c| c.id == id);
+            let actions = found.map(|c| c.recent_actions.entries()).unwrap_or_default();
+            Watch { alive: found.is_some(), id, pending: None, records: VecDeque::new(), actions }
+        });
+    }
+
+    pub fn watched_creature(&self) -> Option<WatchedCreature> {
+        self.watch.as_ref().map(|w| WatchedCreature { id: w.id.clone(), alive: w.alive, records: w.records.iter().cloned().collect(), recent_actions: w.actions.clone() })
+    }
+
+    // End of tick: refresh vitals and ledger from the finished state and attach the tick's events
+    pub(crate) fn finish_watch(&mut self) {
+        let Some(w) = self.watch.as_mut() else { return };
+        let Some(mut rec) = w.pending.take() else { return };
+        match self.creatures.iter().find(|c| c.id == w.id) {
+            Some(c) => {
+                rec.capture(c);
+                w.actions = c.recent_actions.entries();
+            }
+            None => w.alive = false,
+        }
+        let mut events: Vec<WorldEvent> = self.events.iter().rev()
+            .take_while(|e| event_tick(e) == self.tick)
+            .filter(|e| names(e, &w.id))
+            .cloned()
+            .collect();
+        events.reverse();
+        rec.events = events;
+        while w.records.len() >= WATCH_HISTORY_TICKS { w.records.pop_front(); }
+        w.records.push_back(rec);
+    }
+}
+
+fn event_tick(event: &WorldEvent) -> u64 {
+    match event {
+        WorldEvent::DegenerateBrain { tick, .. }
+        | WorldEvent::EnergyShared { tick, .. }
+        | WorldEvent::Death { tick, .. }
+        | WorldEvent::Miscarriage { tick, .. }
+        | WorldEvent::HandFed { tick, .. }
+        | WorldEvent::FoodPlaced { tick, .. }
+        | WorldEvent::BalanceAdjusted { tick, .. }
+        | WorldEvent::InvariantViolation { tick, .. } => *tick,
+    }
+}
@@ -0,0 +1,28 @@
+// Partial config updates: unmentioned fields survive, unknown or invalid payloads are rejected.
+
+use ecosim_core::World;
+use serde_json::json;
+
+#[test]
+fn partial_update_keeps_unmentioned_fields() {
+    let mut w = World::new(600.0, 400.0, 3);
+    w.update_config(&json!({ "attackDamagePerSec": 2.5, "disease": { "enabled": true } })).unwrap();
+    let before = w.config().clone();
+    w.update_config(&json!({ "thirstDecayPerSec": 0.0, "disease": { "contactRadius": 17.0 } })).unwrap();
+    let cfg = w.config();
+    assert_eq!(cfg.thirst_decay_per_sec, 0.0);
+    assert_eq!(cfg.attack_damage_per_sec, 2.5);
+    assert!(cfg.disease.enabled);
+    assert_eq!(cfg.disease.contact_radius, 17.0);
+    assert_eq!(cfg.max_life, before.max_life);
+}
+
+#[test]
+fn unknown_keys_and_bad_values_leave_config_unchanged() {
+    let mut w = World::new(600.0, 400.0, 3);
+    let before = serde_json::to_value(w.config()).unwrap();
+    assert!(w.update_config(&json!({ "thirstDecayPerSecc": 0.0 })).is_err());
+    assert!(w.update_config(&json!({ "thirstDecayPerSec": "fast" })).is_err());
+    assert!(w.update_config(&json!([1, 2])).is_err());
+    assert_eq!(serde_json::to_value(w.config()).unwrap(), before);
+}
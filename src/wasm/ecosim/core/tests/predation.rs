@@ -0,0 +1,116 @@
+// Predation: carnivores only bite prey in reach, bites do damage, and only deaths caused by that
+// damage count as kills.
+
+use ecosim_core::{DeathCause, Diet, World, WorldEvent};
+
+const DT: f32 = 1.0 / 60.0;
+
+// One carnivore at (200, 200) and one herbivore `distance` to its right, nothing else alive
+fn duel(distance: f32, prey_energy: f32, prey_health: f32) -> World {
+    let mut w = World::new(600.0, 400.0, 5);
+    let mut snap = w.snapshot();
+    let mut hunter = snap.creatures[0].clone();
+    let mut prey = snap.creatures[1].clone();
+    hunter.diet = Diet::Carnivore;
+    (hunter.x, hunter.y, hunter.vx, hunter.vy) = (200.0, 200.0, 0.0, 0.0);
+    prey.diet = Diet::Herbivore;
+    (prey.x, prey.y, prey.vx, prey.vy) = (200.0 + distance, 200.0, 0.0, 0.0);
+    prey.energy = prey_energy;
+    prey.health = prey_health;
+    snap.creatures = vec![hunter, prey];
+    snap.runtime = vec![Default::default(); 2];
+    w.load_snapshot(snap);
+    let mut cfg = w.config().clone();
+    cfg.attack_damage_per_sec = 4.0;
+    w.set_config(cfg);
+    w
+}
+
+// Step one tick with the carnivore boosting (output 5) and the herbivore idle
+fn hunt(w: &mut World) {
+    let ids: Vec<String> = w.creatures().iter().map(|c| c.id.clone()).collect();
+    let width = w.creatures()[0].brain.layer_sizes.last().copied().unwrap_or(0) as usize;
+    let mut outputs = vec![0.0; width * ids.len()];
+    if w.creatures()[0].diet == Diet::Carnivore { outputs[5] = 5.0; }
+    w.set_brain_outputs(&ids, &outputs);
+    w.advance(DT);
+}
+
+fn death_causes(w: &mut World) -> Vec<DeathCause> {
+    w.drain_events().into_iter().filter_map(|e| match e { WorldEvent::Death { cause, .. } => Some(cause), _ => None }).collect()
+}
+
+#[test]
+fn starvation_after_out_of_range_attack_is_not_a_kill() {
+    let mut w = duel(150.0, 0.001, 100.0);
+    hunt(&mut w);
+    assert_eq!(death_causes(&mut w), vec![DeathCause::Starvation]);
+    let stats = w.stats();
+    assert_eq!(stats.attack_attempts_total, 0);
+    assert_eq!(stats.kills_total, 0);
+}
+
+#[test]
+fn bites_in_reach_kill_and_are_credited() {
+    let mut w = duel(6.0, 80.0, 0.5);
+    let hunter = w.creatures()[0].id.clone();
+    hunt(&mut w);
+    assert_eq!(death_causes(&mut w), vec![DeathCause::Predation]);
+    let stats = w.stats();
+    assert_eq!(stats.attack_attempts_total, 1);
+    assert_eq!(stats.kills_total, 1);
+    assert_eq!(w.creatures().iter().find(|c| c.id == hunter).map(|c| c.metrics.kills), Some(1));
+}
+
+#[test]
+fn starvation_while_bitten_is_not_a_kill() {
+    let mut w = duel(6.0, 0.001, 100.0);
+    hunt(&mut w);
+    assert_eq!(death_causes(&mut w), vec![DeathCause::Starvation]);
+    assert_eq!(w.stats().attack_attempts_total, 1);
+    assert_eq!(w.stats().kills_total, 0);
+}
+
+#[test]
+fn bites_are_harmless_by_default() {
+    let mut w = duel(6.0, 80.0, 0.5);
+    let mut cfg = w.config().clone();
+    cfg.attack_damage_per_sec = ecosim_core::Config::default().attack_damage_per_sec;
+    w.set_config(cfg);
+    hunt(&mut w);
+    assert!(death_causes(&mut w).is_empty());
+    assert_eq!(w.stats().attack_attempts_total, 1);
+    assert_eq!(w.stats().kills_total, 0);
+}
+
+#[test]
+fn kill_credit_outlives_the_action_log() {
+    let mut w = duel(6.0, 50.0, 100.0);
+    let hunter = w.creatures()[0].id.clone();
+    hunt(&mut w);
+    // Flood the hunter's action log with later entries, then let the earlier bite prove fatal
+    let mut snap = w.snapshot();
+    let filler: Vec<serde_json::Value> = (0..20u64)
+        .map(|t| serde_json::json!({ "tick": 10 + 2 * t, "lastTick": 10 + 2 * t, "action": { "type": "drank" }, "amount": 1.0 }))
+        .collect();
+    snap.runtime[0].recent_actions = serde_json::from_value(serde_json::Value::Array(filler)).unwrap();
+    snap.creatures[1].x += 150.0;
+    snap.creatures[1].health = 0.0;
+    w.load_snapshot(snap);
+    hunt(&mut w);
+    assert_eq!(death_causes(&mut w), vec![DeathCause::Predation]);
+    assert_eq!(w.stats().kills_total, 1);
+    assert_eq!(w.creatures().iter().find(|c| c.id == hunter).map(|c| c.metrics.kills), Some(1));
+}
+
+#[test]
+fn bites_cost_the_hunter_stamina() {
+    let mut far = duel(150.0, 80.0, 100.0);
+    let mut near = duel(6.0, 80.0, 100.0);
+    hunt(&mut far);
+    hunt(&mut near);
+    let cost = near.config().attack_cost_per_hit_stamina;
+    let (idle, biting) = (far.creatures()[0].stamina, near.creatures()[0].stamina);
+    assert!(cost > 0.0);
+    assert!((idle - biting - cost).abs() < 1e-4, "idle {} biting {} cost {}", idle, biting, cost);
+}
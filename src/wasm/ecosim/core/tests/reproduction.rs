@@ -0,0 +1,50 @@
+// Gestation eligibility: conception needs enough energy, health and age, no current pregnancy,
+// and an elapsed postpartum cooldown.
+
+use ecosim_core::{Config, Creature, World};
+
+// A creature just past every threshold in `cfg`
+fn ready(cfg: &Config) -> Creature {
+    let mut c = World::new(600.0, 400.0, 5).creatures()[0].clone();
+    c.energy = cfg.gestation_min_energy;
+    c.health = cfg.gestation_min_health;
+    c.lifespan = cfg.gestation_min_age;
+    c.is_pregnant = false;
+    c.postpartum_timer = 0.0;
+    c
+}
+
+#[test]
+fn each_threshold_blocks_conception() {
+    let cfg = Config::default();
+    assert!(ready(&cfg).can_conceive(&cfg));
+
+    let mut c = ready(&cfg);
+    c.energy -= 0.1;
+    assert!(!c.can_conceive(&cfg), "energy below gestation_min_energy");
+    let mut c = ready(&cfg);
+    c.health -= 0.1;
+    assert!(!c.can_conceive(&cfg), "health below gestation_min_health");
+    let mut c = ready(&cfg);
+    c.lifespan -= 1;
+    assert!(!c.can_conceive(&cfg), "younger than gestation_min_age");
+    let mut c = ready(&cfg);
+    c.is_pregnant = true;
+    assert!(!c.can_conceive(&cfg), "already pregnant");
+    let mut c = ready(&cfg);
+    c.postpartum_timer = 1.0;
+    assert!(!c.can_conceive(&cfg), "postpartum cooldown not over");
+}
+
+#[test]
+fn postpartum_cooldown_runs_down_with_ticks() {
+    let mut w = World::new(600.0, 400.0, 5);
+    let mut snap = w.snapshot();
+    snap.creatures.truncate(1);
+    snap.creatures[0].postpartum_timer = 30.0;
+    snap.runtime.truncate(1);
+    w.load_snapshot(snap);
+    for _ in 0..10 { w.advance(1.0 / 60.0); }
+    let timer = w.creatures()[0].postpartum_timer;
+    assert!((timer - 20.0).abs() < 1e-3, "timer {}", timer);
+}
@@ -0,0 +1,90 @@
+// Survival upkeep: thirst runs down away from water, dry creatures die of dehydration, drinking
+// at the shore refills them, and only surplus energy heals.
+
+use ecosim_core::{DeathCause, World, WorldEvent};
+
+const DT: f32 = 1.0 / 60.0;
+
+// A single idle creature at (300, y), with ambient decay and rain puddles switched off so thirst
+// and the shore are the only things in play
+fn loner(y: f32, thirst: f32, energy: f32, health: f32) -> World {
+    let mut w = World::new(600.0, 400.0, 5);
+    let mut snap = w.snapshot();
+    let mut c = snap.creatures[0].clone();
+    (c.x, c.y, c.vx, c.vy) = (300.0, y, 0.0, 0.0);
+    c.thirst = thirst;
+    c.energy = energy;
+    c.health = health;
+    snap.creatures = vec![c];
+    snap.runtime = vec![Default::default()];
+    w.load_snapshot(snap);
+    let mut cfg = w.config().clone();
+    cfg.ambient_health_decay_per_sec = 0.0;
+    cfg.puddle_rain_threshold = 2.0;
+    w.set_config(cfg);
+    w
+}
+
+// Step one tick with every brain output at 0
+fn idle(w: &mut World) {
+    let ids: Vec<String> = w.creatures().iter().map(|c| c.id.clone()).collect();
+    let width = w.creatures()[0].brain.layer_sizes.last().copied().unwrap_or(0) as usize;
+    w.set_brain_outputs(&ids, &vec![0.0; width * ids.len()]);
+    w.advance(DT);
+}
+
+fn death_causes(w: &mut World) -> Vec<DeathCause> {
+    w.drain_events().into_iter().filter_map(|e| match e { WorldEvent::Death { cause, .. } => Some(cause), _ => None }).collect()
+}
+
+#[test]
+fn dry_creatures_lose_health_and_die_of_dehydration() {
+    let mut w = loner(200.0, 0.0, 50.0, 100.0);
+    for _ in 0..60 { idle(&mut w); }
+    let health = w.creatures()[0].health;
+    assert!(health < 100.0, "health {}", health);
+    assert!(death_causes(&mut w).is_empty());
+
+    let mut w = loner(200.0, 0.0, 50.0, 0.001);
+    idle(&mut w);
+    assert!(w.creatures().is_empty());
+    assert_eq!(death_causes(&mut w), vec![DeathCause::Dehydration]);
+}
+
+#[test]
+fn drinking_at_the_shore_restores_thirst() {
+    let mut inland = loner(200.0, 10.0, 50.0, 100.0);
+    idle(&mut inland);
+    assert!(inland.creatures()[0].thirst < 10.0);
+
+    // The top water band ends at 12% of the height; stand just below it
+    let mut shore = loner(400.0 * 0.12 + 2.0, 10.0, 50.0, 100.0);
+    for _ in 0..3 { idle(&mut shore); }
+    let thirst = shore.creatures()[0].thirst;
+    assert!(thirst > 10.0, "thirst {}", thirst);
+}
+
+#[test]
+fn only_surplus_energy_heals() {
+    let mut fed = loner(200.0, 100.0, 90.0, 50.0);
+    let mut hungry = loner(200.0, 100.0, 60.0, 50.0);
+    for _ in 0..60 {
+        idle(&mut fed);
+        idle(&mut hungry);
+    }
+    assert!(fed.creatures()[0].health > 50.0);
+    assert_eq!(hungry.creatures()[0].health, 50.0);
+}
+
+#[test]
+fn healing_is_capped_by_the_surplus() {
+    // One energy above the threshold pays for at most 1 / regen_energy_per_health health
+    let mut w = loner(200.0, 100.0, 71.0, 50.0);
+    let mut cfg = w.config().clone();
+    cfg.regen_health_per_sec = 1000.0;
+    let cap = (71.0 - cfg.regen_energy_threshold) / cfg.regen_energy_per_health;
+    w.set_config(cfg);
+    idle(&mut w);
+    let health = w.creatures()[0].health;
+    assert!(health > 50.0 && health <= 50.0 + cap + 1e-4, "health {} cap {}", health, cap);
+}
@@ -0,0 +1,308 @@
+// PyO3 bindings over ecosim-core. Structured results cross the boundary as plain dicts/lists
+// (via the json module), so they drop straight into pandas.DataFrame(...).
+//
+//   import ecosim_py
+//   w = ecosim_py.World(800, 600, seed=7)
+//   rows = w.run(36_000, report_every=600)
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::Serialize;
+
+use ecosim_core::{Brain, BrainMode, Config};
+
+#[pyclass(unsendable)]
+struct World {
+    inner: ecosim_core::World,
+}
+
+#[pymethods]
+impl World {
+    #[new]
+    #[pyo3(signature = (width = 800.0, height = 600.0, seed = 1))]
+    fn new(width: f32, height: f32, seed: u32) -> Self {
+        World { inner: ecosim_core::World::new(width, height, seed) }
+    }
+
+    // Advance by real elapsed seconds (honours time scale and pause; clamped and sub-stepped per config)
+    #[pyo3(signature = (dt = 1.0 / 60.0))]
+    fn step(&mut self, dt: f32) { self.inner.step(dt); }
+
+    // step() returning a dict of births, deaths by cause, attacks, grazing, counts, and phase timings
+    #[pyo3(signature = (dt = 1.0 / 60.0))]
+    fn step_with_summary<'py>(&mut self, py: Python<'py>, dt: f32) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.step_with_summary(dt)) }
+
+    // Advance `ticks` fixed 1/60s ticks; returns a stats dict every `report_every` ticks plus the last one
+    #[pyo3(signature = (ticks, report_every = 600))]
+    fn run<'py>(&mut self, py: Python<'py>, ticks: u32, report_every: u32) -> PyResult<Bound<'py, PyAny>> {
+        let reports = py.detach(|| self.inner.run_headless(ticks, report_every));
+        to_py(py, &reports)
+    }
+
+    // Advance fixed 1/60s ticks until a stop condition dict (or any of a list) holds or max_ticks run
+    // out; returns {reason, condition, ticks, stats}
+    #[pyo3(signature = (conditions, max_ticks = 36000))]
+    fn step_until<'py>(&mut self, py: Python<'py>, conditions: &Bound<'py, PyAny>, max_ticks: u32) -> PyResult<Bound<'py, PyAny>> {
+        let conditions = ecosim_core::parse_stop_conditions(from_py(py, conditions)?).map_err(PyValueError::new_err)?;
+        let outcome = py.detach(|| self.inner.step_until(&conditions, max_ticks));
+        to_py(py, &outcome)
+    }
+
+    // Deterministic synthetic dense-world run: per-phase timings and ticks/sec
+    #[staticmethod]
+    #[pyo3(signature = (ticks = 600, population = 500))]
+    fn benchmark<'py>(py: Python<'py>, ticks: u32, population: u32) -> PyResult<Bound<'py, PyAny>> {
+        let report = py.detach(|| ecosim_core::World::benchmark(ticks, population));
+        to_py(py, &report)
+    }
+
+    // Run `config` headless once per seed; per-seed finals plus mean/CI and extinction rates
+    #[staticmethod]
+    #[pyo3(signature = (config, seeds, ticks = 3600, width = 800.0, height = 600.0))]
+    fn run_ensemble<'py>(py: Python<'py>, config: &Bound<'py, PyAny>, seeds: Vec<u32>, ticks: u32, width: f32, height: f32) -> PyResult<Bound<'py, PyAny>> {
+        let cfg = from_py::<Config>(py, config)?;
+        let report = py.detach(|| ecosim_core::World::run_ensemble(&cfg, (width, height), &seeds, ticks));
+        to_py(py, &report)
+    }
+
+    // Both configs on matched seeds; paired B-minus-A differences with effect sizes per outcome
+    #[staticmethod]
+    #[pyo3(signature = (config_a, config_b, seeds, ticks = 3600, width = 800.0, height = 600.0))]
+    fn compare_configs<'py>(py: Python<'py>, config_a: &Bound<'py, PyAny>, config_b: &Bound<'py, PyAny>, seeds: Vec<u32>, ticks: u32, width: f32, height: f32) -> PyResult<Bound<'py, PyAny>> {
+        let (a, b) = (from_py::<Config>(py, config_a)?, from_py::<Config>(py, config_b)?);
+        let report = py.detach(|| ecosim_core::World::compare_configs(&a, &b, (width, height), &seeds, ticks));
+        to_py(py, &report)
+    }
+
+    // Built-in curriculum scenarios as [{ name, description }]
+    // {actions, feelings}: bit, mask, and name of every actions_mask/feelings_mask flag
+    #[staticmethod]
+    fn flag_registry<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &ecosim_core::World::flag_registry()) }
+
+    #[staticmethod]
+    fn scenarios<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, ecosim_core::World::scenarios()) }
+
+    #[getter]
+    fn tick(&self) -> u64 { self.inner.tick() }
+
+    // Tick of the frame the entity getters serve (lags tick only with double_buffered_exports)
+    #[getter]
+    fn frame_tick(&self) -> u64 { self.inner.frame_tick() }
+
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.stats()) }
+
+    // Per-biome creature counts, plant biomass, and mean vitals
+    fn biome_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.biome_stats()) }
+
+    // Decomposer colonies as [{x, y, biomass, bornTick}]; empty unless config["decomposers"] is enabled
+    fn decomposers<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, self.inner.decomposers()) }
+
+    // Food items as [{x, y, energy, decayPerSec, bornTick}]
+    fn food_items<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, self.inner.food_items()) }
+
+    // Estimated capacity per biome and the population's load against it
+    fn carrying_capacity<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.carrying_capacity()) }
+
+    // Capacity vs population samples; empty unless config["carryingCapacity"] is enabled
+    fn capacity_history<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.capacity_history()) }
+
+    // Quadrant crossings per season, the season under way last; empty unless config["migration"] is enabled
+    fn migration_history<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.migration_history()) }
+
+    // "spring" | "summer" | "autumn" | "winter", or None while config["seasons"] is off
+    #[getter]
+    fn season<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.season()) }
+
+    // Upcoming weather frames (rain/temperature/wind grids and means) over the next ticks_ahead
+    fn forecast<'py>(&self, py: Python<'py>, ticks_ahead: u64) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.forecast(ticks_ahead)) }
+
+    // Decaying density heatmap layer ("herbivore", "carnivore", or "all") as a flat row-major list of
+    // density_resolution x density_resolution cells; empty unless config["density"] is enabled
+    #[pyo3(signature = (layer = "all"))]
+    fn density_grid(&self, layer: &str) -> PyResult<Vec<f32>> {
+        self.inner.density_grid(layer).ok_or_else(|| PyValueError::new_err(format!("unknown density layer '{}'", layer)))
+    }
+
+    #[getter]
+    fn density_resolution(&self) -> u32 { self.inner.density_resolution() }
+
+    // Recent positions for `ids` (every creature when omitted); empty unless config["trails"] is enabled
+    #[pyo3(signature = (ids = Vec::new()))]
+    fn trails<'py>(&self, py: Python<'py>, ids: Vec<String>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.trails(&ids)) }
+
+    // Follow one creature with per-tick telemetry (None stops)
+    #[pyo3(signature = (id = None))]
+    fn watch_creature(&mut self, id: Option<String>) { self.inner.watch_creature(id); }
+
+    // Recent per-tick records for the watched creature, or None when none is watched
+    fn watched_creature<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.watched_creature()) }
+
+    fn creatures<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.export_entities(self.inner.creatures())) }
+
+    fn plants<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.export_entities(self.inner.plants())) }
+
+    fn corpses<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.export_entities(self.inner.corpses())) }
+
+    fn elites<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.export_entities(self.inner.elites())) }
+
+    // [{ id, coverage, plantShare, movementEntropy, novelty }] for living creatures
+    fn behavior_descriptors<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.behavior_descriptors()) }
+
+    fn config<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, self.inner.config()) }
+
+    // Replace the config from a camelCase dict; missing keys use defaults
+    fn set_config(&mut self, py: Python<'_>, config: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.inner.set_config(from_py::<Config>(py, config)?);
+        Ok(())
+    }
+
+    // "OG" or "Zegion"; ValueError for any other name
+    fn set_brain_mode(&mut self, mode: &str) -> PyResult<()> { self.inner.set_brain_mode(mode).map_err(PyValueError::new_err) }
+
+    fn set_seed(&mut self, seed: u32) { self.inner.set_seed(seed); }
+
+    #[pyo3(signature = (x, y))]
+    fn spawn_creature(&mut self, x: f32, y: f32) { self.inner.spawn_creature(x, y); }
+
+    #[pyo3(signature = (x, y, radius = None))]
+    fn spawn_plant(&mut self, x: f32, y: f32, radius: Option<f32>) { self.inner.spawn_plant(x, y, radius); }
+
+    // Drop a food item right away (not in the command log); False when the item cap is reached
+    #[pyo3(signature = (x, y, energy, decay_per_sec = None))]
+    fn spawn_food(&mut self, x: f32, y: f32, energy: f32, decay_per_sec: Option<f32>) -> bool { self.inner.spawn_food(x, y, energy, decay_per_sec) }
+
+    // Hand feeding, applied before the next tick and recorded in the command log; the command's
+    // sequence id, or None for an unknown creature
+    fn feed_creature(&mut self, id: &str, amount: f32) -> Option<u64> { self.inner.feed_creature(id, amount) }
+
+    #[pyo3(signature = (x, y, energy))]
+    fn place_food(&mut self, x: f32, y: f32, energy: f32) -> Option<u64> { self.inner.place_food(x, y, energy) }
+
+    fn reset(&mut self) { self.inner.reset_world(); }
+
+    // Named input/output channels for "OG" / "Zegion" (current mode when None)
+    #[pyo3(signature = (mode = None))]
+    fn brain_io_schema<'py>(&self, py: Python<'py>, mode: Option<&str>) -> PyResult<Bound<'py, PyAny>> {
+        let parsed = match mode {
+            Some(m) => Some(BrainMode::parse(m).ok_or_else(|| PyValueError::new_err(format!("unknown brain mode '{}'", m)))?),
+            None => None,
+        };
+        to_py(py, &self.inner.brain_io_schema(parsed))
+    }
+
+    // Extra brain inputs for one creature id or "*" (all); the list length sets the number of
+    // custom channels (changing it resizes every brain's input layer); ValueError for an unknown id
+    fn set_custom_inputs(&mut self, target: &str, values: Vec<f32>) -> PyResult<()> {
+        self.inner.set_custom_inputs(target, &values).map_err(PyValueError::new_err)
+    }
+
+    // Row-major cols x rows movement speed multipliers over the world, replacing the built-in
+    // terrain noise; ValueError if the size doesn't match
+    fn set_speed_grid(&mut self, cols: u32, rows: u32, values: Vec<f32>) -> PyResult<()> {
+        self.inner.set_speed_grid(cols, rows, &values).map_err(PyValueError::new_err)
+    }
+
+    fn clear_speed_grid(&mut self) { self.inner.clear_speed_grid(); }
+
+    // Row-major cols x rows heightmap (0..1) over the world, replacing the generated elevation;
+    // ValueError if the size doesn't match
+    fn set_elevation_grid(&mut self, cols: u32, rows: u32, values: Vec<f32>) -> PyResult<()> {
+        self.inner.set_elevation_grid(cols, rows, &values).map_err(PyValueError::new_err)
+    }
+
+    fn clear_elevation_grid(&mut self) { self.inner.clear_elevation_grid(); }
+
+    #[getter]
+    fn input_len(&self) -> u32 { self.inner.input_len() }
+
+    // Reset into a built-in scenario (e.g. "foodGradient") on the default config; ValueError for an unknown name
+    fn load_scenario(&mut self, name: &str) -> PyResult<()> { self.inner.load_scenario(name).map_err(PyValueError::new_err) }
+
+    fn rewind(&mut self, ticks: u64) -> bool { self.inner.rewind(ticks) }
+
+    // Merge a partial weights dict (lifespan, offspring, energyHarvested, kills, distanceTraveled,
+    // damageAvoided) into config["fitness"]; raises ValueError if invalid
+    fn set_fitness_weights(&mut self, py: Python<'_>, weights: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.inner.set_fitness_weights(&from_py::<serde_json::Value>(py, weights)?).map_err(PyValueError::new_err)
+    }
+
+    // Credit a host-resolved kill to a living creature; False if there is none
+    fn record_kill(&mut self, killer_id: &str) -> bool { self.inner.record_kill(killer_id) }
+
+    // Mutated copy of an exported brain dict using config["mutation"]
+    #[pyo3(signature = (brain, generation = 0))]
+    fn mutate_brain<'py>(&mut self, py: Python<'py>, brain: &Bound<'_, PyAny>, generation: u32) -> PyResult<Bound<'py, PyAny>> {
+        let b = from_py::<Brain>(py, brain)?;
+        to_py(py, &self.inner.mutate_brain(&b, generation))
+    }
+
+    // Recombined child of two brain dicts under config["crossover"]; None if off or incompatible
+    fn crossover_brains<'py>(&mut self, py: Python<'py>, a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let (a, b) = (from_py::<Brain>(py, a)?, from_py::<Brain>(py, b)?);
+        to_py(py, &self.inner.crossover_brains(&a, &b))
+    }
+
+    // Dead-neuron pruning / weight sparsification pass (see config pruneMode, sparsifyWeightBelow)
+    fn prune_brains<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.prune_brains()) }
+
+    // Events since the last call, as dicts with a "type" key
+    fn drain_events<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.drain_events()) }
+
+    // Latest notable moments (records, new species, extinctions), oldest first
+    fn highlights<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, self.inner.highlights()) }
+
+    // Energy conservation report for the latest tick; None unless config["energyAudit"]
+    fn energy_audit<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.energy_audit()) }
+
+    // Per-cell population and infected counts on a resolution x resolution grid
+    fn infection_grid<'py>(&self, py: Python<'py>, resolution: u32) -> PyResult<Bound<'py, PyAny>> { to_py(py, &self.inner.infection_grid(resolution)) }
+
+    fn state_hash(&self) -> String { format!("{:016x}", self.inner.state_hash()) }
+
+    // Full state as MessagePack bytes (same format as the wasm snapshot_msgpack)
+    fn snapshot<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        Ok(PyBytes::new(py, &self.inner.snapshot_msgpack().map_err(PyValueError::new_err)?))
+    }
+
+    fn load_snapshot(&mut self, data: &[u8]) -> PyResult<()> { self.inner.load_snapshot_msgpack(data).map_err(PyValueError::new_err) }
+
+    // [{ "id": ..., "brain": { layerSizes, weights, biases } }] for every living creature
+    fn export_brains<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        #[derive(Serialize)]
+        struct Entry<'a> { id: &'a str, brain: &'a Brain }
+        let v: Vec<Entry> = self.inner.creatures().iter().map(|c| Entry { id: &c.id, brain: &c.brain }).collect();
+        to_py(py, &v)
+    }
+
+    // Reward for recorded transitions: "energy" | "health" | "fitness" | "survival"; ValueError if unknown
+    fn set_reward_source(&mut self, mode: &str) -> PyResult<()> { self.inner.set_reward_source(mode).map_err(PyValueError::new_err) }
+
+    // [{ tick, inputs, outputs, reward, nextInputs, done }] recorded for `id` since the last call;
+    // the first call selects the creature for recording
+    fn collect_transitions<'py>(&mut self, py: Python<'py>, id: &str) -> PyResult<Bound<'py, PyAny>> {
+        to_py(py, &self.inner.collect_transitions(id))
+    }
+
+    // Replace a creature's brain with an exported/trained one (NaNs zeroed, extremes clamped);
+    // False if the id or topology doesn't fit
+    fn import_brain(&mut self, py: Python<'_>, id: &str, brain: &Bound<'_, PyAny>) -> PyResult<bool> {
+        Ok(self.inner.set_creature_brain(id, from_py::<Brain>(py, brain)?).is_ok())
+    }
+}
+
+fn to_py<'py, T: Serialize + ?Sized>(py: Python<'py>, value: &T) -> PyResult<Bound<'py, PyAny>> {
+    let text = serde_json::to_string(value).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    py.import("json")?.call_method1("loads", (text,))
+}
+
+fn from_py<T: serde::de::DeserializeOwned>(py: Python<'_>, obj: &Bound<'_, PyAny>) -> PyResult<T> {
+    let text: String = py.import("json")?.call_method1("dumps", (obj,))?.extract()?;
+    serde_json::from_str(&text).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+#[pymodule]
+fn ecosim_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<World>()?;
+    Ok(())
+}
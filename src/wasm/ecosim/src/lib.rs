@@ -1,550 +1,394 @@
+// wasm-bindgen layer over ecosim-core: JsValue/typed-array conversion and console logging only.
+// Simulation logic lives in the core crate. Invalid arguments and serialization failures surface
+// as thrown JS Errors (Result<_, JsError>) naming the export, never as panics.
+
 use wasm_bindgen::prelude::*;
-use serde::{Serialize, Deserialize};
-use std::collections::HashSet;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use ecosim_core::{Brain, BrainMode, Command, Config, ScheduledCommand, LEDGER_CATEGORIES, LEDGER_LEN, POSITION_STRIDE};
 
 #[wasm_bindgen]
 pub struct World {
-    width: f32,
-    height: f32,
-    tick: u64,
-    creatures: Vec<Creature>,
-    plants: Vec<Plant>,
-    corpses: Vec<Corpse>,
-    brain_mode: BrainMode,
-    rng: RngLCG,
-    bad_brain_hashes: HashSet<String>,
-    config: Config,
+    inner: ecosim_core::World,
 }
 
-// Simulation cost configuration (subset mirrored from JS simulationParams)
-#[derive(Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Config {
-    pub rest_stamina_regen_per_sec: f32,
-    pub rest_health_regen_per_sec: f32,
-    pub harvest_plant_action_cost_per_second: f32,
-    pub attack_cost_per_hit_stamina: f32,
-    pub sprint_overflow_cost_per_sec: f32,
-    pub posture_cost_per_sec: f32,
-    pub attack_cost_per_hit_energy: f32,
-    pub thirst_threshold: f32,
-    pub thirst_recovery_per_sec: f32,
-    pub drink_cost_per_second: f32,
-    pub move_cost_coeff_per_speed_per_sec: f32,
-    pub ambient_health_decay_per_sec: f32,
-    pub aging_health_decay_coeff: f32,
-    pub gestation_base_cost_per_sec: f32,
-    pub gestation_cost_per_offspring_per_sec: f32,
-    pub gestation_period: f32, // measured in 'frames' units since we advance timer by dt*60
-    pub birth_event_cost_energy: f32,
-    pub mutation_cost_energy_base: f32,
-    pub mutation_cost_per_std_change: f32,
-    // Telemetry thresholds (parity with JS simulationParams)
-    pub hunger_energy_threshold: f32,
-    pub fatigue_stamina_threshold: f32,
-    pub movement_threshold: f32,
-    pub stagnant_ticks_limit: u32,
-    // Environmental costs (parity scaffolding)
-    pub swim_energy_cost_per_sec: f32,
-    pub wind_drag_coeff: f32,
-    pub temp_cold_penalty_per_sec: f32,
-    pub temp_heat_penalty_per_sec: f32,
-    pub comfort_low_c: f32,
-    pub comfort_high_c: f32,
-    pub humidity_dehydration_coeff_per_sec: f32,
-    pub humidity_threshold: f32,
-    pub oxygen_thin_air_penalty_per_sec: f32,
-    pub thin_air_elevation_cutoff01: f32,
-    pub noise_stress_penalty_per_sec: f32,
-    pub disease_energy_drain_per_sec: f32,
-    // Corpse decay tunables
-    pub corpse_base_decay_per_sec: f32,
-    pub corpse_temp_decay_coeff: f32,
-    pub corpse_humidity_decay_coeff: f32,
-    pub corpse_rain_decay_coeff: f32,
-    pub corpse_wetness_decay_coeff: f32,
-}
+#[wasm_bindgen]
+impl World {
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: f32, height: f32, seed: u32) -> World {
+        init_host();
+        World { inner: ecosim_core::World::new(width, height, seed) }
+    }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            rest_stamina_regen_per_sec: 2.0,
-            rest_health_regen_per_sec: 0.2,
-            harvest_plant_action_cost_per_second: 0.02,
-            attack_cost_per_hit_stamina: 2.0,
-            sprint_overflow_cost_per_sec: 0.03,
-            posture_cost_per_sec: 0.005,
-            attack_cost_per_hit_energy: 0.04,
-            thirst_threshold: 30.0,
-            thirst_recovery_per_sec: 5.0,
-            drink_cost_per_second: 0.01,
-            move_cost_coeff_per_speed_per_sec: 0.02,
-            ambient_health_decay_per_sec: 0.02,
-            aging_health_decay_coeff: 0.5,
-            gestation_base_cost_per_sec: 0.02,
-            gestation_cost_per_offspring_per_sec: 0.015,
-            gestation_period: 60.0 * 20.0, // ~20s at 60fps
-            birth_event_cost_energy: 4.0,
-            mutation_cost_energy_base: 0.5,
-            mutation_cost_per_std_change: 0.8,
-            hunger_energy_threshold: 30.0,
-            fatigue_stamina_threshold: 30.0,
-            movement_threshold: 0.02,
-            stagnant_ticks_limit: 600,
-            // Environmental defaults (mostly disabled until configured from JS)
-            swim_energy_cost_per_sec: 0.0,
-            wind_drag_coeff: 0.0,
-            temp_cold_penalty_per_sec: 0.0,
-            temp_heat_penalty_per_sec: 0.0,
-            comfort_low_c: 10.0,
-            comfort_high_c: 28.0,
-            humidity_dehydration_coeff_per_sec: 0.0,
-            humidity_threshold: 0.7,
-            oxygen_thin_air_penalty_per_sec: 0.0,
-            thin_air_elevation_cutoff01: 0.8,
-            noise_stress_penalty_per_sec: 0.0,
-            disease_energy_drain_per_sec: 0.0,
-            corpse_base_decay_per_sec: 0.5,
-            corpse_temp_decay_coeff: 0.0,
-            corpse_humidity_decay_coeff: 0.0,
-            corpse_rain_decay_coeff: 0.0,
-            corpse_wetness_decay_coeff: 0.0,
-        }
+    // Advance by real elapsed seconds scaled by the time scale; no-op while paused. dt is clamped to
+    // config.maxFrameDtSec and split into bounded sub-steps (config.maxSubstepDtSec/maxSubsteps).
+    pub fn step(&mut self, real_dt: f32) {
+        self.inner.step(real_dt);
     }
-}
 
-fn nearest_carnivore(x: f32, y: f32, a: &[Creature], b: &[Creature]) -> Option<(f32,f32)> {
-    let mut best: Option<(f32,f32,f32)> = None;
-    for c in a.iter().chain(b.iter()) {
-        if c.diet != Diet::Carnivore { continue; }
-        let dx = c.x - x; let dy = c.y - y; let d = (dx*dx + dy*dy).sqrt();
-        match best { Some((_,_,bd)) if d >= bd => {}, _ => { best = Some((c.x, c.y, d)); } }
+    // step() that also returns births, deaths by cause, attacks, grazing, current counts, and phase
+    // timings summed over its sub-steps, so a frame needs no entity dumps
+    #[wasm_bindgen(js_name = step_with_summary, unchecked_return_type = "StepSummary")]
+    pub fn step_with_summary(&mut self, real_dt: f32) -> Result<JsValue, JsError> {
+        to_js("step_with_summary", &self.inner.step_with_summary(real_dt))
     }
-    best.map(|(x,y,_)| (x,y))
-}
 
-#[derive(Serialize, Deserialize, Clone)]
-pub struct Creature {
-    pub id: String,
-    pub x: f32,
-    pub y: f32,
-    pub vx: f32,
-    pub vy: f32,
-    pub radius: f32,
-    pub health: f32,
-    pub energy: f32,
-    pub stamina: f32,
-    pub max_stamina: f32,
-    pub thirst: f32, // 0..100, lower = thirstier
-    pub lifespan: u32,
-    pub diet: Diet,
-    pub brain: Brain,
-    // Reproduction (parity scaffolding)
-    pub is_pregnant: bool,
-    pub gestation_timer: f32,
-    pub offspring_count: u32,
-    // Telemetry
-    pub actions_mask: u32,
-    pub feelings_mask: u32,
-    pub stagnant_ticks: u32,
-    // Last-tick telemetry (not serialized in creatures_json)
-    #[serde(skip_serializing)] pub last_env_total: f32,
-    #[serde(skip_serializing)] pub last_env_swim: f32,
-    #[serde(skip_serializing)] pub last_env_wind: f32,
-    #[serde(skip_serializing)] pub last_env_cold: f32,
-    #[serde(skip_serializing)] pub last_env_heat: f32,
-    #[serde(skip_serializing)] pub last_env_humid: f32,
-    #[serde(skip_serializing)] pub last_env_oxy: f32,
-    #[serde(skip_serializing)] pub last_env_noise: f32,
-    #[serde(skip_serializing)] pub last_env_disease: f32,
-    #[serde(skip_serializing)] pub last_locomotion: f32,
-}
+    // Run a deterministic synthetic dense world for `ticks` ticks and report per-phase timings
+    // and ticks/sec (World.benchmark(...) in JS; does not touch any existing world)
+    #[wasm_bindgen(unchecked_return_type = "BenchmarkReport")]
+    pub fn benchmark(ticks: u32, population: u32) -> Result<JsValue, JsError> {
+        init_host();
+        to_js("benchmark", &ecosim_core::World::benchmark(ticks, population))
+    }
 
-#[derive(Serialize, Deserialize, Clone)]
-pub struct Plant {
-    pub x: f32,
-    pub y: f32,
-    pub radius: f32,
-}
+    // Run `config` (partial camelCase, defaults fill the rest) headless once per seed and return
+    // per-seed finals plus mean/CI and extinction rates (World.run_ensemble(...) in JS). Does not
+    // touch any existing world.
+    #[wasm_bindgen(js_name = run_ensemble, unchecked_return_type = "EnsembleReport")]
+    pub fn run_ensemble(#[wasm_bindgen(unchecked_param_type = "Partial<Config>")] config: JsValue, width: f32, height: f32, seeds: &[u32], ticks: u32) -> Result<JsValue, JsError> {
+        init_host();
+        let cfg: Config = from_js("run_ensemble: invalid config payload", config)?;
+        to_js("run_ensemble", &ecosim_core::World::run_ensemble(&cfg, (width, height), seeds, ticks))
+    }
 
-#[derive(Serialize, Deserialize, Clone)]
-pub struct Corpse {
-    pub x: f32,
-    pub y: f32,
-    pub radius: f32,
-    pub energy_remaining: f32,
-    pub initial_decay_time: f32,
-    pub decay_timer: f32,
-    // Last-tick decay telemetry (not serialized in corpses_json)
-    #[serde(skip_serializing)] pub last_decay_total: f32,
-    #[serde(skip_serializing)] pub last_decay_base: f32,
-    #[serde(skip_serializing)] pub last_decay_temp: f32,
-    #[serde(skip_serializing)] pub last_decay_humid: f32,
-    #[serde(skip_serializing)] pub last_decay_rain: f32,
-    #[serde(skip_serializing)] pub last_decay_wet: f32,
-}
+    // Run two configs on the same seeds and return paired B-minus-A differences with 95% intervals
+    // and effect sizes per outcome, plus both ensembles (World.compare_configs(...) in JS)
+    #[wasm_bindgen(js_name = compare_configs, unchecked_return_type = "ConfigComparison")]
+    pub fn compare_configs(
+        #[wasm_bindgen(unchecked_param_type = "Partial<Config>")] config_a: JsValue,
+        #[wasm_bindgen(unchecked_param_type = "Partial<Config>")] config_b: JsValue,
+        width: f32,
+        height: f32,
+        seeds: &[u32],
+        ticks: u32,
+    ) -> Result<JsValue, JsError> {
+        init_host();
+        let a: Config = from_js("compare_configs: invalid configA payload", config_a)?;
+        let b: Config = from_js("compare_configs: invalid configB payload", config_b)?;
+        to_js("compare_configs", &ecosim_core::World::compare_configs(&a, &b, (width, height), seeds, ticks))
+    }
 
-#[derive(Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Brain {
-    pub layer_sizes: Vec<u32>,
-    pub weights: Option<Vec<Vec<f32>>>,
-    pub biases: Option<Vec<Vec<f32>>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub activations: Option<Vec<Vec<f32>>>,
-}
+    // Bit position, mask, and name of every actions_mask/feelings_mask flag (World.flag_registry_json()
+    // in JS; the ActionFlag/FeelingFlag enums carry the same bit positions)
+    #[wasm_bindgen(js_name = flag_registry_json, unchecked_return_type = "FlagRegistry")]
+    pub fn flag_registry_json() -> Result<JsValue, JsError> {
+        to_js("flag_registry_json", &ecosim_core::World::flag_registry())
+    }
 
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[serde(rename_all = "PascalCase")]
-pub enum Diet { Herbivore, Carnivore }
+    // Built-in curriculum scenarios: [{ name, description }] (World.scenarios_json() in JS)
+    #[wasm_bindgen(js_name = scenarios_json, unchecked_return_type = "ScenarioInfo[]")]
+    pub fn scenarios_json() -> Result<JsValue, JsError> {
+        to_js("scenarios_json", ecosim_core::World::scenarios())
+    }
 
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[serde(rename_all = "PascalCase")]
-pub enum BrainMode { OG, Zegion }
+    #[wasm_bindgen(js_name = set_time_scale)]
+    pub fn set_time_scale(&mut self, scale: f32) { self.inner.set_time_scale(scale); }
 
-#[wasm_bindgen]
-impl World {
-    #[wasm_bindgen(constructor)]
-    pub fn new(width: f32, height: f32, seed: u32) -> World {
-        // Deterministic LCG RNG (parity with JS RNG)
-        let mut rng = RngLCG::new(seed);
-
-        let mut creatures = Vec::new();
-        let bad_brains: HashSet<String> = HashSet::new();
-        for i in 0..50 {
-            creatures.push(Creature{
-                id: format!("c{}", i),
-                x: rng.uniform(0.0, width),
-                y: rng.uniform(0.0, height),
-                vx: rng.uniform(-1.0, 1.0) * 2.0,
-                vy: rng.uniform(-1.0, 1.0) * 2.0,
-                radius: 5.0,
-                health: 100.0,
-                energy: 100.0,
-                stamina: 100.0,
-                max_stamina: 100.0,
-                thirst: 100.0,
-                lifespan: 0,
-                diet: if rng.next_f32_01() > 0.8 { Diet::Carnivore } else { Diet::Herbivore },
-                brain: init_brain_avoiding_bad(vec![14, 8, 8], &mut rng, &bad_brains),
-                is_pregnant: false,
-                gestation_timer: 0.0,
-                offspring_count: 1,
-                actions_mask: 0,
-                feelings_mask: 0,
-                stagnant_ticks: 0,
-                last_env_total: 0.0,
-                last_env_swim: 0.0,
-                last_env_wind: 0.0,
-                last_env_cold: 0.0,
-                last_env_heat: 0.0,
-                last_env_humid: 0.0,
-                last_env_oxy: 0.0,
-                last_env_noise: 0.0,
-                last_env_disease: 0.0,
-                last_locomotion: 0.0,
-            });
-        }
-        let mut plants = Vec::new();
-        for _ in 0..150 {
-            plants.push(Plant{ x: rng.uniform(0.0, width), y: rng.uniform(0.0, height), radius: 3.0 });
-        }
-        World { width, height, tick: 0, creatures, plants, corpses: Vec::new(), brain_mode: BrainMode::OG, rng, bad_brain_hashes: bad_brains, config: Config::default() }
-    }
-
-    pub fn step(&mut self, dt: f32) {
-        self.tick += 1;
-        // Simple behavior: herbivores drift, carnivores chase nearest herbivore
-        // Collect offspring to append after the main iteration to avoid borrow conflicts
-        let mut newborns: Vec<Creature> = Vec::new();
-        for i in 0..self.creatures.len() {
-            let (left, right) = self.creatures.split_at_mut(i);
-            // Split again to keep current creature disjoint from the rest to satisfy the borrow checker
-            let (cur_slice, rest) = right.split_at_mut(1);
-            let c = &mut cur_slice[0];
-            // Terrain influence reduces effective speed on rough terrain
-            let speed_mult = terrain_speed_at(c.x, c.y, self.tick);
-            // Build inputs and run brain forward pass to steer
-            let inputs = build_inputs(self.width, self.height, self.tick, c, left, &rest, self.brain_mode);
-            let (out, acts) = brain_forward(&mut c.brain, &inputs, self.brain_mode);
-            // Use outputs
-            let ax = out.get(0).cloned().unwrap_or(0.0).tanh();
-            let ay = out.get(1).cloned().unwrap_or(0.0).tanh();
-            let a_scale = (out.get(2).cloned().unwrap_or(0.0)).tanh().abs();
-            let eat_sig = out.get(3).cloned().unwrap_or(0.0).tanh();
-            let rest_sig = out.get(4).cloned().unwrap_or(0.0).tanh();
-            let boost_sig = out.get(5).cloned().unwrap_or(0.0).tanh();
-            let mut accel = 0.35 * speed_mult * (0.5 + a_scale);
-            let wants_boost = boost_sig > 0.5;
-            if wants_boost { accel *= 1.5; }
-            c.vx += ax * accel;
-            c.vy += ay * accel;
-            c.x += c.vx * dt * 60.0 * speed_mult;
-            c.y += c.vy * dt * 60.0 * speed_mult;
-            c.vx *= 0.99;
-            c.vy *= 0.99;
-            // Reset telemetry masks
-            c.actions_mask = 0;
-            c.feelings_mask = 0;
-            // Rest behavior: damp and regen small amounts
-            let wants_rest = rest_sig > 0.5;
-            if wants_rest {
-                c.vx *= 0.9;
-                c.vy *= 0.9;
-                // rest regen (scaled by config)
-                c.stamina = (c.stamina + self.config.rest_stamina_regen_per_sec * dt * 60.0).min(c.max_stamina);
-                if c.health < 100.0 { c.health = (c.health + self.config.rest_health_regen_per_sec * dt * 60.0).min(100.0); }
-                c.actions_mask |= 1 << 0; // RESTING
-            }
-            // Eat behavior: small trickle near a plant
-            let wants_eat = eat_sig > 0.5;
-            if wants_eat {
-                if plants_near(&self.plants, c.x, c.y, c.radius + 5.0) {
-                    // intake and action cost
-                    c.energy = (c.energy + 0.15).min(100.0);
-                    c.energy = (c.energy - self.config.harvest_plant_action_cost_per_second * dt * 60.0).max(0.0);
-                    c.actions_mask |= 1 << 1; // EATING
-                }
-            }
-            // Sprint energy drain
-            if wants_boost {
-                c.energy = (c.energy - 0.1).max(0.0);
-                c.stamina = (c.stamina - self.config.attack_cost_per_hit_stamina * 0.0).max(0.0); // placeholder, stamina not heavily used here
-                c.actions_mask |= 1 << 2; // SPRINTING
-            }
-            // Sprint overflow: if moving fast while boosting, extra cost
-            let speed_mag = (c.vx * c.vx + c.vy * c.vy).sqrt();
-            if wants_boost && speed_mag > 2.5 { c.energy = (c.energy - self.config.sprint_overflow_cost_per_sec * dt * 60.0).max(0.0); }
-            // Posture maintenance when nearly idle and not explicitly resting
-            if !wants_rest && speed_mag < 0.05 { c.energy = (c.energy - self.config.posture_cost_per_sec * dt * 60.0).max(0.0); }
-            // Attack attempt heuristic costs
-            // Offensive: carnivores boosting near herbivore target
-            if c.diet == Diet::Carnivore && wants_boost {
-                if let Some((_tx,_ty)) = nearest_herbivore(c.x, c.y, left, rest) {
-                    c.energy = (c.energy - self.config.attack_cost_per_hit_energy * dt * 60.0).max(0.0);
-                    c.actions_mask |= 1 << 3; // ATTACKING (attempt)
-                }
-            }
-            // Drinking when near plant: recover thirst, pay drink cost
-            if plants_near(&self.plants, c.x, c.y, c.radius + 5.0) {
-                let thirst_thresh = self.config.thirst_threshold;
-                if c.thirst < thirst_thresh {
-                    c.thirst = (c.thirst + self.config.thirst_recovery_per_sec * dt * 60.0).min(100.0);
-                    c.energy = (c.energy - self.config.drink_cost_per_second * dt * 60.0).max(0.0);
-                    c.actions_mask |= 1 << 4; // DRINKING
-                }
-            }
-            // Baseline movement energy (locomotion cost proportional to speed)
-            let locomotion = self.config.move_cost_coeff_per_speed_per_sec * speed_mag;
-            c.energy = (c.energy - locomotion * dt * 60.0).max(0.0);
-            // Environmental energy costs (simple samplers for parity scaffolding)
-            // NOTE: Keep these formulas 1:1 with the JS validator in useSimulationStore.ts.
-            // Units: all costs are per-second rates; we multiply by t_sec = dt*60 to apply.
-            // Components:
-            //  - Swim: flat penalty when inside heuristic water bands.
-            //  - Wind: proportional to wind speed and creature ground speed.
-            //  - Cold/Heat: linear penalties outside comfort range (degC scaled by 1/10 factor).
-            //  - Humidity: dehydration above threshold.
-            //  - Oxygen: thin air penalty above elevation cutoff.
-            //  - Noise: proportional to ambient noise.
-            //  - Disease: flat drain.
-            let t_sec = dt * 60.0;
-            let temp_c = sample_temperature_c(c.x, c.y, self.tick);
-            let humid01 = sample_humidity01(c.x, c.y, self.tick);
-            let rain01 = sample_rain01(c.x, c.y, self.tick);
-            let wet01 = sample_wetness01(c.x, c.y, self.tick);
-            let wind = sample_wind_speed(c.x, c.y, self.tick);
-            let elev01 = sample_elevation01(c.x, c.y);
-            let noise01 = sample_noise01(c.x, c.y, self.tick);
-            // Swim heuristic: treat top/bottom bands as water
-            let in_water = c.y < self.height * 0.12 || c.y > self.height * 0.88;
-            let env_swim = if in_water { self.config.swim_energy_cost_per_sec } else { 0.0 };
-            let env_wind = self.config.wind_drag_coeff * wind * speed_mag;
-            let env_cold = if temp_c < self.config.comfort_low_c {
-                let d = (self.config.comfort_low_c - temp_c).max(0.0);
-                self.config.temp_cold_penalty_per_sec * d / 10.0
-            } else { 0.0 };
-            let env_heat = if temp_c > self.config.comfort_high_c {
-                let d = (temp_c - self.config.comfort_high_c).max(0.0);
-                self.config.temp_heat_penalty_per_sec * d / 10.0
-            } else { 0.0 };
-            let env_humid = if humid01 > self.config.humidity_threshold {
-                let ex = (humid01 - self.config.humidity_threshold).max(0.0);
-                self.config.humidity_dehydration_coeff_per_sec * ex
-            } else { 0.0 };
-            let env_oxy = if elev01 > self.config.thin_air_elevation_cutoff01 {
-                let ex = (elev01 - self.config.thin_air_elevation_cutoff01).max(0.0);
-                self.config.oxygen_thin_air_penalty_per_sec * ex
-            } else { 0.0 };
-            let env_noise = self.config.noise_stress_penalty_per_sec * noise01;
-            let env_disease = self.config.disease_energy_drain_per_sec;
-            let env_total = env_swim + env_wind + env_cold + env_heat + env_humid + env_oxy + env_noise + env_disease;
-            // Record telemetry
-            c.last_locomotion = locomotion;
-            c.last_env_total = env_total;
-            c.last_env_swim = env_swim;
-            c.last_env_wind = env_wind;
-            c.last_env_cold = env_cold;
-            c.last_env_heat = env_heat;
-            c.last_env_humid = env_humid;
-            c.last_env_oxy = env_oxy;
-            c.last_env_noise = env_noise;
-            c.last_env_disease = env_disease;
-            if env_total != 0.0 { c.energy = (c.energy - env_total * t_sec).max(0.0); }
-            // Ambient health decay with aging
-            let max_life = 60.0 * 60.0 * 60.0; // ~60 minutes at 60fps equivalent
-            let age_norm = (c.lifespan as f32 / max_life).clamp(0.0, 1.0);
-            let ambient = self.config.ambient_health_decay_per_sec * (1.0 + self.config.aging_health_decay_coeff * age_norm);
-            if !wants_rest { c.health = (c.health - ambient * dt * 60.0).max(0.0); }
-            // Gestation per-second cost and birth handling
-            if c.is_pregnant {
-                let oc = c.offspring_count.max(1) as f32;
-                let gest_e = self.config.gestation_base_cost_per_sec + self.config.gestation_cost_per_offspring_per_sec * oc;
-                c.energy = (c.energy - gest_e * dt * 60.0).max(-50.0);
-                c.gestation_timer += dt * 60.0;
-                if c.gestation_timer >= self.config.gestation_period {
-                    // Birth energy cost
-                    c.energy = (c.energy - self.config.birth_event_cost_energy).max(-50.0);
-                    // Mutation energy cost approximation (no genes here): base + per-offspring scaled by small random factor
-                    let mut mut_cost = self.config.mutation_cost_energy_base;
-                    let rand_factor = 0.5 + self.rng.next_f32_01(); // 0.5..1.5
-                    mut_cost += self.config.mutation_cost_per_std_change * oc * rand_factor;
-                    c.energy = (c.energy - mut_cost).max(-50.0);
-                    // Spawn offspring near parent with small jitter
-                    for k in 0..c.offspring_count.max(1) {
-                        let angle = (k as f32) * 0.7 + self.rng.next_f32_01() * 6.2831;
-                        let r = 4.0 + self.rng.next_f32_01() * 6.0;
-                        let nx = (c.x + angle.cos() * r).clamp(0.0, self.width);
-                        let ny = (c.y + angle.sin() * r).clamp(0.0, self.height);
-                        let id = format!("c{}", self.tick + k as u64);
-                        let diet = c.diet; // inherit diet
-                        let layer_sizes = match self.brain_mode { BrainMode::OG => vec![14, 8, 8], BrainMode::Zegion => vec![24, 16, 6] };
-                        let brain = init_brain_avoiding_bad(layer_sizes, &mut self.rng, &self.bad_brain_hashes);
-                        newborns.push(Creature{
-                            id,
-                            x: nx, y: ny,
-                            vx: self.rng.uniform(-0.5, 0.5), vy: self.rng.uniform(-0.5, 0.5),
-                            radius: 4.0,
-                            health: 100.0,
-                            energy: 80.0,
-                            stamina: 100.0,
-                            max_stamina: 100.0,
-                            thirst: 100.0,
-                            lifespan: 0,
-                            diet,
-                            brain,
-                            is_pregnant: false,
-                            gestation_timer: 0.0,
-                            offspring_count: 1,
-                            actions_mask: 0,
-                            feelings_mask: 0,
-                            stagnant_ticks: 0,
-                            last_env_total: 0.0,
-                            last_env_swim: 0.0,
-                            last_env_wind: 0.0,
-                            last_env_cold: 0.0,
-                            last_env_heat: 0.0,
-                            last_env_humid: 0.0,
-                            last_env_oxy: 0.0,
-                            last_env_noise: 0.0,
-                            last_env_disease: 0.0,
-                            last_locomotion: 0.0,
-                        });
-                    }
-                    // Reset pregnancy
-                    c.is_pregnant = false;
-                    c.gestation_timer = 0.0;
-                    c.offspring_count = 1;
-                }
-            }
-            wrap(&mut c.x, self.width);
-            wrap(&mut c.y, self.height);
-            // Clamp vital ranges
-            c.energy = c.energy.clamp(-50.0, 100.0);
-            c.health = c.health.clamp(0.0, 100.0);
-            // Age increment (ticks)
-            c.lifespan = c.lifespan.saturating_add(1);
-            // Store activations for visualization
-            c.brain.activations = Some(acts);
-            // Feelings telemetry based on thresholds
-            if c.thirst < self.config.thirst_threshold { c.feelings_mask |= 1 << 0; } // THIRSTY
-            if c.energy < self.config.hunger_energy_threshold { c.feelings_mask |= 1 << 1; } // HUNGRY
-            if c.stamina < self.config.fatigue_stamina_threshold { c.feelings_mask |= 1 << 2; } // FATIGUED
-            // Restless: track stagnant ticks based on speed
-            let speed = (c.vx * c.vx + c.vy * c.vy).sqrt();
-            if speed < self.config.movement_threshold { c.stagnant_ticks = c.stagnant_ticks.saturating_add(1); } else { c.stagnant_ticks = 0; }
-            if c.stagnant_ticks >= self.config.stagnant_ticks_limit { c.feelings_mask |= 1 << 3; } // RESTLESS
-        }
-        // Append any newborn creatures after processing all current ones
-        if !newborns.is_empty() {
-            self.creatures.extend(newborns);
-        }
-        // Remove dead into corpses
-        let mut alive = Vec::with_capacity(self.creatures.len());
-        for c in self.creatures.drain(..) {
-            if c.health <= 0.0 || c.energy <= 0.0 {
-                self.corpses.push(Corpse{
-                    x: c.x, y: c.y, radius: c.radius,
-                    energy_remaining: c.energy.max(0.0),
-                    initial_decay_time: 100.0,
-                    decay_timer: 100.0,
-                    last_decay_total: 0.0,
-                    last_decay_base: 0.0,
-                    last_decay_temp: 0.0,
-                    last_decay_humid: 0.0,
-                    last_decay_rain: 0.0,
-                    last_decay_wet: 0.0,
-                });
-            } else {
-                alive.push(c);
-            }
+    #[wasm_bindgen(js_name = time_scale)]
+    pub fn time_scale(&self) -> f32 { self.inner.time_scale() }
+
+    #[wasm_bindgen(js_name = set_paused)]
+    pub fn set_paused(&mut self, paused: bool) { self.inner.set_paused(paused); }
+
+    #[wasm_bindgen(js_name = is_paused)]
+    pub fn is_paused(&self) -> bool { self.inner.is_paused() }
+
+    #[wasm_bindgen(unchecked_return_type = "Creature[]")]
+    pub fn creatures_json(&self) -> Result<JsValue, JsError> { self.timed_to_value("creatures_json", self.inner.creatures()) }
+
+    #[wasm_bindgen(unchecked_return_type = "Plant[]")]
+    pub fn plants_json(&self) -> Result<JsValue, JsError> { self.timed_to_value("plants_json", self.inner.plants()) }
+
+    #[wasm_bindgen(unchecked_return_type = "Corpse[]")]
+    pub fn corpses_json(&self) -> Result<JsValue, JsError> { self.timed_to_value("corpses_json", self.inner.corpses()) }
+
+    // MessagePack alternatives to the *_json exports (same field names; decode with a msgpack lib)
+    #[wasm_bindgen(js_name = creatures_msgpack)]
+    pub fn creatures_msgpack(&self) -> Result<Vec<u8>, JsError> {
+        self.inner.creatures_msgpack().map_err(|e| JsError::new(&format!("creatures_msgpack: {}", e)))
+    }
+
+    #[wasm_bindgen(js_name = plants_msgpack)]
+    pub fn plants_msgpack(&self) -> Result<Vec<u8>, JsError> {
+        self.inner.plants_msgpack().map_err(|e| JsError::new(&format!("plants_msgpack: {}", e)))
+    }
+
+    #[wasm_bindgen(js_name = corpses_msgpack)]
+    pub fn corpses_msgpack(&self) -> Result<Vec<u8>, JsError> {
+        self.inner.corpses_msgpack().map_err(|e| JsError::new(&format!("corpses_msgpack: {}", e)))
+    }
+
+    // Full simulation state as MessagePack, restorable with load_snapshot_msgpack
+    #[wasm_bindgen(js_name = snapshot_msgpack)]
+    pub fn snapshot_msgpack(&self) -> Result<Vec<u8>, JsError> {
+        self.inner.snapshot_msgpack().map_err(|e| JsError::new(&format!("snapshot_msgpack: {}", e)))
+    }
+
+    // Replace the world with a snapshot_msgpack payload (older formats are migrated);
+    // clears rewind/lockstep history. Throws (world unchanged) for an unreadable payload.
+    #[wasm_bindgen(js_name = load_snapshot_msgpack)]
+    pub fn load_snapshot_msgpack(&mut self, bytes: &[u8]) -> Result<(), JsError> {
+        self.inner.load_snapshot_msgpack(bytes).map_err(|e| JsError::new(&format!("load_snapshot_msgpack: {}", e)))
+    }
+
+    // Per-creature behaviour descriptors and novelty scores (config.novelty)
+    #[wasm_bindgen(js_name = behavior_descriptors_json, unchecked_return_type = "BehaviorDescriptor[]")]
+    pub fn behavior_descriptors_json(&self) -> Result<JsValue, JsError> {
+        to_js("behavior_descriptors_json", &self.inner.behavior_descriptors())
+    }
+
+    // Elite archive, fittest first (same shape as creatures_json; living elites included)
+    #[wasm_bindgen(js_name = elites_json, unchecked_return_type = "Creature[]")]
+    pub fn elites_json(&self) -> Result<JsValue, JsError> { self.timed_to_value("elites_json", self.inner.elites()) }
+
+    #[wasm_bindgen(unchecked_return_type = "Burrow[]")]
+    pub fn burrows_json(&self) -> Result<JsValue, JsError> { self.timed_to_value("burrows_json", self.inner.burrows()) }
+
+    // Decomposer colonies (config.decomposers); soil litter and fertility are env_grid_json fields
+    #[wasm_bindgen(unchecked_return_type = "Decomposer[]")]
+    pub fn decomposers_json(&self) -> Result<JsValue, JsError> { self.timed_to_value("decomposers_json", self.inner.decomposers()) }
+
+    // Loose food items: placed food, fallen fruit, and spawn_food drops (config.foodItems)
+    #[wasm_bindgen(unchecked_return_type = "FoodItem[]")]
+    pub fn food_items_json(&self) -> Result<JsValue, JsError> { self.timed_to_value("food_items_json", self.inner.food_items()) }
+
+    // Population/vitals summary for the current tick
+    #[wasm_bindgen(js_name = stats_json, unchecked_return_type = "WorldStats")]
+    pub fn stats_json(&self) -> Result<JsValue, JsError> {
+        to_js("stats_json", &self.inner.stats())
+    }
+
+    // Advance `ticks` fixed 1/60s ticks with no per-frame exports (ignores pause/time scale).
+    // Returns a stats summary every `report_every` ticks plus one for the final tick.
+    #[wasm_bindgen(js_name = run_headless, unchecked_return_type = "WorldStats[]")]
+    pub fn run_headless(&mut self, ticks: u32, report_every: u32) -> Result<JsValue, JsError> {
+        to_js("run_headless", &self.inner.run_headless(ticks, report_every))
+    }
+
+    // Advance fixed 1/60s ticks until any condition holds ({ type: "populationBelow", count, diet? },
+    // { type: "tickReached", tick }, { type: "firstExtinction" }, { type: "speciesCountChanges" }, or
+    // an array of them) or `max_ticks` run out; returns the stopping reason and final stats
+    #[wasm_bindgen(js_name = step_until, unchecked_return_type = "StepUntilOutcome")]
+    pub fn step_until(&mut self, #[wasm_bindgen(unchecked_param_type = "StopCondition | StopCondition[]")] conditions: JsValue, max_ticks: u32) -> Result<JsValue, JsError> {
+        let value: serde_json::Value = from_js("step_until: invalid conditions payload", conditions)?;
+        let conditions = ecosim_core::parse_stop_conditions(value).map_err(|e| JsError::new(&format!("step_until: invalid conditions payload: {}", e)))?;
+        to_js("step_until", &self.inner.step_until(&conditions, max_ticks))
+    }
+
+    // Jump back `ticks` ticks via the snapshot ring; returns false if no snapshot is old enough
+    pub fn rewind(&mut self, ticks: u32) -> bool { self.inner.rewind(ticks as u64) }
+
+    // Fork a ghost world from the current state with `overrides` (partial camelCase config) applied
+    // on top of the current config. It then steps in lockstep with this world and receives the same
+    // commands, spawns, custom inputs, and brain outputs.
+    #[wasm_bindgen(js_name = start_ghost)]
+    pub fn start_ghost(&mut self, #[wasm_bindgen(unchecked_param_type = "Partial<Config>")] overrides: JsValue) -> Result<(), JsError> {
+        let patch: serde_json::Value = from_js("start_ghost: invalid overrides payload", overrides)?;
+        self.inner.start_ghost(&patch).map_err(|e| JsError::new(&format!("start_ghost: {}", e)))
+    }
+
+    #[wasm_bindgen(js_name = stop_ghost)]
+    pub fn stop_ghost(&mut self) { self.inner.stop_ghost(); }
+
+    // Ghost minus main differences plus both stat summaries; null when no ghost is running
+    #[wasm_bindgen(js_name = ghost_divergence_json, unchecked_return_type = "GhostDivergence | null")]
+    pub fn ghost_divergence_json(&self) -> Result<JsValue, JsError> {
+        match self.inner.ghost_divergence() {
+            Some(d) => to_js("ghost_divergence_json", &d),
+            None => Ok(JsValue::NULL),
         }
-        self.creatures = alive;
-        // Decay corpses
-        // NOTE: Rate = base + sum(component contributions). Each component is a fraction of base
-        // controlled by its coefficient and an environmental scalar.
-        for co in &mut self.corpses {
-            let temp_c = sample_temperature_c(co.x, co.y, self.tick);
-            let humid01 = sample_humidity01(co.x, co.y, self.tick);
-            let rain01 = sample_rain01(co.x, co.y, self.tick);
-            let wet01 = sample_wetness01(co.x, co.y, self.tick);
-            // Componentized decay rate contributions (per second)
-            let base = self.config.corpse_base_decay_per_sec.max(0.0);
-            let temp_term = (temp_c - 20.0) / 15.0; // >0 when warmer than ~room temp
-            // Warmer-than-room temperature accelerates decay; clamp 0..2 for stability
-            let contrib_temp = base * self.config.corpse_temp_decay_coeff * temp_term.clamp(0.0, 2.0);
-            let contrib_humid = base * self.config.corpse_humidity_decay_coeff * humid01.max(0.0);
-            let contrib_rain = base * self.config.corpse_rain_decay_coeff * rain01.max(0.0);
-            let contrib_wet = base * self.config.corpse_wetness_decay_coeff * wet01.max(0.0);
-            let rate = (base + contrib_temp + contrib_humid + contrib_rain + contrib_wet).max(0.0);
-            // Telemetry record
-            co.last_decay_base = base;
-            co.last_decay_temp = contrib_temp.max(0.0);
-            co.last_decay_humid = contrib_humid.max(0.0);
-            co.last_decay_rain = contrib_rain.max(0.0);
-            co.last_decay_wet = contrib_wet.max(0.0);
-            co.last_decay_total = rate;
-            co.decay_timer -= dt * 60.0 * rate;
-            if co.decay_timer < 0.0 { co.decay_timer = 0.0; }
+    }
+
+    // Schedule a command ({ type: "spawnCreature" | "spawnPlant" | "setBrainMode" | "setConfig", ... })
+    // for `tick`; it runs before that tick advances. Returns the sequence id; throws for an invalid
+    // command or a past tick.
+    #[wasm_bindgen(js_name = queue_command)]
+    pub fn queue_command(&mut self, tick: f64, #[wasm_bindgen(unchecked_param_type = "Command")] command: JsValue) -> Result<f64, JsError> {
+        let cmd: Command = from_js("queue_command: invalid command", command)?;
+        let tick = tick.max(0.0) as u64;
+        match self.inner.queue_command(tick, cmd) {
+            Some(seq) => Ok(seq as f64),
+            None => Err(JsError::new(&format!("queue_command: tick {} is in the past (current {})", tick, self.inner.tick()))),
         }
-        self.corpses.retain(|c| c.decay_timer > 0.0);
     }
 
-    pub fn creatures_json(&self) -> JsValue {
-        serde_wasm_bindgen::to_value(&self.creatures).unwrap()
+    // Applied commands in application order (serializable; feed to load_command_log on a peer)
+    #[wasm_bindgen(js_name = command_log_json, unchecked_return_type = "ScheduledCommand[]")]
+    pub fn command_log_json(&self) -> Result<JsValue, JsError> {
+        to_js("command_log_json", self.inner.command_log())
+    }
+
+    // Queue every entry of a command log that is not yet in the past; returns how many were queued
+    #[wasm_bindgen(js_name = load_command_log)]
+    pub fn load_command_log(&mut self, #[wasm_bindgen(unchecked_param_type = "ScheduledCommand[]")] log_json: JsValue) -> Result<u32, JsError> {
+        let entries: Vec<ScheduledCommand> = from_js("load_command_log: invalid payload", log_json)?;
+        Ok(self.inner.load_command_log(entries))
+    }
+
+    // Hex hash of the current simulation state
+    #[wasm_bindgen(js_name = state_hash)]
+    pub fn state_hash(&self) -> String { format!("{:016x}", self.inner.state_hash()) }
+
+    // Recent per-tick hashes [{ tick, hash }] (requires config.stateHashHistory > 0)
+    #[wasm_bindgen(js_name = state_hashes_json)]
+    pub fn state_hashes_json(&self) -> Result<JsValue, JsError> {
+        #[derive(Serialize)]
+        struct TickHash { tick: u64, hash: String }
+        let v: Vec<TickHash> = self.inner.state_hashes().map(|(tick, h)| TickHash { tick, hash: format!("{:016x}", h) }).collect();
+        to_js("state_hashes_json", &v)
+    }
+
+    // Oldest tick reachable by rewind (or the current tick when history is empty)
+    #[wasm_bindgen(js_name = rewind_horizon)]
+    pub fn rewind_horizon(&self) -> f64 { self.inner.rewind_horizon() as f64 }
+
+    // Accumulated step-phase timings (ms) since construction or the last reset_perf()
+    #[wasm_bindgen(js_name = perf_json, unchecked_return_type = "PerfReport")]
+    pub fn perf_json(&self) -> Result<JsValue, JsError> {
+        to_js("perf_json", &self.inner.perf())
+    }
+
+    #[wasm_bindgen(js_name = reset_perf)]
+    pub fn reset_perf(&mut self) { self.inner.reset_perf(); }
+
+    // Cheap metadata getters (avoid deserializing full entity dumps)
+    // Tick as f64 so JS receives a plain number rather than a BigInt
+    pub fn tick(&self) -> f64 { self.inner.tick() as f64 }
+
+    // Tick of the frame the entity exports serve (lags tick() only with doubleBufferedExports)
+    #[wasm_bindgen(js_name = frame_tick)]
+    pub fn frame_tick(&self) -> f64 { self.inner.frame_tick() as f64 }
+
+    #[wasm_bindgen(js_name = creature_count)]
+    pub fn creature_count(&self) -> u32 { self.inner.creatures().len() as u32 }
+
+    #[wasm_bindgen(js_name = plant_count)]
+    pub fn plant_count(&self) -> u32 { self.inner.plants().len() as u32 }
+
+    #[wasm_bindgen(js_name = corpse_count)]
+    pub fn corpse_count(&self) -> u32 { self.inner.corpses().len() as u32 }
+
+    pub fn width(&self) -> f32 { self.inner.width() }
+
+    pub fn height(&self) -> f32 { self.inner.height() }
+
+    #[wasm_bindgen(js_name = brain_mode)]
+    pub fn brain_mode(&self) -> String {
+        match self.inner.brain_mode() { BrainMode::OG => "OG".to_string(), BrainMode::Zegion => "Zegion".to_string() }
+    }
+
+    // Approximate heap + inline bytes held by each entity collection (capacity-based)
+    #[wasm_bindgen(js_name = memory_report_json, unchecked_return_type = "MemoryReport")]
+    pub fn memory_report_json(&self) -> Result<JsValue, JsError> {
+        to_js("memory_report_json", &self.inner.memory_report())
+    }
+
+    // Sample one environment field on a resolution x resolution grid (row-major, cell centers)
+    // field: "temperature" | "humidity" | "rain" | "wetness" | "wind" | "elevation" | "noise" | "scent" | "speed" |
+    // "currentX" | "currentY" | "plantGrowth" | "litter" | "fertility"
+    #[wasm_bindgen(js_name = env_grid_json)]
+    pub fn env_grid_json(&self, field: &str, resolution: u32) -> js_sys::Float32Array {
+        js_sys::Float32Array::from(&self.inner.env_grid(field, resolution)[..])
+    }
+
+    // Decaying creature-density heatmap (config.density) for layer "herbivore" | "carnivore" | "all":
+    // density_resolution() x density_resolution() row-major cells of time spent there. Empty while
+    // disabled; throws for an unknown layer.
+    #[wasm_bindgen(js_name = density_f32)]
+    pub fn density_f32(&self, layer: &str) -> Result<js_sys::Float32Array, JsError> {
+        let grid = self.inner.density_grid(layer)
+            .ok_or_else(|| JsError::new(&format!("density_f32: unknown layer '{}' (expected herbivore, carnivore, or all)", layer)))?;
+        Ok(js_sys::Float32Array::from(&grid[..]))
     }
 
-    pub fn plants_json(&self) -> JsValue {
-        serde_wasm_bindgen::to_value(&self.plants).unwrap()
+    #[wasm_bindgen(js_name = density_resolution)]
+    pub fn density_resolution(&self) -> u32 { self.inner.density_resolution() }
+
+    // Replace the built-in terrain speed noise with a cols x rows row-major grid of movement speed
+    // multipliers stretched over the world (roads, mud, rock fields); throws if the size doesn't match
+    #[wasm_bindgen(js_name = set_speed_grid)]
+    pub fn set_speed_grid(&mut self, cols: u32, rows: u32, values: &[f32]) -> Result<(), JsError> {
+        self.inner.set_speed_grid(cols, rows, values).map_err(|e| JsError::new(&format!("set_speed_grid: {}", e)))
     }
 
-    pub fn corpses_json(&self) -> JsValue {
-        serde_wasm_bindgen::to_value(&self.corpses).unwrap()
+    #[wasm_bindgen(js_name = clear_speed_grid)]
+    pub fn clear_speed_grid(&mut self) { self.inner.clear_speed_grid(); }
+
+    // Replace the generated elevation with a cols x rows row-major heightmap (0..1) stretched over
+    // the world; drives temperature, slopes, biomes and thin air. Throws if the size doesn't match
+    #[wasm_bindgen(js_name = set_elevation_grid)]
+    pub fn set_elevation_grid(&mut self, cols: u32, rows: u32, values: &[f32]) -> Result<(), JsError> {
+        self.inner.set_elevation_grid(cols, rows, values).map_err(|e| JsError::new(&format!("set_elevation_grid: {}", e)))
+    }
+
+    #[wasm_bindgen(js_name = clear_elevation_grid)]
+    pub fn clear_elevation_grid(&mut self) { self.inner.clear_elevation_grid(); }
+
+    // All environment values at a point for the current tick (tooltips / JS parity checks)
+    #[wasm_bindgen(js_name = sample_env, unchecked_return_type = "EnvSample")]
+    pub fn sample_env(&self, x: f32, y: f32) -> Result<JsValue, JsError> {
+        to_js("sample_env", &self.inner.sample_env(x, y))
+    }
+
+    // Creature counts, plant biomass, and mean vitals for each biome (all six, in a fixed order)
+    #[wasm_bindgen(js_name = biome_stats_json, unchecked_return_type = "BiomeStats[]")]
+    pub fn biome_stats_json(&self) -> Result<JsValue, JsError> {
+        to_js("biome_stats_json", &self.inner.biome_stats())
+    }
+
+    // Estimated herbivore/carnivore capacity per biome and the population's load against it
+    #[wasm_bindgen(js_name = carrying_capacity_json, unchecked_return_type = "CarryingCapacity")]
+    pub fn carrying_capacity_json(&self) -> Result<JsValue, JsError> {
+        to_js("carrying_capacity_json", &self.inner.carrying_capacity())
+    }
+
+    // Capacity vs population samples, oldest first; empty unless config.carryingCapacity is enabled
+    #[wasm_bindgen(js_name = capacity_history_json, unchecked_return_type = "CapacitySample[]")]
+    pub fn capacity_history_json(&self) -> Result<JsValue, JsError> {
+        to_js("capacity_history_json", &self.inner.capacity_history())
+    }
+
+    // Quadrant crossings per season, oldest first, ending with the season under way; empty unless config.migration is enabled
+    #[wasm_bindgen(js_name = migration_history_json, unchecked_return_type = "QuadrantMovement[]")]
+    pub fn migration_history_json(&self) -> Result<JsValue, JsError> {
+        to_js("migration_history_json", &self.inner.migration_history())
+    }
+
+    // "spring" | "summer" | "autumn" | "winter", or null while config.seasons is off
+    #[wasm_bindgen(js_name = season, unchecked_return_type = "Season | undefined")]
+    pub fn season(&self) -> Result<JsValue, JsError> {
+        to_js("season", &self.inner.season())
+    }
+
+    // Upcoming rain/temperature/wind at up to 8 ticks spread over the next ticks_ahead (8x8 cells
+    // per frame); the weather is seed-driven, so this is exact and leaves the world untouched
+    #[wasm_bindgen(js_name = forecast_json, unchecked_return_type = "Forecast")]
+    pub fn forecast_json(&self, ticks_ahead: u32) -> Result<JsValue, JsError> {
+        to_js("forecast_json", &self.inner.forecast(ticks_ahead as u64))
+    }
+
+    // Recent positions (oldest first) for `ids`, or every creature for an empty array; empty unless
+    // config.trails is enabled
+    #[wasm_bindgen(js_name = trails_json, unchecked_return_type = "Trail[]")]
+    pub fn trails_json(&self, #[wasm_bindgen(unchecked_param_type = "string[]")] ids: JsValue) -> Result<JsValue, JsError> {
+        let ids: Vec<String> = from_js("trails_json: ids must be a string array", ids)?;
+        to_js("trails_json", &self.inner.trails(&ids))
+    }
+
+    // Follow one creature with per-tick telemetry (null stops); only it keeps brain activations
+    #[wasm_bindgen(js_name = watch_creature)]
+    pub fn watch_creature(&mut self, id: Option<String>) { self.inner.watch_creature(id); }
+
+    // Recent per-tick records for the watched creature (oldest first), or null when none is watched
+    #[wasm_bindgen(js_name = watched_creature_json, unchecked_return_type = "WatchedCreature | null")]
+    pub fn watched_creature_json(&self) -> Result<JsValue, JsError> {
+        to_js("watched_creature_json", &self.inner.watched_creature())
     }
 
     // Minimal environmental cost telemetry for validation/parity checks
     #[wasm_bindgen(js_name = env_costs_json)]
-    pub fn env_costs_json(&self) -> JsValue {
+    pub fn env_costs_json(&self) -> Result<JsValue, JsError> {
         #[derive(Serialize)]
         #[serde(rename_all = "camelCase")]
         struct EnvCost<'a> {
@@ -558,9 +402,10 @@ impl World {
             env_oxy: f32,
             env_noise: f32,
             env_disease: f32,
+            env_slope: f32,
             locomotion: f32,
         }
-        let v: Vec<EnvCost> = self.creatures.iter().map(|c| EnvCost{
+        let v: Vec<EnvCost> = self.inner.creatures().iter().map(|c| EnvCost{
             id: &c.id,
             env_total: c.last_env_total,
             env_swim: c.last_env_swim,
@@ -571,14 +416,15 @@ impl World {
             env_oxy: c.last_env_oxy,
             env_noise: c.last_env_noise,
             env_disease: c.last_env_disease,
+            env_slope: c.last_env_slope,
             locomotion: c.last_locomotion,
         }).collect();
-        serde_wasm_bindgen::to_value(&v).unwrap()
+        to_js("env_costs_json", &v)
     }
 
     // Minimal corpse decay telemetry for validation/parity checks
     #[wasm_bindgen(js_name = corpse_costs_json)]
-    pub fn corpse_costs_json(&self) -> JsValue {
+    pub fn corpse_costs_json(&self) -> Result<JsValue, JsError> {
         #[derive(Serialize)]
         #[serde(rename_all = "camelCase")]
         struct CorpseCost {
@@ -589,7 +435,7 @@ impl World {
             rain: f32,
             wet: f32,
         }
-        let v: Vec<CorpseCost> = self.corpses.iter().map(|co| CorpseCost{
+        let v: Vec<CorpseCost> = self.inner.corpses().iter().map(|co| CorpseCost{
             total: co.last_decay_total,
             base: co.last_decay_base,
             temp: co.last_decay_temp,
@@ -597,392 +443,325 @@ impl World {
             rain: co.last_decay_rain,
             wet: co.last_decay_wet,
         }).collect();
-        serde_wasm_bindgen::to_value(&v).unwrap()
+        to_js("corpse_costs_json", &v)
     }
 
-    // Spawn a single creature at a specific location (diet randomized)
-    #[wasm_bindgen(js_name = spawn_creature)]
-    pub fn spawn_creature(&mut self, x: f32, y: f32) {
-        let id = format!("c{}", self.tick); // coarse unique-ish id based on tick
-        let diet = if self.rng.next_f32_01() > 0.8 { Diet::Carnivore } else { Diet::Herbivore };
-        let layer_sizes = match self.brain_mode {
-            BrainMode::OG => vec![14, 8, 8],
-            BrainMode::Zegion => vec![24, 16, 6],
+    // Per-tick energy ledger as a flat Float32Array: creatures_json order, LEDGER_LEN values per creature
+    #[wasm_bindgen(js_name = energy_ledger_f32)]
+    pub fn energy_ledger_f32(&self) -> js_sys::Float32Array {
+        let mut flat: Vec<f32> = Vec::with_capacity(self.inner.creatures().len() * LEDGER_LEN);
+        for c in self.inner.creatures() { flat.extend_from_slice(&c.ledger.0); }
+        js_sys::Float32Array::from(&flat[..])
+    }
+
+    // Zero-copy views into wasm memory, refreshed at the end of every step() in creatures_json order:
+    //   new Float32Array(World.memory().buffer, w.positions_ptr(), w.hot_capacity() * 3) // x, y, radius
+    //   new Float32Array(World.memory().buffer, w.vitals_ptr(), w.hot_capacity() * 4) // energy, health, stamina, thirst
+    // The first hot_count() slots are live. Rebuild the views when hot_version() changes (the
+    // population outgrew the buffers) or when memory growth detaches the old buffer.
+    #[wasm_bindgen(js_name = positions_ptr)]
+    pub fn positions_ptr(&self) -> u32 { self.inner.hot_positions().as_ptr() as u32 }
+
+    #[wasm_bindgen(js_name = vitals_ptr)]
+    pub fn vitals_ptr(&self) -> u32 { self.inner.hot_vitals().as_ptr() as u32 }
+
+    // Creature slots in each buffer
+    #[wasm_bindgen(js_name = hot_capacity)]
+    pub fn hot_capacity(&self) -> u32 { (self.inner.hot_positions().len() / POSITION_STRIDE) as u32 }
+
+    #[wasm_bindgen(js_name = hot_count)]
+    pub fn hot_count(&self) -> u32 { self.inner.hot_count() as u32 }
+
+    #[wasm_bindgen(js_name = hot_version)]
+    pub fn hot_version(&self) -> u32 { self.inner.hot_version() }
+
+    // The module's linear memory, for the *_ptr views
+    pub fn memory() -> JsValue { wasm_bindgen::memory() }
+
+    // Column names for energy_ledger_f32 (stride = number of categories)
+    #[wasm_bindgen(js_name = energy_ledger_categories)]
+    pub fn energy_ledger_categories(&self) -> Result<JsValue, JsError> {
+        to_js("energy_ledger_categories", &LEDGER_CATEGORIES)
+    }
+
+    // Per-topology brain batches for external evaluation:
+    // [{ layerSizes, ids, weights: Float32Array[], biases: Float32Array[], inputs: Float32Array }]
+    #[wasm_bindgen(js_name = brain_batches)]
+    pub fn brain_batches(&self) -> Result<js_sys::Array, JsError> {
+        let f32_arrays = |layers: &[Vec<f32>]| -> js_sys::Array {
+            layers.iter().map(|l| JsValue::from(js_sys::Float32Array::from(&l[..]))).collect()
         };
-        let brain = init_brain_avoiding_bad(layer_sizes, &mut self.rng, &self.bad_brain_hashes);
-        self.creatures.push(Creature{
-            id,
-            x,
-            y,
-            vx: self.rng.uniform(-1.0, 1.0) * 2.0,
-            vy: self.rng.uniform(-1.0, 1.0) * 2.0,
-            radius: 5.0,
-            health: 100.0,
-            energy: 100.0,
-            stamina: 100.0,
-            max_stamina: 100.0,
-            thirst: 100.0,
-            lifespan: 0,
-            diet,
-            brain,
-            is_pregnant: false,
-            gestation_timer: 0.0,
-            offspring_count: 1,
-            actions_mask: 0,
-            feelings_mask: 0,
-            stagnant_ticks: 0,
-            last_env_total: 0.0,
-            last_env_swim: 0.0,
-            last_env_wind: 0.0,
-            last_env_cold: 0.0,
-            last_env_heat: 0.0,
-            last_env_humid: 0.0,
-            last_env_oxy: 0.0,
-            last_env_noise: 0.0,
-            last_env_disease: 0.0,
-            last_locomotion: 0.0,
-        });
+        self.inner.brain_batches().into_iter().map(|b| {
+            let obj = js_sys::Object::new();
+            let set = |k: &str, v: JsValue| { let _ = js_sys::Reflect::set(&obj, &JsValue::from_str(k), &v); };
+            set("layerSizes", to_js("brain_batches", &b.layer_sizes)?);
+            set("ids", to_js("brain_batches", &b.ids)?);
+            set("weights", f32_arrays(&b.weights).into());
+            set("biases", f32_arrays(&b.biases).into());
+            set("inputs", js_sys::Float32Array::from(&b.inputs[..]).into());
+            Ok(JsValue::from(obj))
+        }).collect()
+    }
+
+    // Feed back batched outputs (row-major, one row per id) to drive the next tick; returns rows accepted
+    #[wasm_bindgen(js_name = set_brain_outputs)]
+    pub fn set_brain_outputs(&mut self, #[wasm_bindgen(unchecked_param_type = "string[]")] ids: JsValue, outputs: &[f32]) -> Result<u32, JsError> {
+        let ids: Vec<String> = from_js("set_brain_outputs: ids must be a string array", ids)?;
+        Ok(self.inner.set_brain_outputs(&ids, outputs))
     }
 
+    // Spawn a single creature at a specific location (diet randomized)
+    #[wasm_bindgen(js_name = spawn_creature)]
+    pub fn spawn_creature(&mut self, x: f32, y: f32) { self.inner.spawn_creature(x, y); }
+
     // Spawn a plant at a location with optional radius (defaults to 3.0)
     #[wasm_bindgen(js_name = spawn_plant)]
-    pub fn spawn_plant(&mut self, x: f32, y: f32, radius: Option<f32>) {
-        let r = radius.unwrap_or(3.0).max(0.5);
-        self.plants.push(Plant{ x, y, radius: r });
+    pub fn spawn_plant(&mut self, x: f32, y: f32, radius: Option<f32>) { self.inner.spawn_plant(x, y, radius); }
+
+    // Drop a food item right away (not recorded in the command log; use place_food for replays).
+    // decay_per_sec defaults to config.foodItems.decayPerSec; false when the item cap is reached.
+    #[wasm_bindgen(js_name = spawn_food)]
+    pub fn spawn_food(&mut self, x: f32, y: f32, energy: f32, decay_per_sec: Option<f32>) -> bool { self.inner.spawn_food(x, y, energy, decay_per_sec) }
+
+    // Give creature `id` `amount` energy before the next tick (recorded in the command log; emits
+    // handFed). Returns the command's sequence id; throws for an unknown creature.
+    #[wasm_bindgen(js_name = feed_creature)]
+    pub fn feed_creature(&mut self, id: &str, amount: f32) -> Result<f64, JsError> {
+        self.inner.feed_creature(id, amount).map(|seq| seq as f64).ok_or_else(|| JsError::new(&format!("feed_creature: no creature '{}'", id)))
+    }
+
+    // Drop food holding `energy` at (x, y) before the next tick (recorded in the command log; emits
+    // foodPlaced). Returns the command's sequence id; throws if the command could not be queued.
+    #[wasm_bindgen(js_name = place_food)]
+    pub fn place_food(&mut self, x: f32, y: f32, energy: f32) -> Result<f64, JsError> {
+        self.inner.place_food(x, y, energy).map(|seq| seq as f64).ok_or_else(|| JsError::new("place_food: command could not be queued"))
+    }
+
+    // { mode, inputs, outputs } with [{ name, min, max, kind }] per channel for "OG" / "Zegion"
+    // (current mode when omitted), including custom input channels; throws for an unknown mode
+    #[wasm_bindgen(js_name = brain_io_schema, unchecked_return_type = "BrainIoSchema")]
+    pub fn brain_io_schema(&self, mode: Option<String>) -> Result<JsValue, JsError> {
+        let parsed = match mode.as_deref() {
+            Some(m) => Some(BrainMode::parse(m).ok_or_else(|| JsError::new(&format!("brain_io_schema: unknown mode '{}' (expected \"OG\" or \"Zegion\")", m)))?),
+            None => None,
+        };
+        to_js("brain_io_schema", &self.inner.brain_io_schema(parsed))
+    }
+
+    // Extra brain inputs for one creature id or "*" (all); the array length sets the number of
+    // custom channels and changing it resizes every brain's input layer. Throws for an unknown id
+    #[wasm_bindgen(js_name = set_custom_inputs)]
+    pub fn set_custom_inputs(&mut self, target: &str, values: &[f32]) -> Result<(), JsError> {
+        self.inner.set_custom_inputs(target, values).map_err(|e| JsError::new(&format!("set_custom_inputs: {}", e)))
+    }
+
+    // Brain input width (brain mode senses plus custom channels)
+    #[wasm_bindgen(js_name = input_len)]
+    pub fn input_len(&self) -> u32 { self.inner.input_len() }
+
+    // Reset into a built-in scenario (overrides on the default config, plus layout); throws (world
+    // unchanged) for an unknown name
+    #[wasm_bindgen(js_name = load_scenario)]
+    pub fn load_scenario(&mut self, name: &str) -> Result<(), JsError> {
+        self.inner.load_scenario(name).map_err(|e| JsError::new(&format!("load_scenario: {}", e)))
     }
 
     // Reset the world entities using current dimensions, RNG, and brain mode
     #[wasm_bindgen(js_name = reset_world)]
-    pub fn reset_world(&mut self) {
-        self.tick = 0;
-        self.creatures.clear();
-        self.plants.clear();
-        self.corpses.clear();
-        // Recreate a default population similar to constructor
-        let n_cre = 50usize;
-        for i in 0..n_cre {
-            let diet = if self.rng.next_f32_01() > 0.8 { Diet::Carnivore } else { Diet::Herbivore };
-            let layer_sizes = match self.brain_mode {
-                BrainMode::OG => vec![14, 8, 8],
-                BrainMode::Zegion => vec![24, 16, 6],
-            };
-            let brain = init_brain_avoiding_bad(layer_sizes, &mut self.rng, &self.bad_brain_hashes);
-            self.creatures.push(Creature{
-                id: format!("c{}", i),
-                x: self.rng.uniform(0.0, self.width),
-                y: self.rng.uniform(0.0, self.height),
-                vx: self.rng.uniform(-1.0, 1.0) * 2.0,
-                vy: self.rng.uniform(-1.0, 1.0) * 2.0,
-                radius: 5.0,
-                health: 100.0,
-                energy: 100.0,
-                stamina: 100.0,
-                max_stamina: 100.0,
-                thirst: 100.0,
-                lifespan: 0,
-                diet,
-                brain,
-                is_pregnant: false,
-                gestation_timer: 0.0,
-                offspring_count: 1,
-                actions_mask: 0,
-                feelings_mask: 0,
-                stagnant_ticks: 0,
-                last_env_total: 0.0,
-                last_env_swim: 0.0,
-                last_env_wind: 0.0,
-                last_env_cold: 0.0,
-                last_env_heat: 0.0,
-                last_env_humid: 0.0,
-                last_env_oxy: 0.0,
-                last_env_noise: 0.0,
-                last_env_disease: 0.0,
-                last_locomotion: 0.0,
-            });
-        }
-        for _ in 0..150 {
-            self.plants.push(Plant{ x: self.rng.uniform(0.0, self.width), y: self.rng.uniform(0.0, self.height), radius: 3.0 });
-        }
-    }
+    pub fn reset_world(&mut self) { self.inner.reset_world(); }
 
+    // "OG" or "Zegion" (case-insensitive); throws (world unchanged) for any other name
     #[wasm_bindgen(js_name = set_brain_mode)]
-    pub fn set_brain_mode(&mut self, mode: &str) {
-        let new_mode = if mode.eq_ignore_ascii_case("Zegion") { BrainMode::Zegion } else { BrainMode::OG };
-        if new_mode == self.brain_mode { return; }
-        self.brain_mode = new_mode;
-        match self.brain_mode {
-            BrainMode::OG => {
-                for c in &mut self.creatures { c.brain = init_brain_avoiding_bad(vec![14, 8, 8], &mut self.rng, &self.bad_brain_hashes); }
-            }
-            BrainMode::Zegion => {
-                for c in &mut self.creatures { c.brain = init_brain_avoiding_bad(vec![24, 16, 6], &mut self.rng, &self.bad_brain_hashes); }
-            }
-        }
+    pub fn set_brain_mode(&mut self, mode: &str) -> Result<(), JsError> {
+        self.inner.set_brain_mode(mode).map_err(|e| JsError::new(&format!("set_brain_mode: {}", e)))
     }
 
     #[wasm_bindgen(js_name = set_seed)]
-    pub fn set_seed(&mut self, seed: u32) {
-        self.rng = RngLCG::new(seed);
-    }
+    pub fn set_seed(&mut self, seed: u32) { self.inner.set_seed(seed); }
 
-    // Alias for TS compatibility: store calls wasmWorld.set_brain_seed(...)
+    // Reseed only the simulation RNG (brains, spawns, births); unlike set_seed the map is untouched
     #[wasm_bindgen(js_name = set_brain_seed)]
-    pub fn set_brain_seed(&mut self, seed: u32) {
-        self.set_seed(seed);
-    }
+    pub fn set_brain_seed(&mut self, seed: u32) { self.inner.set_brain_seed(seed); }
 
+    // Replace the bad-brain hash set; throws (set unchanged) unless given an array of strings
     #[wasm_bindgen(js_name = set_bad_brain_hashes)]
-    pub fn set_bad_brain_hashes(&mut self, hashes: JsValue) {
-        // Expect an array of strings from JS
-        if let Ok(vec) = serde_wasm_bindgen::from_value::<Vec<String>>(hashes) {
-            let mut new_set: HashSet<String> = HashSet::with_capacity(vec.len());
-            for h in vec { new_set.insert(h); }
-            self.bad_brain_hashes = new_set;
-        }
+    pub fn set_bad_brain_hashes(&mut self, #[wasm_bindgen(unchecked_param_type = "string[]")] hashes: JsValue) -> Result<(), JsError> {
+        let hashes: Vec<String> = from_js("set_bad_brain_hashes: expected an array of strings", hashes)?;
+        self.inner.set_bad_brain_hashes(hashes);
+        Ok(())
     }
 
-    // Receive config from JS (simulationParams parity subset)
-    #[wasm_bindgen(js_name = set_config)]
-    pub fn set_config(&mut self, cfg: JsValue) {
-        if let Ok(parsed) = serde_wasm_bindgen::from_value::<Config>(cfg) {
-            self.config = parsed;
-        }
+    // Replace a creature's brain with a trained { layerSizes, weights, biases } (NaNs zeroed, extremes
+    // clamped); returns how many values were sanitized, throws if the id or topology doesn't fit
+    #[wasm_bindgen(js_name = import_brain)]
+    pub fn import_brain(&mut self, id: &str, #[wasm_bindgen(unchecked_param_type = "Brain")] brain: JsValue) -> Result<u32, JsError> {
+        let brain: Brain = from_js("import_brain: invalid brain payload", brain)?;
+        self.inner.set_creature_brain(id, brain).map_err(|e| JsError::new(&format!("import_brain: {}", e)))
     }
-}
 
-fn wrap(v: &mut f32, max: f32) {
-    if *v > max { *v = 0.0; } else if *v < 0.0 { *v = max; }
-}
+    // Reward for recorded transitions: "energy" | "health" | "fitness" | "survival"; throws if unknown
+    #[wasm_bindgen(js_name = set_reward_source)]
+    pub fn set_reward_source(&mut self, mode: &str) -> Result<(), JsError> {
+        self.inner.set_reward_source(mode).map_err(|e| JsError::new(&format!("set_reward_source: {}", e)))
+    }
 
-fn rand_unit(seed: u64) -> f32 {
-    // simple xorshift to pseudo-random [0,1)
-    let mut s = seed;
-    s ^= s << 13;
-    s ^= s >> 7;
-    s ^= s << 17;
-    ((s % 10_000) as f32) / 10_000.0
-}
+    // [{ tick, inputs, outputs, reward, nextInputs, done }] recorded for `id` since the last call.
+    // The first call selects the creature for recording.
+    #[wasm_bindgen(js_name = collect_transitions, unchecked_return_type = "Transition[]")]
+    pub fn collect_transitions(&mut self, id: &str) -> Result<JsValue, JsError> {
+        to_js("collect_transitions", &self.inner.collect_transitions(id))
+    }
 
-fn nearest_herbivore(x: f32, y: f32, a: &[Creature], b: &[Creature]) -> Option<(f32,f32)> {
-    let mut best_d2 = f32::INFINITY;
-    let mut best = None;
-    for c in a.iter().chain(b.iter()) {
-        if let Diet::Herbivore = c.diet {
-            let dx = c.x - x; let dy = c.y - y;
-            let d2 = dx*dx + dy*dy;
-            if d2 < best_d2 { best_d2 = d2; best = Some((c.x, c.y)); }
-        }
+    // Run the brain pruning/sparsification pass now; returns { brainsTouched, neuronsPruned, weightsZeroed }
+    #[wasm_bindgen(js_name = prune_brains_json, unchecked_return_type = "PruneReport")]
+    pub fn prune_brains_json(&mut self) -> Result<JsValue, JsError> {
+        to_js("prune_brains_json", &self.inner.prune_brains())
     }
-    best
-}
 
-// Very lightweight pseudo-noise for terrain speed multiplier [0.6, 1.0]
-fn terrain_speed_at(x: f32, y: f32, t: u64) -> f32 {
-    let tt = (t % 10_000) as f32 * 0.001;
-    let v = (f32::sin(x * 0.003 + tt) * f32::cos(y * 0.002 - tt) * 0.5 + 0.5);
-    0.6 + v * 0.4
-}
+    // Merge partial fitness weights ({ lifespan, offspring, energyHarvested, kills, distanceTraveled,
+    // damageAvoided }) into config.fitness; throws (weights unchanged) if the payload is invalid
+    #[wasm_bindgen(js_name = set_fitness_weights)]
+    pub fn set_fitness_weights(&mut self, #[wasm_bindgen(unchecked_param_type = "Partial<FitnessWeights>")] weights: JsValue) -> Result<(), JsError> {
+        let patch: serde_json::Value = from_js("set_fitness_weights: invalid weights payload", weights)?;
+        self.inner.set_fitness_weights(&patch).map_err(|e| JsError::new(&format!("set_fitness_weights: {}", e)))
+    }
 
-// --- Simple environment samplers (placeholders; JS provides richer ones) ---
-fn sample_temperature_c(x: f32, y: f32, t: u64) -> f32 {
-    let tt = (t as f32) * 0.01;
-    // 10..30C varying smoothly
-    20.0 + (f32::sin(x * 0.001 + tt) + f32::cos(y * 0.001 - tt * 0.7)) * 5.0
-}
+    // Credit a kill resolved by the host to a living creature (predation stats, fitness kills)
+    #[wasm_bindgen(js_name = record_kill)]
+    pub fn record_kill(&mut self, killer_id: &str) -> bool { self.inner.record_kill(killer_id) }
+
+    // Recombined child of two brains using config.crossover (null if crossover is off or they don't
+    // match); throws for an invalid brain payload
+    #[wasm_bindgen(js_name = crossover_brains, unchecked_return_type = "Brain | null")]
+    pub fn crossover_brains(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "Brain")] a: JsValue,
+        #[wasm_bindgen(unchecked_param_type = "Brain")] b: JsValue,
+    ) -> Result<JsValue, JsError> {
+        let a: Brain = from_js("crossover_brains: invalid brain payload (a)", a)?;
+        let b: Brain = from_js("crossover_brains: invalid brain payload (b)", b)?;
+        match self.inner.crossover_brains(&a, &b) {
+            Some(child) => to_js("crossover_brains", &child),
+            None => Ok(JsValue::NULL),
+        }
+    }
 
-fn sample_humidity01(x: f32, y: f32, t: u64) -> f32 {
-    let tt = (t as f32) * 0.008;
-    ((f32::sin(x * 0.0007 + y * 0.0005 + tt) * 0.5 + 0.5) * 0.9).clamp(0.0, 1.0)
-}
+    // Mutated copy of a { layerSizes, weights, biases } brain using config.mutation; throws if invalid
+    #[wasm_bindgen(js_name = mutate_brain, unchecked_return_type = "Brain")]
+    pub fn mutate_brain(&mut self, #[wasm_bindgen(unchecked_param_type = "Brain")] brain: JsValue, generation: u32) -> Result<JsValue, JsError> {
+        let brain: Brain = from_js("mutate_brain: invalid brain payload", brain)?;
+        to_js("mutate_brain", &self.inner.mutate_brain(&brain, generation))
+    }
 
-fn sample_rain01(x: f32, y: f32, t: u64) -> f32 {
-    let tt = (t as f32) * 0.02;
-    let band = (f32::sin(tt) * 0.5 + 0.5); // periodic rain bands
-    (band * (f32::sin(y * 0.002 + tt * 0.3) * 0.5 + 0.5)).clamp(0.0, 1.0)
-}
+    // Events since the last call ([{ type: "degenerateBrain", tick, id, hash, reason, action }, ...])
+    #[wasm_bindgen(js_name = drain_events_json, unchecked_return_type = "WorldEvent[]")]
+    pub fn drain_events_json(&mut self) -> Result<JsValue, JsError> {
+        to_js("drain_events_json", &self.inner.drain_events())
+    }
 
-fn sample_wetness01(x: f32, y: f32, t: u64) -> f32 {
-    // Wetness lags rain a bit
-    let r = sample_rain01(x, y, t.saturating_sub(50));
-    (r * 0.7 + sample_humidity01(x, y, t) * 0.3).clamp(0.0, 1.0)
-}
+    // Latest notable moments, oldest first ([{ type: "recordLifespan", tick, id, ... }, ...]); not drained
+    #[wasm_bindgen(js_name = highlights_json, unchecked_return_type = "Highlight[]")]
+    pub fn highlights_json(&self) -> Result<JsValue, JsError> {
+        to_js("highlights_json", self.inner.highlights())
+    }
 
-fn sample_wind_speed(x: f32, y: f32, t: u64) -> f32 {
-    let tt = (t as f32) * 0.015;
-    // 0..1 scale
-    (f32::sin(x * 0.0009 + tt) * f32::cos(y * 0.0006 - tt) * 0.5 + 0.5).clamp(0.0, 1.0)
-}
+    // Energy conservation report for the latest tick; null unless config.energyAudit
+    #[wasm_bindgen(js_name = energy_audit_json, unchecked_return_type = "EnergyAudit | null")]
+    pub fn energy_audit_json(&self) -> Result<JsValue, JsError> {
+        self.inner.energy_audit().map_or(Ok(JsValue::NULL), |a| to_js("energy_audit_json", a))
+    }
 
-fn sample_elevation01(x: f32, y: f32) -> f32 {
-    // Normalize position to 0..1; higher y slightly higher elevation
-    let nx = (x * 0.001).sin() * 0.5 + 0.5;
-    let ny = (y * 0.001).cos() * 0.5 + 0.5;
-    ((nx * 0.6 + ny * 0.4) * 0.9).clamp(0.0, 1.0)
-}
+    // { cols, rows, population, infected } over a resolution x resolution grid (infection-rate overlay)
+    #[wasm_bindgen(js_name = infection_grid_json, unchecked_return_type = "InfectionGrid")]
+    pub fn infection_grid_json(&self, resolution: u32) -> Result<JsValue, JsError> {
+        to_js("infection_grid_json", &self.inner.infection_grid(resolution))
+    }
 
-fn sample_noise01(x: f32, y: f32, t: u64) -> f32 {
-    let tt = (t as f32) * 0.05;
-    (f32::sin(x * 0.004 + tt) * f32::sin(y * 0.003 - tt) * 0.5 + 0.5).clamp(0.0, 1.0)
-}
+    // Hash of a { layerSizes, weights, biases } brain in the format set_bad_brain_hashes expects
+    // (canonical xxHash64 hex unless config.legacyBrainHash); throws for an invalid payload
+    #[wasm_bindgen(js_name = brain_hash)]
+    pub fn brain_hash(&self, #[wasm_bindgen(unchecked_param_type = "Brain")] brain: JsValue) -> Result<String, JsError> {
+        let brain: Brain = from_js("brain_hash: invalid brain payload", brain)?;
+        Ok(self.inner.brain_hash(&brain))
+    }
 
-// Brain helpers
-fn init_brain(layer_sizes: Vec<u32>, rng: &mut RngLCG) -> Brain {
-    let mut weights: Vec<Vec<f32>> = Vec::new();
-    let mut biases: Vec<Vec<f32>> = Vec::new();
-    // For L layers where layer_sizes = [n0, n1, ..., n_{L-1}]
-    for li in 1..layer_sizes.len() {
-        let n_in = layer_sizes[li-1] as usize;
-        let n_out = layer_sizes[li] as usize;
-        // He-like init
-        let scale = (2.0f32 / (n_in as f32).max(1.0)).sqrt();
-        let mut w: Vec<f32> = Vec::with_capacity(n_in * n_out);
-        for oi in 0..n_out { let _ = oi; for _ in 0..n_in { w.push((rng.uniform(-1.0, 1.0)) * scale); } }
-        let b: Vec<f32> = vec![0.0; n_out];
-        weights.push(w);
-        biases.push(b);
-    }
-    Brain { layer_sizes, weights: Some(weights), biases: Some(biases), activations: None }
-}
+    // Merge a partial config from JS (simulationParams parity subset) into the current one; fields
+    // it leaves out keep their values. Throws (config unchanged) for unknown keys or invalid values
+    #[wasm_bindgen(js_name = set_config)]
+    pub fn set_config(&mut self, #[wasm_bindgen(unchecked_param_type = "Partial<Config>")] cfg: JsValue) -> Result<(), JsError> {
+        let patch: serde_json::Value = from_js("set_config: invalid config payload", cfg)?;
+        self.inner.update_config(&patch).map_err(|e| JsError::new(&format!("set_config: {}", e)))
+    }
 
-// Initialize a brain, retrying a limited number of times if the hash is in the bad set
-fn init_brain_avoiding_bad(layer_sizes: Vec<u32>, rng: &mut RngLCG, bad: &HashSet<String>) -> Brain {
-    const MAX_TRIES: usize = 16;
-    let mut last = init_brain(layer_sizes.clone(), rng);
-    if bad.is_empty() { return last; }
-    for _ in 0..MAX_TRIES {
-        let h = brain_hash(&last);
-        if !bad.contains(&h) { return last; }
-        last = init_brain(layer_sizes.clone(), rng);
-    }
-    last
+    // Minimum level forwarded to the console: "off" | "error" | "warn" | "info" | "debug" | "trace"
+    #[wasm_bindgen(js_name = set_log_level)]
+    pub fn set_log_level(&mut self, level: &str) -> Result<(), JsError> {
+        let f = level.parse::<log::LevelFilter>()
+            .map_err(|_| JsError::new(&format!("set_log_level: unknown level '{}' (expected off, error, warn, info, debug, or trace)", level)))?;
+        log::set_max_level(f);
+        Ok(())
+    }
 }
 
-// JS-simpleHash parity: 32-bit rolling hash over JSON text, then to radix36 string
-fn simple_hash_str(s: &str) -> String {
-    let mut hash: i32 = 0;
-    for &b in s.as_bytes() {
-        hash = (hash << 5).wrapping_sub(hash).wrapping_add(b as i32);
-    }
-    let mut n: u32 = hash as u32; // two's complement cast like JS |0 then >>>0
-    // convert to radix36
-    if n == 0 { return "0".to_string(); }
-    let mut digits = Vec::new();
-    while n > 0 {
-        let rem = (n % 36) as u8;
-        n /= 36;
-        let ch = if rem < 10 { (b'0' + rem) as char } else { (b'a' + (rem - 10)) as char };
-        digits.push(ch);
-    }
-    digits.iter().rev().collect()
+impl World {
+    // Serialize an entity dump and charge the time to the perf serialization counter. Entities
+    // hold no maps, so plain objects only matter for legacy_snake_case_entities (re-keyed maps).
+    fn timed_to_value<T: Serialize + ?Sized>(&self, what: &str, value: &T) -> Result<JsValue, JsError> {
+        let t0 = ecosim_core::now_ms();
+        let v = self.inner.export_entities(value)
+            .serialize(&serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true))
+            .map_err(|e| JsError::new(&format!("{}: could not serialize result: {}", what, e)));
+        self.inner.record_serialization_ms(ecosim_core::now_ms() - t0);
+        v
+    }
 }
 
-fn brain_hash(brain: &Brain) -> String {
-    // Serialize the same canonical fields as JS does
-    #[derive(Serialize)]
-    struct Canon<'a> { layer_sizes: &'a Vec<u32>, weights: &'a Vec<Vec<f32>>, biases: &'a Vec<Vec<f32>> }
-    let weights = brain.weights.as_ref().unwrap();
-    let biases = brain.biases.as_ref().unwrap();
-    let canon = Canon { layer_sizes: &brain.layer_sizes, weights, biases };
-    let json = serde_json::to_string(&canon).unwrap_or_else(|_| String::new());
-    simple_hash_str(&json)
+// Serialize a return value for JS; a failure becomes an Error naming the export
+fn to_js<T: Serialize + ?Sized>(what: &str, value: &T) -> Result<JsValue, JsError> {
+    serde_wasm_bindgen::to_value(value).map_err(|e| JsError::new(&format!("{}: could not serialize result: {}", what, e)))
 }
 
-// Deterministic LCG RNG (32-bit state)
-struct RngLCG { state: u32 }
-impl RngLCG {
-    fn new(seed: u32) -> Self { let s = if seed == 0 { 0xDEADBEEF } else { seed }; Self { state: s } }
-    fn next_u32(&mut self) -> u32 { self.state = self.state.wrapping_mul(1664525).wrapping_add(1013904223); self.state }
-    fn next_f32_01(&mut self) -> f32 { (self.next_u32() as f32) / 4294967296.0 }
-    fn uniform(&mut self, min: f32, max: f32) -> f32 { min + (max - min) * self.next_f32_01() }
+// Deserialize a JS argument; `context` prefixes the serde message (export and what was expected)
+fn from_js<T: DeserializeOwned>(context: &str, value: JsValue) -> Result<T, JsError> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsError::new(&format!("{}: {}", context, e)))
 }
 
-fn plants_near(plants: &Vec<Plant>, x: f32, y: f32, radius: f32) -> bool {
-    let r2 = radius * radius;
-    for p in plants.iter() {
-        let dx = p.x - x; let dy = p.y - y; if dx*dx + dy*dy <= r2 { return true; }
+// log facade sink: browser console on wasm, stderr elsewhere
+struct ConsoleLogger;
+
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool { metadata.level() <= log::max_level() }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) { return; }
+        let msg = format!("[ecosim] {}", record.args());
+        #[cfg(target_arch = "wasm32")]
+        {
+            let v = JsValue::from_str(&msg);
+            match record.level() {
+                log::Level::Error => web_sys::console::error_1(&v),
+                log::Level::Warn => web_sys::console::warn_1(&v),
+                log::Level::Info => web_sys::console::info_1(&v),
+                log::Level::Debug | log::Level::Trace => web_sys::console::debug_1(&v),
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        eprintln!("{} {}", record.level(), msg);
     }
-    false
+
+    fn flush(&self) {}
 }
 
-fn build_inputs(width: f32, height: f32, tick: u64, c: &Creature, a: &[Creature], b: &[Creature], mode: BrainMode) -> Vec<f32> {
-    // Common features
-    let nx = c.x / width;
-    let ny = c.y / height;
-    let spx = c.vx.tanh();
-    let spy = c.vy.tanh();
-    let e = (c.energy / 100.0).clamp(0.0, 1.0);
-    let h = (c.health / 100.0).clamp(0.0, 1.0);
-    let t = (tick as f32) * 0.01;
-    let ts = f32::sin(t);
-    let tc = f32::cos(t);
-    // Nearest herbivore vector
-    let target = nearest_herbivore(c.x, c.y, a, b);
-    let (dxn, dyy, dd) = if let Some((tx, ty)) = target {
-        let dx = tx - c.x; let dy = ty - c.y; let d = (dx*dx + dy*dy).sqrt().max(0.0001);
-        (dx / d, dy / d, (d / width.max(height)).clamp(0.0, 1.0))
-    } else { (0.0, 0.0, 1.0) };
-    let mut v = vec![nx, ny, spx, spy, e, h, ts, tc, dxn, dyy, dd];
-    match mode {
-        BrainMode::OG => {
-            // Add bias + pad to 14
-            v.push(1.0);
-            let need = 14usize;
-            if v.len() < need { v.extend(std::iter::repeat(0.0).take(need - v.len())); }
-            v.truncate(need);
-            v
-        }
-        BrainMode::Zegion => {
-            // Nearest carnivore
-            let pred = nearest_carnivore(c.x, c.y, a, b);
-            let (dxn2, dyn2, dd2) = if let Some((px, py)) = pred {
-                let dx = px - c.x; let dy = py - c.y; let d = (dx*dx + dy*dy).sqrt().max(0.0001);
-                (dx / d, dy / d, (d / width.max(height)).clamp(0.0, 1.0))
-            } else { (0.0, 0.0, 1.0) };
-            let rough = terrain_speed_at(c.x, c.y, tick);
-            let rough_n = (rough - 0.6) / 0.4; // 0..1 -> normalize
-            let speed_mag = (c.vx * c.vx + c.vy * c.vy).sqrt().tanh();
-            let dot_herb = spx * dxn + spy * dyy;
-            let dot_carn = spx * dxn2 + spy * dyn2;
-            let ts2 = f32::sin(t * 0.37 + c.x * 0.0007 + c.y * 0.0009);
-            let tc2 = f32::cos(t * 0.41 - c.x * 0.0006 + c.y * 0.0011);
-            let diet_carn = if c.diet == Diet::Carnivore { 1.0 } else { 0.0 };
-            let inv_e = (1.0 - e).clamp(0.0, 1.0);
-            v.extend([dxn2, dyn2, dd2, rough, rough_n, speed_mag, dot_herb, dot_carn, ts2, tc2, diet_carn, inv_e, 1.0]);
-            // Now ensure length is 24
-            let need = 24usize;
-            if v.len() < need { v.extend(std::iter::repeat(0.0).take(need - v.len())); }
-            v.truncate(need);
-            v
-        }
-    }
+static LOGGER: ConsoleLogger = ConsoleLogger;
+
+// Install the console logger (defaults to Warn) and the browser clock once; later calls are no-ops
+fn init_host() {
+    if log::set_logger(&LOGGER).is_ok() { log::set_max_level(log::LevelFilter::Warn); }
+    #[cfg(target_arch = "wasm32")]
+    ecosim_core::set_clock(performance_now_ms);
 }
 
-fn brain_forward(brain: &mut Brain, inputs: &Vec<f32>, mode: BrainMode) -> (Vec<f32>, Vec<Vec<f32>>) {
-    let ls = &brain.layer_sizes;
-    let weights = brain.weights.as_ref().unwrap();
-    let biases = brain.biases.as_ref().unwrap();
-    let mut acts: Vec<Vec<f32>> = Vec::new();
-    let mut cur = inputs.clone();
-    acts.push(cur.clone());
-    for li in 1..ls.len() {
-        let n_in = ls[li-1] as usize;
-        let n_out = ls[li] as usize;
-        let w = &weights[li-1];
-        let b = &biases[li-1];
-        let mut next = vec![0.0f32; n_out];
-        for o in 0..n_out {
-            let mut sum = b[o];
-            let base = o * n_in;
-            for ii in 0..n_in { sum += w[base + ii] * cur[ii]; }
-            next[o] = match (mode, li == ls.len()-1) {
-                // Hidden layers: ReLU
-                (_, false) => if sum > 0.0 { sum } else { 0.0 },
-                // Output layer: tanh
-                (_, true) => sum.tanh(),
-            };
-        }
-        acts.push(next.clone());
-        cur = next;
-    }
-    (cur.clone(), acts)
+#[cfg(target_arch = "wasm32")]
+fn performance_now_ms() -> f64 {
+    web_sys::window().and_then(|w| w.performance()).map(|p| p.now()).unwrap_or(0.0)
 }